@@ -1,5 +1,16 @@
 use multiversx_sc::imports::*;
 
+use crate::constants::{
+    ERR_INVALID_DIMINISHING_RATE, ERR_INVALID_REWARD_FEE, ERR_INVALID_REWARD_TOKEN,
+    ERR_NO_FEES_TO_WITHDRAW, ERR_NO_TREASURY_ADDRESS, ERR_REWARD_TOKEN_ALREADY_ACTIVE,
+    ERR_REWARD_TOKEN_HAS_DISTRIBUTION_PLAN, ERR_REWARD_TOKEN_HAS_OUTSTANDING_REWARDS,
+    ERR_REWARD_TOKEN_NOT_RETIRED, ERR_REWARD_TOKEN_OUTSTANDING_REWARDS_NOT_TRACKED,
+    ERR_TOO_MANY_ACTIVE_REWARD_TOKENS, ERR_TOO_MANY_REWARD_TOKENS, MAX_ACTIVE_REWARD_TOKENS,
+    MAX_BPS, MAX_REWARD_TOKENS,
+};
+use crate::pause::Operation;
+use crate::roles::Role;
+
 #[multiversx_sc::module]
 pub trait AdminModule:
     crate::storage::StorageModule
@@ -7,22 +18,54 @@ pub trait AdminModule:
     + crate::utils::UtilsModule
     + crate::reward::reward_rate::RewardRateModule
     + crate::reward::planned_distribution::PlannedDistributionModule
+    + crate::roles::RolesModule
+    + crate::events::EventsModule
+    + crate::season::SeasonModule
+    + crate::pause::PauseModule
 {
-    #[only_owner]
     #[endpoint(disableStaking)]
     fn disable_staking(&self) {
+        self.require_role(Role::Pauser);
+
         self.staking_disabled().set(true);
+        self.emit_staking_disabled_changed_event(true);
     }
 
-    #[only_owner]
     #[endpoint(enableStaking)]
     fn enable_staking(&self) {
+        self.require_role(Role::Pauser);
+
         self.staking_disabled().set(false);
+        self.emit_staking_disabled_changed_event(false);
+    }
+
+    /// Let users withdraw their NFTs through `emergencyWithdraw`, without any reward accounting.
+    /// New stakes are rejected while emergency mode is active.
+    #[only_owner]
+    #[endpoint(enableEmergencyMode)]
+    fn enable_emergency_mode(&self) {
+        self.emergency_mode().set(true);
+        self.emit_emergency_mode_changed_event(true);
+    }
+
+    #[only_owner]
+    #[endpoint(disableEmergencyMode)]
+    fn disable_emergency_mode(&self) {
+        self.emergency_mode().set(false);
+        self.emit_emergency_mode_changed_event(false);
     }
 
+    /// Enable or disable `transferStakedPosition`.
     #[only_owner]
+    #[endpoint(setPositionTransferEnabled)]
+    fn set_position_transfer_enabled(&self, enabled: bool) {
+        self.position_transfer_enabled().set(enabled);
+    }
+
     #[endpoint(allowCollections)]
     fn allow_collections(&self, collections: MultiValueManagedVec<TokenIdentifier>) {
+        self.require_role(Role::CollectionManager);
+
         for collection in collections.into_vec().iter() {
             self.allowed_nft_collections()
                 .insert(collection.clone_value());
@@ -31,26 +74,170 @@ pub trait AdminModule:
 
     /// I don't recommend using this function as it won't update user's storage.
     /// Its effect as of now is to stop other users from staking NFTs from the given collections.
-    #[only_owner]
     #[endpoint(disallowCollections)]
     fn disallow_collections(&self, collections: MultiValueManagedVec<TokenIdentifier>) {
+        self.require_role(Role::CollectionManager);
+
         for collection in collections.into_vec().iter() {
             self.allowed_nft_collections()
                 .remove(&collection.clone_value());
         }
     }
 
+    /// Register a reward token, or reactivate a retired one.
+    /// Only active reward tokens can be distributed, either manually or through a plan.
+    #[endpoint(addRewardToken)]
+    fn add_reward_token(&self, token_id: EgldOrEsdtTokenIdentifier) {
+        self.require_role(Role::RewardManager);
+
+        require!(token_id.is_valid(), ERR_INVALID_REWARD_TOKEN);
+        require!(
+            !self.is_active_reward_token(&token_id),
+            ERR_REWARD_TOKEN_ALREADY_ACTIVE
+        );
+        require!(
+            self.get_active_reward_token_count() < MAX_ACTIVE_REWARD_TOKENS,
+            ERR_TOO_MANY_ACTIVE_REWARD_TOKENS
+        );
+        require!(
+            self.reward_token_ids().contains(&token_id)
+                || self.reward_token_ids().len() < MAX_REWARD_TOKENS,
+            ERR_TOO_MANY_REWARD_TOKENS
+        );
+
+        self.retired_reward_token_ids().remove(&token_id);
+        if self.reward_token_ids().insert(token_id.clone()) {
+            self.outstanding_rewards_tracked(&token_id).set(true);
+        }
+    }
+
+    /// Stop a reward token from accruing new rewards.
+    /// Already accrued rewards remain claimable until the token is removed.
+    /// Distribution plans for the token must be removed beforehand.
+    #[endpoint(retireRewardToken)]
+    fn retire_reward_token(&self, token_id: EgldOrEsdtTokenIdentifier) {
+        self.require_role(Role::RewardManager);
+
+        self.require_active_reward_token(&token_id);
+        require!(
+            !self.has_distribution_plan(&token_id),
+            ERR_REWARD_TOKEN_HAS_DISTRIBUTION_PLAN
+        );
+
+        self.retired_reward_token_ids().insert(token_id);
+    }
+
+    /// Remove a retired reward token from the registry, once all its distributed rewards are claimed.
+    /// Tokens registered before the unclaimed rewards were tracked cannot be removed,
+    /// as their rewards distributed back then could still be unclaimed.
+    #[endpoint(removeRewardToken)]
+    fn remove_reward_token(&self, token_id: EgldOrEsdtTokenIdentifier) {
+        self.require_role(Role::RewardManager);
+
+        require!(
+            self.outstanding_rewards_tracked(&token_id).get(),
+            ERR_REWARD_TOKEN_OUTSTANDING_REWARDS_NOT_TRACKED
+        );
+        require!(
+            self.get_outstanding_rewards(&token_id) == 0,
+            ERR_REWARD_TOKEN_HAS_OUTSTANDING_REWARDS
+        );
+        require!(
+            self.retired_reward_token_ids().remove(&token_id),
+            ERR_REWARD_TOKEN_NOT_RETIRED
+        );
+
+        self.reward_token_ids().remove(&token_id);
+    }
+
+    /// Allow an address (e.g. a royalties or sales contract) to deposit rewards.
+    #[only_owner]
+    #[endpoint(addRewardDepositor)]
+    fn add_reward_depositor(&self, depositor: ManagedAddress) {
+        self.reward_depositors().insert(depositor);
+    }
+
+    /// Revoke the reward depositor role.
+    /// Contributions made so far are kept for attribution.
+    #[only_owner]
+    #[endpoint(removeRewardDepositor)]
+    fn remove_reward_depositor(&self, depositor: ManagedAddress) {
+        self.reward_depositors().remove(&depositor);
+    }
+
+    /// Set the protocol fee deducted from claimed rewards of the token, in basis points. 0 disables it.
+    #[only_owner]
+    #[endpoint(setRewardFee)]
+    fn set_reward_fee(&self, token_id: EgldOrEsdtTokenIdentifier, fee_bps: u64) {
+        require!(token_id.is_valid(), ERR_INVALID_REWARD_TOKEN);
+        require!(fee_bps <= MAX_BPS, ERR_INVALID_REWARD_FEE);
+
+        self.reward_fee(&token_id).set(fee_bps);
+    }
+
+    /// Set the treasury accumulated fees can be swept to.
+    /// Without an address, fees stay in the contract until withdrawn by the owner.
+    #[only_owner]
+    #[endpoint(setTreasuryAddress)]
+    fn set_treasury_address(&self, treasury: OptionalValue<ManagedAddress>) {
+        match treasury {
+            OptionalValue::Some(treasury) => self.treasury_address().set(treasury),
+            OptionalValue::None => self.treasury_address().clear(),
+        }
+    }
+
+    /// Withdraw the fees accumulated for the given tokens.
+    #[only_owner]
+    #[endpoint(withdrawFees)]
+    fn withdraw_fees(&self, token_ids: MultiValueEncoded<EgldOrEsdtTokenIdentifier>) {
+        let mut fees = ManagedVec::new();
+        for token_id in token_ids {
+            let amount = self.accumulated_fees(&token_id).take();
+            if amount > 0 {
+                fees.push(EgldOrEsdtTokenPayment::new(token_id, 0, amount));
+            }
+        }
+
+        require!(!fees.is_empty(), ERR_NO_FEES_TO_WITHDRAW);
+        self.tx().to(ToCaller).payment(&fees).transfer();
+    }
+
+    /// Send the fees accumulated for the given tokens to the treasury. Can be called by anyone.
+    #[endpoint(sweepFeesToTreasury)]
+    fn sweep_fees_to_treasury(&self, token_ids: MultiValueEncoded<EgldOrEsdtTokenIdentifier>) {
+        require!(!self.treasury_address().is_empty(), ERR_NO_TREASURY_ADDRESS);
+
+        let mut fees = ManagedVec::new();
+        for token_id in token_ids {
+            let amount = self.accumulated_fees(&token_id).take();
+            if amount > 0 {
+                fees.push(EgldOrEsdtTokenPayment::new(token_id, 0, amount));
+            }
+        }
+
+        require!(!fees.is_empty(), ERR_NO_FEES_TO_WITHDRAW);
+        self.tx()
+            .to(self.treasury_address().get())
+            .payment(&fees)
+            .transfer();
+    }
+
     /// Distribute rewards to all stakers.
     /// Expects at least a payment that consists of the total amount of tokens to be distributed.
+    /// Accepts both EGLD and ESDT payments, as long as they are active reward tokens.
     /// Used for unscheduled reward distributions (e.g. airdrop, campaigns, module integrations etc).
-    #[only_owner]
+    /// Can be called by the owner or by reward depositors; each deposit is attributed to the caller.
     #[payable("*")]
     #[endpoint(distributeRewards)]
     fn distribute_rewards(&self) {
         self.require_staking_enabled();
+        self.require_operation_not_paused(Operation::Distribution);
 
-        let payments = self.call_value().all_esdt_transfers();
-        self.handle_distribute_rewards(&payments);
+        let caller = self.blockchain().get_caller();
+        self.require_owner_or_reward_depositor(&caller);
+
+        let payments = self.call_value().all_transfers();
+        self.handle_distribute_rewards(&caller, &payments);
     }
 
     /// Set the unstaking penalty.
@@ -65,9 +252,10 @@ pub trait AdminModule:
     /// Change the score for all NFTs in the collection.
     /// Will also add the collection to the list of allowed collections.
     /// This will *NOT* update the score for already staked NFTs.
-    #[only_owner]
     #[endpoint(setCollectionScore)]
     fn set_collection_score(&self, collection: TokenIdentifier, score: u64) {
+        self.require_role(Role::ScoreManager);
+
         self.nft_collection_score(&collection)
             .set(BigUint::from(score));
         self.allowed_nft_collections().insert(collection);
@@ -76,24 +264,86 @@ pub trait AdminModule:
     /// Change the score for a specific nonce of an NFT in the collection.
     /// Will also add the collection to the list of allowed collections.
     /// This will *NOT* update the score for already staked NFTs.
-    #[only_owner]
     #[endpoint(setCollectionNonceScore)]
     fn set_collection_nonce_score(&self, collection: TokenIdentifier, nonce: u64, score: u64) {
+        self.require_role(Role::ScoreManager);
+
         self.nft_collection_nonce_score(&collection, nonce)
             .set(BigUint::from(score));
         self.allowed_nft_collections().insert(collection);
     }
 
+    /// Cap the raw staking score of a single user. Zero removes the cap.
+    /// Only enforced on new stakes: users already above a lowered cap keep their score,
+    /// but cannot stake more until they are back under it.
+    #[endpoint(setMaxUserScore)]
+    fn set_max_user_score(&self, max_score: u64) {
+        self.require_role(Role::ScoreManager);
+
+        if max_score == 0 {
+            self.max_user_score().clear();
+        } else {
+            self.max_user_score().set(BigUint::from(max_score));
+        }
+    }
+
+    /// Cap the quantity a single user can stake of the given nonce. Zero removes the cap.
+    /// Only enforced on new stakes.
+    #[endpoint(setMaxStakeQuantityPerNonce)]
+    fn set_max_stake_quantity_per_nonce(
+        &self,
+        collection: TokenIdentifier,
+        nonce: u64,
+        max_quantity: u64,
+    ) {
+        self.require_role(Role::ScoreManager);
+
+        if max_quantity == 0 {
+            self.max_stake_quantity_per_nonce(&collection, nonce)
+                .clear();
+        } else {
+            self.max_stake_quantity_per_nonce(&collection, nonce)
+                .set(BigUint::from(max_quantity));
+        }
+    }
+
+    /// Only `rate_bps` of the part of a user's raw score above `threshold` counts towards their staked score.
+    /// This will *NOT* update the score of users until their next stake or unstake,
+    /// or until `recomputeUserScore` is called for them.
+    #[endpoint(setDiminishingReturns)]
+    fn set_diminishing_returns(&self, threshold: u64, rate_bps: u64) {
+        self.require_role(Role::ScoreManager);
+
+        require!(rate_bps <= MAX_BPS, ERR_INVALID_DIMINISHING_RATE);
+
+        self.diminishing_returns()
+            .set((BigUint::from(threshold), rate_bps));
+    }
+
+    /// This will *NOT* update the score of users until their next stake or unstake,
+    /// or until `recomputeUserScore` is called for them.
+    #[endpoint(clearDiminishingReturns)]
+    fn clear_diminishing_returns(&self) {
+        self.require_role(Role::ScoreManager);
+
+        self.diminishing_returns().clear();
+    }
+
     /// Create a new distribution plan.
-    /// Expects a single payment that consists of the total amount of tokens to be distributed.
+    /// Expects a single payment (EGLD or ESDT) that consists of the total amount of tokens to be distributed.
     /// The amount per round will be calculated based on the total amount and the number of rounds.
+    /// Can be called by the owner or by reward depositors; the deposit is attributed to the caller.
     #[payable("*")]
-    #[only_owner]
     #[endpoint(createDistributionPlan)]
     fn create_distribution_plan(&self, start_round: u64, end_round: u64) {
-        let payment = self.call_value().single_esdt();
-        self.reward_token_ids()
-            .insert(payment.token_identifier.clone());
+        self.require_operation_not_paused(Operation::Distribution);
+
+        let caller = self.blockchain().get_caller();
+        self.require_owner_or_reward_depositor(&caller);
+
+        let payment = self.call_value().egld_or_single_esdt();
+        self.require_active_reward_token(&payment.token_identifier);
+        self.record_reward_contribution(&caller, &payment);
         self.create_plan(
             payment.token_identifier.clone(),
             start_round,
@@ -104,15 +354,16 @@ pub trait AdminModule:
 
     /// Remove a distribution plan.
     /// Must provide the exact plan configuration to remove.
-    #[only_owner]
     #[endpoint(removeDistributionPlan)]
     fn remove_distribution_plan(
         &self,
-        reward_token_id: TokenIdentifier,
+        reward_token_id: EgldOrEsdtTokenIdentifier,
         start_round: u64,
         end_round: u64,
         amount_per_round: BigUint,
     ) {
+        self.require_role(Role::RewardManager);
+
         self.remove_plan(reward_token_id, start_round, end_round, amount_per_round);
     }
 }
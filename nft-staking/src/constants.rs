@@ -4,7 +4,70 @@ pub const ERR_USER_HAS_NOT_ENOUGH_STAKED_BALANCE: &str = "User has not enough st
 pub const ERR_STAKING_DISABLED: &str = "Staking is disabled";
 pub const ERR_NO_UNSTAKED_ITEMS: &str = "No unstaked items";
 pub const ERR_NO_REWARDS_TO_CLAIM: &str = "No rewards to claim";
+pub const ERR_INVALID_REWARD_TOKEN: &str = "Invalid reward token";
+pub const ERR_REWARD_TOKEN_NOT_ACTIVE: &str = "Reward token not active";
+pub const ERR_REWARD_TOKEN_ALREADY_ACTIVE: &str = "Reward token already active";
+pub const ERR_REWARD_TOKEN_NOT_RETIRED: &str = "Reward token not retired";
+pub const ERR_REWARD_TOKEN_HAS_DISTRIBUTION_PLAN: &str = "Reward token has a distribution plan";
+pub const ERR_TOO_MANY_ACTIVE_REWARD_TOKENS: &str = "Too many active reward tokens";
+pub const ERR_TOO_MANY_REWARD_TOKENS: &str = "Too many reward tokens";
+pub const ERR_REWARD_TOKEN_HAS_OUTSTANDING_REWARDS: &str = "Reward token has unclaimed rewards";
+pub const ERR_REWARD_TOKEN_OUTSTANDING_REWARDS_NOT_TRACKED: &str =
+    "Reward token unclaimed rewards are not tracked";
+pub const ERR_NOT_REWARD_DEPOSITOR: &str = "Caller is not a reward depositor";
+pub const ERR_NOTHING_STAKED: &str = "Nothing is staked";
+pub const ERR_MISSING_ROLE: &str = "Caller does not have the required role";
+pub const ERR_OPERATION_PAUSED: &str = "Operation is paused";
+pub const ERR_EMERGENCY_MODE_ACTIVE: &str = "Emergency mode is active";
+pub const ERR_EMERGENCY_MODE_NOT_ACTIVE: &str = "Emergency mode is not active";
+pub const ERR_NOTHING_TO_WITHDRAW: &str = "Nothing to withdraw";
+pub const ERR_TOO_MANY_UNSTAKING_BATCHES: &str = "Too many unstaking batches";
+pub const ERR_MAX_USER_SCORE_EXCEEDED: &str = "User staking score cap exceeded";
+pub const ERR_MAX_STAKE_QUANTITY_EXCEEDED: &str = "Stake quantity cap exceeded";
+pub const ERR_INVALID_DIMINISHING_RATE: &str = "Invalid diminishing returns rate";
+pub const ERR_ADDRESS_FROZEN: &str = "Address is frozen";
+pub const ERR_ADDRESS_NOT_FROZEN: &str = "Address is not frozen";
+pub const ERR_ITEM_FROZEN: &str = "Item is frozen";
+pub const ERR_ITEM_NOT_FROZEN: &str = "Item is not frozen";
+pub const ERR_ITEM_NOT_STAKED: &str = "Item is not staked";
+pub const ERR_POSITION_TRANSFERS_DISABLED: &str = "Position transfers are disabled";
+pub const ERR_INVALID_TRANSFER_RECEIVER: &str = "Invalid transfer receiver";
+pub const ERR_RAFFLE_NOT_FOUND: &str = "Raffle not found";
+pub const ERR_RAFFLE_ALREADY_DRAWN: &str = "Raffle already drawn";
+pub const ERR_RAFFLE_NOT_DRAWN: &str = "Raffle not drawn";
+pub const ERR_INVALID_DRAW_TIMESTAMP: &str = "Invalid draw timestamp";
+pub const ERR_RAFFLE_DRAW_TOO_EARLY: &str = "Raffle cannot be drawn yet";
+pub const ERR_TOO_MANY_RAFFLE_PRIZES: &str = "Too many raffle prizes";
+pub const ERR_NO_RAFFLE_PRIZES_TO_CLAIM: &str = "No raffle prizes to claim";
+pub const ERR_RAFFLE_CLAIM_PERIOD_NOT_OVER: &str = "Raffle claim period not over";
+pub const ERR_RAFFLE_CANCELLED: &str = "Raffle cancelled";
+pub const ERR_RAFFLE_ENTRIES_CLOSED: &str = "Raffle entries closed";
+pub const ERR_RAFFLE_ALREADY_ENTERED: &str = "Raffle already entered";
+pub const ERR_TOO_MANY_RAFFLE_ENTRIES: &str = "Too many raffle entries";
+pub const ERR_RAFFLE_ENTRY_SCORE_TOO_LOW: &str = "Staked score too low to enter the raffle";
+pub const ERR_NO_STAKED_SCORE: &str = "No staked score";
+pub const ERR_SEASON_NOT_FOUND: &str = "Season not found";
+pub const ERR_INVALID_SEASON_END: &str = "Invalid season end timestamp";
+pub const ERR_SEASON_NOT_ENDED: &str = "Season has not ended";
+pub const ERR_SEASON_ENDED: &str = "Season has ended";
+pub const ERR_INVALID_SEASON_REWARD: &str = "Season rewards must be fungible tokens";
+pub const ERR_SEASON_REWARDS_ALREADY_CLAIMED: &str = "Season rewards already claimed";
+pub const ERR_NO_SEASON_REWARDS_TO_CLAIM: &str = "No season rewards to claim";
+pub const ERR_SEASON_CLAIM_PERIOD_OVER: &str = "Season claim period over";
+pub const ERR_SEASON_CLAIM_PERIOD_NOT_OVER: &str = "Season claim period not over";
+pub const ERR_SEASON_REWARDS_ALREADY_SWEPT: &str = "Season rewards already swept";
+pub const ERR_INVALID_REWARD_FEE: &str = "Invalid reward fee";
+pub const ERR_NO_FEES_TO_WITHDRAW: &str = "No fees to withdraw";
+pub const ERR_NO_TREASURY_ADDRESS: &str = "No treasury address";
 
 // default configuration
 pub const UNSTAKE_PENALTY: u64 = 7 * 24 * 3600u64; // 7 days
 pub const DEFAULT_NFT_SCORE: u64 = 1_000_000; // 1000
+pub const MAX_ACTIVE_REWARD_TOKENS: usize = 10;
+pub const MAX_REWARD_TOKENS: usize = 20; // active and retired, bounds the gas used by claims and state changes
+pub const MAX_UNSTAKING_BATCHES: usize = 50; // bounds the gas used by claimUnstaked
+pub const MAX_BPS: u64 = 10_000; // 100%
+pub const MAX_RAFFLE_PRIZES: usize = 20; // bounds the gas used by drawRaffle
+pub const MAX_RAFFLE_ENTRIES: usize = 200; // bounds the gas used by drawRaffle
+pub const SEASON_CLAIM_PERIOD: u64 = 30 * 24 * 3600u64; // 30 days
+pub const MIN_SEASON_DURATION: u64 = 24 * 3600u64; // 1 day, bounds the seasons settled on every score change
@@ -1,6 +1,9 @@
 use multiversx_sc::imports::*;
 
-use crate::constants::{ERR_NO_REWARDS_TO_CLAIM, ERR_NO_UNSTAKED_ITEMS};
+use crate::constants::{
+    ERR_INVALID_REWARD_TOKEN, ERR_NOTHING_STAKED, ERR_NOTHING_TO_WITHDRAW, ERR_NO_REWARDS_TO_CLAIM,
+    ERR_NO_UNSTAKED_ITEMS, ERR_TOO_MANY_UNSTAKING_BATCHES, MAX_UNSTAKING_BATCHES,
+};
 
 #[multiversx_sc::module]
 pub trait CoreLogic:
@@ -8,6 +11,10 @@ pub trait CoreLogic:
     + crate::utils::UtilsModule
     + crate::reward::reward_rate::RewardRateModule
     + crate::reward::planned_distribution::PlannedDistributionModule
+    + crate::roles::RolesModule
+    + crate::events::EventsModule
+    + crate::season::SeasonModule
+    + crate::pause::PauseModule
 {
     fn handle_stake(
         &self,
@@ -20,14 +27,33 @@ pub trait CoreLogic:
 
         for payment in payments.iter() {
             self.require_can_stake(&payment.token_identifier);
-            total_score += self.get_payment_score(&payment);
-            self.stake_quantity(user, &payment.token_identifier, payment.token_nonce)
-                .update(|prev| *prev += &payment.amount);
+            self.require_item_not_frozen(user, &payment.token_identifier, payment.token_nonce);
+            let payment_score = self.get_payment_score(&payment);
+            self.credit_staked_item_score(
+                user,
+                &payment.token_identifier,
+                payment.token_nonce,
+                &payment_score,
+            );
+            total_score += payment_score;
+            let quantity = self
+                .stake_quantity(user, &payment.token_identifier, payment.token_nonce)
+                .update(|prev| {
+                    *prev += &payment.amount;
+                    prev.clone()
+                });
+            self.require_within_max_stake_quantity(
+                &payment.token_identifier,
+                payment.token_nonce,
+                &quantity,
+            );
             let staked_item = (payment.token_identifier.clone(), payment.token_nonce);
             self.staked_items(user).insert(staked_item);
         }
 
-        self.handle_increase_staked_score(user, &total_score);
+        let raw_score = self.get_user_raw_score(user) + &total_score;
+        self.require_within_max_user_score(&raw_score);
+        self.update_user_score(user, raw_score);
 
         total_score
     }
@@ -38,10 +64,20 @@ pub trait CoreLogic:
         payments: ManagedVec<EsdtTokenPayment>,
     ) -> BigUint {
         self.handle_state_change(user);
+        self.migrate_legacy_unstaking_items(user);
+
+        let block_timestamp = self.blockchain().get_block_timestamp();
+        let unstaking_queue = self.unstaking_queue(user);
+        require!(
+            unstaking_queue.back() == Some(block_timestamp)
+                || unstaking_queue.len() < MAX_UNSTAKING_BATCHES,
+            ERR_TOO_MANY_UNSTAKING_BATCHES
+        );
 
         let mut total_score = BigUint::zero();
 
         for payment in payments.iter() {
+            self.require_item_not_frozen(user, &payment.token_identifier, payment.token_nonce);
             self.require_user_has_enough_staked_balance(
                 user,
                 &payment.token_identifier,
@@ -49,7 +85,12 @@ pub trait CoreLogic:
                 &payment.amount,
             );
 
-            total_score += self.get_payment_score(&payment);
+            total_score += self.debit_staked_item_score(
+                user,
+                &payment.token_identifier,
+                payment.token_nonce,
+                &payment.amount,
+            );
             self.stake_quantity(user, &payment.token_identifier, payment.token_nonce)
                 .update(|prev| *prev -= &payment.amount);
 
@@ -62,44 +103,282 @@ pub trait CoreLogic:
             }
         }
 
-        self.handle_decrease_staked_score(user, &total_score);
+        let raw_score = self.get_user_raw_score(user) - &total_score;
+        self.update_user_score(user, raw_score);
+
+        self.push_unstaking_batch(user, block_timestamp, payments);
+
+        total_score
+    }
+
+    /// Moves staked items from one user to another, along with their score.
+    /// Both users' pending rewards are stored beforehand, so rewards accrued so far stay with the sender.
+    fn handle_transfer_position(
+        &self,
+        from: &ManagedAddress,
+        to: &ManagedAddress,
+        items: &ManagedVec<EsdtTokenPayment>,
+    ) -> BigUint {
+        self.handle_state_change(from);
+        self.handle_store_all_pending_rewards(to);
+
+        let mut total_score = BigUint::zero();
+
+        for item in items.iter() {
+            self.require_item_not_frozen(from, &item.token_identifier, item.token_nonce);
+            self.require_item_not_frozen(to, &item.token_identifier, item.token_nonce);
+            self.require_user_has_enough_staked_balance(
+                from,
+                &item.token_identifier,
+                item.token_nonce,
+                &item.amount,
+            );
+
+            let item_score = self.debit_staked_item_score(
+                from,
+                &item.token_identifier,
+                item.token_nonce,
+                &item.amount,
+            );
+            self.credit_staked_item_score(
+                to,
+                &item.token_identifier,
+                item.token_nonce,
+                &item_score,
+            );
+            total_score += item_score;
+            self.stake_quantity(from, &item.token_identifier, item.token_nonce)
+                .update(|prev| *prev -= &item.amount);
+            if self
+                .stake_quantity(from, &item.token_identifier, item.token_nonce)
+                .is_empty()
+            {
+                let staked_item = (item.token_identifier.clone(), item.token_nonce);
+                self.staked_items(from).remove(&staked_item);
+            }
+
+            let quantity = self
+                .stake_quantity(to, &item.token_identifier, item.token_nonce)
+                .update(|prev| {
+                    *prev += &item.amount;
+                    prev.clone()
+                });
+            self.require_within_max_stake_quantity(
+                &item.token_identifier,
+                item.token_nonce,
+                &quantity,
+            );
+            let staked_item = (item.token_identifier.clone(), item.token_nonce);
+            self.staked_items(to).insert(staked_item);
+        }
+
+        let from_raw_score = self.get_user_raw_score(from) - &total_score;
+        self.update_user_score(from, from_raw_score);
 
-        self.unstaking_items(user)
-            .insert((self.blockchain().get_block_timestamp(), payments));
+        let to_raw_score = self.get_user_raw_score(to) + &total_score;
+        self.require_within_max_user_score(&to_raw_score);
+        self.update_user_score(to, to_raw_score);
 
         total_score
     }
 
-    fn handle_claim_unstaked(&self, user: &ManagedAddress) {
+    /// Stores the user's raw score and moves their staked score to the matching effective score.
+    /// Frozen addresses keep their raw score but have no effective score.
+    fn update_user_score(&self, user: &ManagedAddress, raw_score: BigUint) {
+        let effective_score = if self.frozen_addresses().contains(user) {
+            BigUint::zero()
+        } else {
+            self.get_effective_score(&raw_score)
+        };
+        let staked_score = self.user_staked_score(user).get();
+        self.checkpoint_season_score(user);
+
+        if effective_score >= staked_score {
+            self.handle_increase_staked_score(user, &(&effective_score - &staked_score));
+        } else {
+            self.handle_decrease_staked_score(user, &(&staked_score - &effective_score));
+        }
+
+        self.user_raw_score(user).set(raw_score);
+    }
+
+    /// Claims at most `max_batches` unlocked batches, oldest first.
+    fn handle_claim_unstaked(&self, user: &ManagedAddress, max_batches: usize) {
+        self.migrate_legacy_unstaking_items(user);
+
         let block_timestamp = self.blockchain().get_block_timestamp();
         let unstake_penalty = self.unstaking_penalty().get();
-        let mut has_unstaked = false;
-
-        for (unstake_timestamp, payments) in self.unstaking_items(user).iter() {
-            let time_passed = block_timestamp - unstake_timestamp;
-            if time_passed >= unstake_penalty {
-                self.send().direct_multi(user, &payments);
-                self.unstaking_items(user)
-                    .remove(&(unstake_timestamp, payments));
-                has_unstaked = true;
+        let mut unstaking_queue = self.unstaking_queue(user);
+        let mut payments = ManagedVec::new();
+        let mut claimed_batches = 0;
+
+        while claimed_batches < max_batches {
+            match unstaking_queue.front() {
+                Some(unstake_timestamp)
+                    if block_timestamp - unstake_timestamp >= unstake_penalty =>
+                {
+                    unstaking_queue.pop_front();
+                    payments.append_vec(self.unstaking_batch(user, unstake_timestamp).take());
+                    claimed_batches += 1;
+                }
+                _ => break,
             }
         }
 
-        require!(has_unstaked, ERR_NO_UNSTAKED_ITEMS);
+        require!(!payments.is_empty(), ERR_NO_UNSTAKED_ITEMS);
+        self.send().direct_multi(user, &payments);
     }
 
-    fn handle_claim_rewards(&self, user: &ManagedAddress) {
-        self.handle_state_change(user);
+    /// Appends a batch to the user's unstaking queue.
+    /// Items unstaked at the same timestamp as the last batch are merged into it.
+    fn push_unstaking_batch(
+        &self,
+        user: &ManagedAddress,
+        timestamp: u64,
+        payments: ManagedVec<EsdtTokenPayment>,
+    ) {
+        let mut unstaking_queue = self.unstaking_queue(user);
+        if unstaking_queue.back() == Some(timestamp) {
+            self.unstaking_batch(user, timestamp)
+                .update(|batch| batch.append_vec(payments));
+            return;
+        }
+
+        unstaking_queue.push_back(timestamp);
+        self.unstaking_batch(user, timestamp).set(payments);
+    }
+
+    fn migrate_legacy_unstaking_items(&self, user: &ManagedAddress) {
+        let legacy_unstaking_items = self.legacy_unstaking_items(user);
+        if legacy_unstaking_items.is_empty() {
+            return;
+        }
+
+        for (unstake_timestamp, payments) in legacy_unstaking_items.iter() {
+            self.push_unstaking_batch(user, unstake_timestamp, payments);
+        }
+        self.legacy_unstaking_items(user).clear();
+    }
+
+    /// Returns every staked and unstaking item of the user, skipping reward accounting and the unstaking penalty.
+    /// Rewards that were not stored before the withdrawal are forfeited; stored rewards remain claimable.
+    /// Frozen items stay in the contract.
+    fn handle_emergency_withdraw(&self, user: &ManagedAddress) -> ManagedVec<EsdtTokenPayment> {
+        self.migrate_legacy_unstaking_items(user);
+
+        let mut payments = ManagedVec::new();
+
+        for staked_item in self.staked_items(user).iter() {
+            if self.frozen_items(user).contains(&staked_item) {
+                continue;
+            }
+
+            let (token_id, nonce) = staked_item;
+            self.staked_item_score(user, &token_id, nonce).clear();
+            let amount = self.stake_quantity(user, &token_id, nonce).take();
+            payments.push(EsdtTokenPayment::new(token_id, nonce, amount));
+        }
+        for payment in payments.iter() {
+            self.staked_items(user)
+                .remove(&(payment.token_identifier.clone(), payment.token_nonce));
+        }
+
+        let mut unstaking_queue = self.unstaking_queue(user);
+        while let Some(unstake_timestamp) = unstaking_queue.pop_front() {
+            payments.append_vec(self.unstaking_batch(user, unstake_timestamp).take());
+        }
+
+        require!(!payments.is_empty(), ERR_NOTHING_TO_WITHDRAW);
+
+        self.checkpoint_season_score(user);
+        self.forfeit_unstored_rewards(user);
+        self.user_raw_score(user).clear();
+        let user_score = self.user_staked_score(user).take();
+        self.aggregated_staked_score().update(|prev| {
+            if *prev >= user_score {
+                *prev -= &user_score;
+            } else {
+                *prev = BigUint::zero();
+            }
+        });
+
+        self.send().direct_multi(user, &payments);
+
+        payments
+    }
+
+    /// Claims the given reward tokens, or all of them if none is given.
+    /// Tokens that are not claimed keep their reward rate snapshot untouched.
+    fn handle_claim_rewards(
+        &self,
+        user: &ManagedAddress,
+        reward_token_ids: ManagedVec<EgldOrEsdtTokenIdentifier>,
+    ) {
+        self.distribute_as_planned();
+
+        let reward_token_ids = if reward_token_ids.is_empty() {
+            self.reward_token_ids().iter().collect()
+        } else {
+            reward_token_ids
+        };
+
+        let mut has_rewards = false;
         let mut reward_payments = ManagedVec::new();
-        for reward_token_id in self.reward_token_ids().iter() {
-            let reward_payment = self.handle_claim_pending_rewards(user, reward_token_id);
+        let mut fee_payments = ManagedVec::new();
+        for reward_token_id in reward_token_ids.iter() {
+            require!(
+                self.reward_token_ids().contains(&reward_token_id),
+                ERR_INVALID_REWARD_TOKEN
+            );
+
+            let reward_payment =
+                self.handle_claim_pending_rewards(user, reward_token_id.clone_value());
             if let Some(reward_payment) = reward_payment {
-                reward_payments.push(reward_payment);
+                has_rewards = true;
+
+                let fee = self.get_reward_fee_amount(&reward_payment);
+                let net_amount = &reward_payment.amount - &fee;
+                self.emit_reward_claimed_event(
+                    user,
+                    &reward_payment.token_identifier,
+                    &reward_payment.amount,
+                    &fee,
+                    &net_amount,
+                );
+
+                if fee > 0 {
+                    self.collected_fees(&reward_payment.token_identifier)
+                        .update(|collected| *collected += &fee);
+                    fee_payments.push(EgldOrEsdtTokenPayment::new(
+                        reward_payment.token_identifier.clone(),
+                        0,
+                        fee,
+                    ));
+                }
+                if net_amount > 0 {
+                    reward_payments.push(EgldOrEsdtTokenPayment::new(
+                        reward_payment.token_identifier,
+                        0,
+                        net_amount,
+                    ));
+                }
             }
         }
 
-        require!(!reward_payments.is_empty(), ERR_NO_REWARDS_TO_CLAIM);
-        self.send().direct_multi(user, &reward_payments);
+        require!(has_rewards, ERR_NO_REWARDS_TO_CLAIM);
+        if !reward_payments.is_empty() {
+            self.tx().to(user).payment(&reward_payments).transfer();
+        }
+        self.handle_collected_fees(fee_payments);
+    }
+
+    /// Keeps the fees in the contract until they are withdrawn by the owner or swept to the treasury,
+    /// so that a failing transfer to the treasury can never block claims.
+    fn handle_collected_fees(&self, fees: ManagedVec<EgldOrEsdtTokenPayment>) {
+        for fee in fees.iter() {
+            self.accumulated_fees(&fee.token_identifier)
+                .update(|accumulated| *accumulated += &fee.amount);
+        }
     }
 
     /// This function is called when any user's state changes.
@@ -115,16 +394,41 @@ pub trait CoreLogic:
         }
     }
 
-    fn handle_distribute_rewards(&self, rewards: &ManagedVec<EsdtTokenPayment>) {
+    /// Drops the rewards accrued since the user's last store, so that they no longer count as outstanding.
+    fn forfeit_unstored_rewards(&self, user: &ManagedAddress) {
+        for reward_token_id in self.reward_token_ids().iter() {
+            let rewards_scaled = self.get_unstored_rewards_scaled(user, &reward_token_id);
+            self.release_outstanding_rewards(&reward_token_id, &rewards_scaled);
+            self.user_reward_rate(user, &reward_token_id)
+                .set(self.current_reward_rate(&reward_token_id).get());
+        }
+    }
+
+    fn handle_distribute_rewards(
+        &self,
+        depositor: &ManagedAddress,
+        rewards: &ManagedVec<EgldOrEsdtTokenPayment>,
+    ) {
         self.distribute_as_planned(); // Why not?
 
         for payment in rewards.iter() {
-            if !self.reward_token_ids().contains(&payment.token_identifier) {
-                self.reward_token_ids()
-                    .insert(payment.token_identifier.clone());
-            }
-
+            self.require_active_reward_token(&payment.token_identifier);
+            // With nothing staked the rewards could not be claimed by anyone
+            require!(self.aggregated_staked_score().get() > 0, ERR_NOTHING_STAKED);
             self.handle_increase_reward_rate(&payment);
+            self.record_reward_contribution(depositor, &payment);
         }
     }
+
+    fn record_reward_contribution(
+        &self,
+        depositor: &ManagedAddress,
+        payment: &EgldOrEsdtTokenPayment,
+    ) {
+        self.reward_contributors().insert(depositor.clone());
+        self.reward_contributor_tokens(depositor)
+            .insert(payment.token_identifier.clone());
+        self.reward_contribution(depositor, &payment.token_identifier)
+            .update(|prev| *prev += &payment.amount);
+    }
 }
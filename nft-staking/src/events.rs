@@ -0,0 +1,334 @@
+#[allow(unused_imports)]
+use multiversx_sc::imports::*;
+
+use crate::pause::Operation;
+use crate::roles::Role;
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_role_granted_event(&self, role: Role, address: &ManagedAddress) {
+        self.role_granted_event(
+            role,
+            address,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn emit_role_revoked_event(&self, role: Role, address: &ManagedAddress) {
+        self.role_revoked_event(
+            role,
+            address,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn emit_operation_pause_changed_event(&self, operation: Operation, paused: bool) {
+        self.operation_pause_changed_event(
+            operation,
+            paused,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn emit_staking_disabled_changed_event(&self, disabled: bool) {
+        self.staking_disabled_changed_event(
+            disabled,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn emit_emergency_mode_changed_event(&self, active: bool) {
+        self.emergency_mode_changed_event(
+            active,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn emit_emergency_withdraw_event(
+        &self,
+        user: &ManagedAddress,
+        payments: &ManagedVec<EsdtTokenPayment>,
+    ) {
+        self.emergency_withdraw_event(
+            user,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+            payments,
+        );
+    }
+
+    fn emit_item_freeze_changed_event(
+        &self,
+        address: &ManagedAddress,
+        token_id: &TokenIdentifier,
+        nonce: u64,
+        frozen: bool,
+    ) {
+        self.item_freeze_changed_event(
+            address,
+            token_id,
+            nonce,
+            frozen,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn emit_address_freeze_changed_event(&self, address: &ManagedAddress, frozen: bool) {
+        self.address_freeze_changed_event(
+            address,
+            frozen,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn emit_position_transferred_event(
+        &self,
+        from: &ManagedAddress,
+        to: &ManagedAddress,
+        items: &ManagedVec<EsdtTokenPayment>,
+    ) {
+        self.position_transferred_event(
+            from,
+            to,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+            items,
+        );
+    }
+
+    fn emit_raffle_drawn_event(&self, raffle_id: u64, winners: &ManagedVec<ManagedAddress>) {
+        self.raffle_drawn_event(
+            raffle_id,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+            winners,
+        );
+    }
+
+    fn emit_raffle_prizes_claimed_event(
+        &self,
+        raffle_id: u64,
+        winner: &ManagedAddress,
+        prizes: &ManagedVec<EgldOrEsdtTokenPayment>,
+    ) {
+        self.raffle_prizes_claimed_event(
+            raffle_id,
+            winner,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+            prizes,
+        );
+    }
+
+    fn emit_season_started_event(&self, season_id: u64, start_timestamp: u64, end_timestamp: u64) {
+        self.season_started_event(
+            season_id,
+            start_timestamp,
+            end_timestamp,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn emit_season_rewards_claimed_event(
+        &self,
+        season_id: u64,
+        user: &ManagedAddress,
+        rewards: &ManagedVec<EgldOrEsdtTokenPayment>,
+    ) {
+        self.season_rewards_claimed_event(
+            season_id,
+            user,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+            rewards,
+        );
+    }
+
+    fn emit_reward_claimed_event(
+        &self,
+        user: &ManagedAddress,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        gross_amount: &BigUint,
+        fee: &BigUint,
+        net_amount: &BigUint,
+    ) {
+        self.reward_claimed_event(
+            user,
+            token_id,
+            gross_amount,
+            fee,
+            net_amount,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    #[event("roleGranted")]
+    fn role_granted_event(
+        &self,
+        #[indexed] role: Role,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
+
+    #[event("roleRevoked")]
+    fn role_revoked_event(
+        &self,
+        #[indexed] role: Role,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
+
+    #[event("operationPauseChanged")]
+    fn operation_pause_changed_event(
+        &self,
+        #[indexed] operation: Operation,
+        #[indexed] paused: bool,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
+
+    #[event("stakingDisabledChanged")]
+    fn staking_disabled_changed_event(
+        &self,
+        #[indexed] disabled: bool,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
+
+    #[event("emergencyModeChanged")]
+    fn emergency_mode_changed_event(
+        &self,
+        #[indexed] active: bool,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
+
+    #[event("emergencyWithdraw")]
+    fn emergency_withdraw_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+        payments: &ManagedVec<EsdtTokenPayment>,
+    );
+
+    #[event("itemFreezeChanged")]
+    fn item_freeze_changed_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] nonce: u64,
+        #[indexed] frozen: bool,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
+
+    #[event("addressFreezeChanged")]
+    fn address_freeze_changed_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] frozen: bool,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
+
+    #[event("positionTransferred")]
+    fn position_transferred_event(
+        &self,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+        items: &ManagedVec<EsdtTokenPayment>,
+    );
+
+    #[event("raffleDrawn")]
+    fn raffle_drawn_event(
+        &self,
+        #[indexed] raffle_id: u64,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+        winners: &ManagedVec<ManagedAddress>,
+    );
+
+    #[event("rafflePrizesClaimed")]
+    fn raffle_prizes_claimed_event(
+        &self,
+        #[indexed] raffle_id: u64,
+        #[indexed] winner: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+        prizes: &ManagedVec<EgldOrEsdtTokenPayment>,
+    );
+
+    #[event("seasonStarted")]
+    fn season_started_event(
+        &self,
+        #[indexed] season_id: u64,
+        #[indexed] start_timestamp: u64,
+        #[indexed] end_timestamp: u64,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
+
+    #[event("seasonRewardsClaimed")]
+    fn season_rewards_claimed_event(
+        &self,
+        #[indexed] season_id: u64,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+        rewards: &ManagedVec<EgldOrEsdtTokenPayment>,
+    );
+
+    #[event("rewardClaimed")]
+    fn reward_claimed_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] gross_amount: &BigUint,
+        #[indexed] fee: &BigUint,
+        #[indexed] net_amount: &BigUint,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
+}
@@ -0,0 +1,120 @@
+use multiversx_sc::imports::*;
+
+use crate::constants::{
+    ERR_ADDRESS_FROZEN, ERR_ADDRESS_NOT_FROZEN, ERR_ITEM_FROZEN, ERR_ITEM_NOT_FROZEN,
+    ERR_ITEM_NOT_STAKED,
+};
+use crate::roles::Role;
+
+/// Lets moderators lock reported (e.g. stolen) items or whole addresses.
+/// Pending rewards are settled before any score is removed, and given back on release.
+#[multiversx_sc::module]
+pub trait FreezeModule:
+    crate::storage::StorageModule
+    + crate::core_logic::CoreLogic
+    + crate::utils::UtilsModule
+    + crate::reward::reward_rate::RewardRateModule
+    + crate::reward::planned_distribution::PlannedDistributionModule
+    + crate::roles::RolesModule
+    + crate::events::EventsModule
+    + crate::season::SeasonModule
+    + crate::pause::PauseModule
+{
+    /// Stop a staked item of the given address from earning rewards and from being unstaked.
+    #[endpoint(freezeItem)]
+    fn freeze_item(&self, address: ManagedAddress, token_id: TokenIdentifier, nonce: u64) {
+        self.require_role(Role::Moderator);
+
+        let quantity = self.stake_quantity(&address, &token_id, nonce).get();
+        require!(quantity > 0, ERR_ITEM_NOT_STAKED);
+        require!(
+            self.frozen_items(&address)
+                .insert((token_id.clone(), nonce)),
+            ERR_ITEM_FROZEN
+        );
+
+        self.handle_state_change(&address);
+
+        // Items staked before their score was stored fall back to their current score, hence the cap.
+        let raw_score = self.get_user_raw_score(&address);
+        let item_score = BigUint::min(
+            self.get_staked_item_score(&address, &token_id, nonce),
+            raw_score.clone(),
+        );
+        self.update_user_score(&address, raw_score - &item_score);
+        self.frozen_item_score(&address, &token_id, nonce)
+            .set(item_score);
+
+        self.addresses_with_frozen_items().insert(address.clone());
+        self.emit_item_freeze_changed_event(&address, &token_id, nonce, true);
+    }
+
+    #[endpoint(releaseItem)]
+    fn release_item(&self, address: ManagedAddress, token_id: TokenIdentifier, nonce: u64) {
+        self.require_role(Role::Moderator);
+
+        require!(
+            self.frozen_items(&address)
+                .remove(&(token_id.clone(), nonce)),
+            ERR_ITEM_NOT_FROZEN
+        );
+
+        self.handle_state_change(&address);
+
+        let item_score = self.frozen_item_score(&address, &token_id, nonce).take();
+        let raw_score = self.get_user_raw_score(&address) + item_score;
+        self.update_user_score(&address, raw_score);
+
+        if self.frozen_items(&address).is_empty() {
+            self.addresses_with_frozen_items().remove(&address);
+        }
+        self.emit_item_freeze_changed_event(&address, &token_id, nonce, false);
+    }
+
+    /// Stop the given address from earning rewards and from staking, unstaking or claiming.
+    #[endpoint(freezeAddress)]
+    fn freeze_address(&self, address: ManagedAddress) {
+        self.require_role(Role::Moderator);
+
+        require!(
+            !self.frozen_addresses().contains(&address),
+            ERR_ADDRESS_FROZEN
+        );
+
+        self.handle_state_change(&address);
+        self.frozen_addresses().insert(address.clone());
+        self.update_user_score(&address, self.get_user_raw_score(&address));
+
+        self.emit_address_freeze_changed_event(&address, true);
+    }
+
+    #[endpoint(releaseAddress)]
+    fn release_address(&self, address: ManagedAddress) {
+        self.require_role(Role::Moderator);
+
+        require!(
+            self.frozen_addresses().contains(&address),
+            ERR_ADDRESS_NOT_FROZEN
+        );
+
+        self.handle_state_change(&address);
+        self.frozen_addresses().remove(&address);
+        self.update_user_score(&address, self.get_user_raw_score(&address));
+
+        self.emit_address_freeze_changed_event(&address, false);
+    }
+
+    /// Lists every frozen item along with the address that staked it.
+    #[view(getFrozenItems)]
+    fn get_frozen_items(
+        &self,
+    ) -> MultiValueEncoded<MultiValue3<ManagedAddress, TokenIdentifier, u64>> {
+        let mut frozen_items = MultiValueEncoded::new();
+        for address in self.addresses_with_frozen_items().iter() {
+            for (token_id, nonce) in self.frozen_items(&address).iter() {
+                frozen_items.push((address.clone(), token_id, nonce).into());
+            }
+        }
+        frozen_items
+    }
+}
@@ -1,14 +1,21 @@
 #![no_std]
 
-use constants::UNSTAKE_PENALTY;
+use constants::{ERR_INVALID_TRANSFER_RECEIVER, MAX_UNSTAKING_BATCHES, UNSTAKE_PENALTY};
 #[allow(unused_imports)]
 use multiversx_sc::imports::*;
+use pause::Operation;
 
 pub mod admin;
 pub mod constants;
 pub mod core_logic;
+pub mod events;
+pub mod freeze;
+pub mod pause;
 pub mod proxy;
+pub mod raffle;
 pub mod reward;
+pub mod roles;
+pub mod season;
 pub mod storage;
 pub mod utils;
 pub mod views;
@@ -24,12 +31,28 @@ pub trait NftStaking:
     + reward::reward_rate::RewardRateModule
     + reward::planned_distribution::PlannedDistributionModule
     + admin::AdminModule
+    + roles::RolesModule
+    + events::EventsModule
+    + pause::PauseModule
+    + freeze::FreezeModule
+    + raffle::RaffleModule
+    + season::SeasonModule
 {
     #[init]
     fn init(&self) {
         self.set_unstaking_penalty(UNSTAKE_PENALTY);
     }
 
+    /// Reward token identifiers were migrated from `TokenIdentifier` to `EgldOrEsdtTokenIdentifier`.
+    /// ESDT identifiers share the same encoding in both types, so existing reward storage
+    /// (token list, reward rates, stored rewards and distribution plans) is read as-is.
+    /// Reward rate snapshots now also move forward when a user has no pending rewards,
+    /// so users (re)staking from a zero score no longer earn past distributions.
+    /// Pending rewards already inflated that way before the upgrade are not corrected.
+    /// Unclaimed rewards are only tracked for reward tokens registered after the upgrade,
+    /// so the reward tokens registered before it can be retired but not removed.
+    /// The score credited for each staked item is now stored and removed on unstake.
+    /// Items staked before the upgrade use their current score until they are next staked or unstaked.
     #[upgrade]
     fn upgrade(&self) {}
 
@@ -37,8 +60,11 @@ pub trait NftStaking:
     #[endpoint(stake)]
     fn stake(&self) -> BigUint {
         self.require_staking_enabled();
+        self.require_operation_not_paused(Operation::Stake);
+        self.require_not_emergency_mode();
 
         let caller = self.blockchain().get_caller();
+        self.require_address_not_frozen(&caller);
         let payments = self.call_value().all_esdt_transfers();
 
         self.handle_stake(&caller, &payments)
@@ -47,26 +73,84 @@ pub trait NftStaking:
     #[endpoint(unstake)]
     fn unstake(&self, unstake_request: MultiValueManagedVec<EsdtTokenPayment>) -> BigUint {
         self.require_staking_enabled();
+        self.require_operation_not_paused(Operation::Unstake);
 
         let caller = self.blockchain().get_caller();
+        self.require_address_not_frozen(&caller);
         let payments = unstake_request.into_vec();
 
         self.handle_unstake(&caller, payments)
     }
 
+    /// Claim unstaked items whose unstaking penalty has passed, oldest batches first.
+    /// At most `max_batches` batches are claimed; all unlocked batches are claimed if not given.
     #[endpoint(claimUnstaked)]
-    fn claim_unstaked(&self) {
+    fn claim_unstaked(&self, max_batches: OptionalValue<usize>) {
         self.require_staking_enabled();
+        self.require_operation_not_paused(Operation::ClaimUnstaked);
 
         let caller = self.blockchain().get_caller();
-        self.handle_claim_unstaked(&caller);
+        self.require_address_not_frozen(&caller);
+        let max_batches = max_batches.into_option().unwrap_or(MAX_UNSTAKING_BATCHES);
+        self.handle_claim_unstaked(&caller, max_batches);
     }
 
+    /// Claim pending rewards.
+    /// Only the given reward tokens are claimed; if none is given, all reward tokens are claimed.
     #[endpoint(claimRewards)]
-    fn claim_rewards(&self) {
+    fn claim_rewards(&self, reward_token_ids: MultiValueEncoded<EgldOrEsdtTokenIdentifier>) {
         self.require_staking_enabled();
+        self.require_operation_not_paused(Operation::ClaimRewards);
 
         let caller = self.blockchain().get_caller();
-        self.handle_claim_rewards(&caller);
+        self.require_address_not_frozen(&caller);
+        self.handle_claim_rewards(&caller, reward_token_ids.to_vec());
+    }
+
+    /// Withdraw all staked and unstaking NFTs at once, without rewards and without waiting.
+    /// Only available while the owner has enabled emergency mode.
+    #[endpoint(emergencyWithdraw)]
+    fn emergency_withdraw(&self) {
+        self.require_emergency_mode();
+
+        let caller = self.blockchain().get_caller();
+        self.require_address_not_frozen(&caller);
+        let payments = self.handle_emergency_withdraw(&caller);
+        self.emit_emergency_withdraw_event(&caller, &payments);
+    }
+
+    /// Move staked items to another wallet without unstaking them.
+    /// Rewards accrued so far stay claimable by the sender; the receiver earns from now on.
+    #[endpoint(transferStakedPosition)]
+    fn transfer_staked_position(
+        &self,
+        to: ManagedAddress,
+        items: MultiValueManagedVec<EsdtTokenPayment>,
+    ) -> BigUint {
+        self.require_staking_enabled();
+        self.require_position_transfer_enabled();
+        self.require_not_emergency_mode();
+        self.require_operation_not_paused(Operation::Transfer);
+
+        let caller = self.blockchain().get_caller();
+        self.require_address_not_frozen(&caller);
+        self.require_address_not_frozen(&to);
+        require!(to != caller, ERR_INVALID_TRANSFER_RECEIVER);
+
+        let items = items.into_vec();
+        let total_score = self.handle_transfer_position(&caller, &to, &items);
+        self.emit_position_transferred_event(&caller, &to, &items);
+
+        total_score
+    }
+
+    /// Settle the rewards of the given user and re-apply the current diminishing returns curve to their raw score.
+    /// Anyone can call this to bring the staked score of a user in line with a changed curve.
+    #[endpoint(recomputeUserScore)]
+    fn recompute_user_score(&self, user: ManagedAddress) {
+        self.require_not_emergency_mode();
+
+        self.handle_state_change(&user);
+        self.update_user_score(&user, self.get_user_raw_score(&user));
     }
 }
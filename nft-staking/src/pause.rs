@@ -0,0 +1,88 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::constants::ERR_OPERATION_PAUSED;
+use crate::roles::Role;
+
+/// Operations that can be paused independently of each other.
+/// `Distribution` only covers new reward deposits; running distribution plans keep emitting.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Stake,
+    Unstake,
+    ClaimUnstaked,
+    ClaimRewards,
+    Distribution,
+    Transfer,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct PauseState {
+    pub staking_disabled: bool,
+    pub emergency_mode: bool,
+    pub stake_paused: bool,
+    pub unstake_paused: bool,
+    pub claim_unstaked_paused: bool,
+    pub claim_rewards_paused: bool,
+    pub distribution_paused: bool,
+    pub transfer_paused: bool,
+}
+
+#[multiversx_sc::module]
+pub trait PauseModule:
+    crate::storage::StorageModule + crate::roles::RolesModule + crate::events::EventsModule
+{
+    #[endpoint(pauseOperations)]
+    fn pause_operations(&self, operations: MultiValueEncoded<Operation>) {
+        self.require_role(Role::Pauser);
+
+        for operation in operations {
+            self.set_operation_paused(operation, true);
+        }
+    }
+
+    #[endpoint(unpauseOperations)]
+    fn unpause_operations(&self, operations: MultiValueEncoded<Operation>) {
+        self.require_role(Role::Pauser);
+
+        for operation in operations {
+            self.set_operation_paused(operation, false);
+        }
+    }
+
+    #[view(getPauseState)]
+    fn get_pause_state(&self) -> PauseState {
+        PauseState {
+            staking_disabled: self.staking_disabled().get(),
+            emergency_mode: self.emergency_mode().get(),
+            stake_paused: self.operation_paused(Operation::Stake).get(),
+            unstake_paused: self.operation_paused(Operation::Unstake).get(),
+            claim_unstaked_paused: self.operation_paused(Operation::ClaimUnstaked).get(),
+            claim_rewards_paused: self.operation_paused(Operation::ClaimRewards).get(),
+            distribution_paused: self.operation_paused(Operation::Distribution).get(),
+            transfer_paused: self.operation_paused(Operation::Transfer).get(),
+        }
+    }
+
+    fn set_operation_paused(&self, operation: Operation, paused: bool) {
+        if self.operation_paused(operation).get() == paused {
+            return;
+        }
+
+        self.operation_paused(operation).set(paused);
+        self.emit_operation_pause_changed_event(operation, paused);
+    }
+
+    fn require_operation_not_paused(&self, operation: Operation) {
+        require!(
+            !self.operation_paused(operation).get(),
+            ERR_OPERATION_PAUSED
+        );
+    }
+
+    #[view(isOperationPaused)]
+    #[storage_mapper("operationPaused")]
+    fn operation_paused(&self, operation: Operation) -> SingleValueMapper<bool>;
+}
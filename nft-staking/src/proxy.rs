@@ -62,6 +62,16 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
+    /// Reward token identifiers were migrated from `TokenIdentifier` to `EgldOrEsdtTokenIdentifier`. 
+    /// ESDT identifiers share the same encoding in both types, so existing reward storage 
+    /// (token list, reward rates, stored rewards and distribution plans) is read as-is. 
+    /// Reward rate snapshots now also move forward when a user has no pending rewards, 
+    /// so users (re)staking from a zero score no longer earn past distributions. 
+    /// Pending rewards already inflated that way before the upgrade are not corrected. 
+    /// Unclaimed rewards are only tracked for reward tokens registered after the upgrade, 
+    /// so the reward tokens registered before it can be retired but not removed. 
+    /// The score credited for each staked item is now stored and removed on unstake. 
+    /// Items staked before the upgrade use their current score until they are next staked or unstaked. 
     pub fn upgrade(
         self,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
@@ -102,187 +112,198 @@ where
             .original_result()
     }
 
-    pub fn claim_unstaked(
+    /// Claim unstaked items whose unstaking penalty has passed, oldest batches first. 
+    /// At most `max_batches` batches are claimed; all unlocked batches are claimed if not given. 
+    pub fn claim_unstaked<
+        Arg0: ProxyArg<OptionalValue<usize>>,
+    >(
         self,
+        max_batches: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("claimUnstaked")
+            .argument(&max_batches)
             .original_result()
     }
 
-    pub fn claim_rewards(
+    /// Claim pending rewards. 
+    /// Only the given reward tokens are claimed; if none is given, all reward tokens are claimed. 
+    pub fn claim_rewards<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
         self,
+        reward_token_ids: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("claimRewards")
+            .argument(&reward_token_ids)
             .original_result()
     }
 
-    pub fn allowed_nft_collections(
+    /// Withdraw all staked and unstaking NFTs at once, without rewards and without waiting. 
+    /// Only available while the owner has enabled emergency mode. 
+    pub fn emergency_withdraw(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TokenIdentifier<Env::Api>>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAllowedNftCollections")
+            .raw_call("emergencyWithdraw")
             .original_result()
     }
 
-    pub fn reward_token_ids(
+    /// Move staked items to another wallet without unstaking them. 
+    /// Rewards accrued so far stay claimable by the sender; the receiver earns from now on. 
+    pub fn transfer_staked_position<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<MultiValueManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TokenIdentifier<Env::Api>>> {
+        to: Arg0,
+        items: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getRewardTokenIds")
+            .raw_call("transferStakedPosition")
+            .argument(&to)
+            .argument(&items)
             .original_result()
     }
 
-    pub fn stake_quantity<
+    /// Settle the rewards of the given user and re-apply the current diminishing returns curve to their raw score. 
+    /// Anyone can call this to bring the staked score of a user in line with a changed curve. 
+    pub fn recompute_user_score<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
-        Arg2: ProxyArg<u64>,
     >(
         self,
-        address: Arg0,
-        token_id: Arg1,
-        nonce: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getStakeQuantityRaw")
-            .argument(&address)
-            .argument(&token_id)
-            .argument(&nonce)
+            .raw_call("recomputeUserScore")
+            .argument(&user)
             .original_result()
     }
 
-    pub fn staked_items<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-    >(
+    pub fn allowed_nft_collections(
         self,
-        address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, (TokenIdentifier<Env::Api>, u64)>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TokenIdentifier<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getStakedItemsRaw")
-            .argument(&address)
+            .raw_call("getAllowedNftCollections")
             .original_result()
     }
 
-    pub fn nft_collection_score<
-        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
-    >(
+    pub fn reward_token_ids(
         self,
-        token_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getNftCollectionScore")
-            .argument(&token_id)
+            .raw_call("getRewardTokenIds")
             .original_result()
     }
 
-    pub fn nft_collection_nonce_score<
-        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
-        Arg1: ProxyArg<u64>,
-    >(
+    /// Subset of `reward_token_ids` that no longer accrue rewards but can still be claimed. 
+    pub fn retired_reward_token_ids(
         self,
-        token_id: Arg0,
-        nonce: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getNftCollectionNonceScore")
-            .argument(&token_id)
-            .argument(&nonce)
+            .raw_call("getRetiredRewardTokenIds")
             .original_result()
     }
 
-    pub fn staking_disabled(
+    pub fn reward_depositors(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getStakingDisabled")
+            .raw_call("getRewardDepositors")
             .original_result()
     }
 
-    pub fn unstaking_items<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-    >(
+    /// Every address that has ever deposited rewards, including the owner and former depositors. 
+    pub fn reward_contributors(
         self,
-        address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, (u64, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>)>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getUnstakingItemsRaw")
-            .argument(&address)
+            .raw_call("getRewardContributors")
             .original_result()
     }
 
-    pub fn unstaking_penalty(
+    pub fn reward_contributor_tokens<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        contributor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getUnstakingPenalty")
+            .raw_call("getRewardContributorTokens")
+            .argument(&contributor)
             .original_result()
     }
 
-    pub fn get_staking_info<
+    pub fn reward_contribution<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
-        address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, StakingInfo<Env::Api>> {
+        contributor: Arg0,
+        token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getStakingInfo")
-            .argument(&address)
+            .raw_call("getRewardContribution")
+            .argument(&contributor)
+            .argument(&token_id)
             .original_result()
     }
 
-    pub fn get_pending_rewards_view<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    /// Protocol fee deducted from claimed rewards of the token, in basis points. 
+    pub fn reward_fee<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
-        address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getPendingRewards")
-            .argument(&address)
+            .raw_call("getRewardFee")
+            .argument(&token_id)
             .original_result()
     }
 
-    pub fn get_staked_items_snapshot<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    pub fn accumulated_fees<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
-        address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getStakedItems")
-            .argument(&address)
+            .raw_call("getAccumulatedFees")
+            .argument(&token_id)
             .original_result()
     }
 
-    pub fn get_unstaking_items<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    /// Total fees collected for the token, whether sent to the treasury or accumulated. 
+    pub fn collected_fees<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
-        address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, UnstakingBatch<Env::Api>>> {
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getUnstakingItems")
-            .argument(&address)
+            .raw_call("getCollectedFees")
+            .argument(&token_id)
             .original_result()
     }
 
-    pub fn get_stake_quantity<
+    pub fn stake_quantity<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
         Arg2: ProxyArg<u64>,
@@ -294,45 +315,47 @@ where
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getStakeQuantity")
+            .raw_call("getStakeQuantityRaw")
             .argument(&address)
             .argument(&token_id)
             .argument(&nonce)
             .original_result()
     }
 
-    pub fn get_user_staking_score<
+    /// Raw score credited for the staked quantity of the item, at the scores in effect when it was staked. 
+    pub fn staked_item_score<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<u64>,
     >(
         self,
         address: Arg0,
+        token_id: Arg1,
+        nonce: Arg2,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getUserStakingScore")
+            .raw_call("getStakedItemScoreRaw")
             .argument(&address)
+            .argument(&token_id)
+            .argument(&nonce)
             .original_result()
     }
 
-    pub fn get_aggregated_staking_score(
-        self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
-        self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("getAggregatedStakingScore")
-            .original_result()
-    }
-
-    pub fn get_last_distribution_round(
+    pub fn staked_items<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, (TokenIdentifier<Env::Api>, u64)>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getLastDistributionRound")
+            .raw_call("getStakedItemsRaw")
+            .argument(&address)
             .original_result()
     }
 
-    pub fn get_reward_rate<
+    pub fn nft_collection_score<
         Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
     >(
         self,
@@ -340,297 +363,1482 @@ where
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getRewardRate")
+            .raw_call("getNftCollectionScore")
             .argument(&token_id)
             .original_result()
     }
 
-    pub fn is_reward_token<
+    pub fn nft_collection_nonce_score<
         Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
     >(
         self,
         token_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("isRewardToken")
+            .raw_call("getNftCollectionNonceScore")
             .argument(&token_id)
+            .argument(&nonce)
             .original_result()
     }
 
-    pub fn get_pending_token_reward<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
-    >(
+    /// Maximum raw staking score a single user can reach. No cap if empty. 
+    pub fn max_user_score(
         self,
-        address: Arg0,
-        token_id: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getPendingTokenReward")
-            .argument(&address)
-            .argument(&token_id)
+            .raw_call("getMaxUserScore")
             .original_result()
     }
 
-    pub fn user_staked_score<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    /// Maximum quantity a single user can stake of the given nonce. No cap if empty. 
+    pub fn max_stake_quantity_per_nonce<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
     >(
         self,
-        address: Arg0,
+        collection: Arg0,
+        nonce: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getUserStakedScore")
-            .argument(&address)
+            .raw_call("getMaxStakeQuantityPerNonce")
+            .argument(&collection)
+            .argument(&nonce)
             .original_result()
     }
 
-    pub fn aggregated_staked_score(
+    /// Raw score threshold and rate (in bps) applied to the part of a user's raw score above the threshold. 
+    pub fn diminishing_returns(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, (BigUint<Env::Api>, u64)> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAggregatedStakedScore")
+            .raw_call("getDiminishingReturns")
             .original_result()
     }
 
-    pub fn current_reward_rate<
-        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
-    >(
+    /// Addresses whose whole position is frozen: they earn no rewards and cannot interact with the contract. 
+    pub fn frozen_addresses(
         self,
-        reward_token_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getCurrentRewardRate")
-            .argument(&reward_token_id)
+            .raw_call("getFrozenAddresses")
             .original_result()
     }
 
-    pub fn user_reward_rate<
+    /// Staked items of the user that no longer contribute score and cannot be unstaked. 
+    pub fn frozen_items<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
     >(
         self,
-        user: Arg0,
-        reward_token_id: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, (TokenIdentifier<Env::Api>, u64)>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getUserRewardRate")
-            .argument(&user)
-            .argument(&reward_token_id)
+            .raw_call("getFrozenItemsRaw")
+            .argument(&address)
             .original_result()
     }
 
-    pub fn user_stored_rewards<
+    /// Raw score removed from the user when the item was frozen, given back on release. 
+    pub fn frozen_item_score<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<u64>,
     >(
         self,
-        user: Arg0,
-        reward_token_id: Arg1,
+        address: Arg0,
+        token_id: Arg1,
+        nonce: Arg2,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getUserStoredRewards")
-            .argument(&user)
-            .argument(&reward_token_id)
+            .raw_call("getFrozenItemScore")
+            .argument(&address)
+            .argument(&token_id)
+            .argument(&nonce)
             .original_result()
     }
 
-    pub fn get_amount_per_round<
-        Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<u64>,
-        Arg2: ProxyArg<BigUint<Env::Api>>,
-    >(
+    pub fn addresses_with_frozen_items(
         self,
-        start_round: Arg0,
-        end_round: Arg1,
-        total_distribution_amount: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getDistributionAmountPerRound")
-            .argument(&start_round)
-            .argument(&end_round)
-            .argument(&total_distribution_amount)
+            .raw_call("getAddressesWithFrozenItems")
             .original_result()
     }
 
-    pub fn last_distribution_round(
+    pub fn position_transfer_enabled(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getLastDistributionRoundRaw")
+            .raw_call("isPositionTransferEnabled")
             .original_result()
     }
 
-    pub fn distribution_plans(
+    pub fn staking_disabled(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, (TokenIdentifier<Env::Api>, u64, u64, BigUint<Env::Api>)>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getDistributionPlanRaw")
+            .raw_call("getStakingDisabled")
             .original_result()
     }
 
-    pub fn disable_staking(
+    /// When set, users can withdraw all their NFTs through `emergencyWithdraw`, bypassing reward accounting. 
+    pub fn emergency_mode(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("disableStaking")
+            .raw_call("isEmergencyMode")
             .original_result()
     }
 
-    pub fn enable_staking(
+    /// Timestamps of the user's unstaking batches, oldest first. 
+    pub fn unstaking_queue<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("enableStaking")
+            .raw_call("getUnstakingQueueRaw")
+            .argument(&address)
             .original_result()
     }
 
-    pub fn allow_collections<
-        Arg0: ProxyArg<MultiValueManagedVec<Env::Api, TokenIdentifier<Env::Api>>>,
+    pub fn unstaking_batch<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
     >(
         self,
-        collections: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        address: Arg0,
+        timestamp: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("allowCollections")
-            .argument(&collections)
+            .raw_call("getUnstakingBatchRaw")
+            .argument(&address)
+            .argument(&timestamp)
             .original_result()
     }
 
-    /// I don't recommend using this function as it won't update user's storage. 
-    /// Its effect as of now is to stop other users from staking NFTs from the given collections. 
-    pub fn disallow_collections<
-        Arg0: ProxyArg<MultiValueManagedVec<Env::Api, TokenIdentifier<Env::Api>>>,
-    >(
+    pub fn unstaking_penalty(
         self,
-        collections: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("disallowCollections")
+            .raw_call("getUnstakingPenalty")
+            .original_result()
+    }
+
+    pub fn get_staking_info<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, StakingInfo<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakingInfo")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Pending rewards, net of the reward fee deducted when claiming. 
+    pub fn get_pending_rewards_view<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingRewards")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_staked_items_snapshot<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakedItems")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Staked items of the user along with their on-chain metadata and the score credited for them. 
+    /// Returns at most `size` items, starting from the `from` index. 
+    pub fn get_staked_items_detailed<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        address: Arg0,
+        from: Arg1,
+        size: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, StakedItemDetails<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakedItemsDetailed")
+            .argument(&address)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    pub fn get_staked_item_count<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakedItemCount")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_unstaking_items<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, UnstakingBatch<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnstakingItems")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Same batches as `getUnstakingItems`, kept with the encoding of the former storage view. 
+    pub fn get_unstaking_items_raw<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, (u64, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>)>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnstakingItemsRaw")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Timestamp at which the oldest unstaking batch of the user can be claimed, if any. 
+    pub fn get_next_unlock_time<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNextUnlockTime")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_stake_quantity<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        address: Arg0,
+        token_id: Arg1,
+        nonce: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakeQuantity")
+            .argument(&address)
+            .argument(&token_id)
+            .argument(&nonce)
+            .original_result()
+    }
+
+    pub fn get_user_staking_score<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserStakingScore")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Share of the raw score of the user that counts towards their staked score, in bps. 
+    /// Reflects the diminishing returns applied to the user, and is zero for frozen addresses. 
+    pub fn get_effective_score_bps<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEffectiveScoreBps")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Staking score of the user before diminishing returns are applied. 
+    pub fn get_user_raw_staking_score<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserRawStakingScore")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_aggregated_staking_score(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAggregatedStakingScore")
+            .original_result()
+    }
+
+    pub fn get_last_distribution_round(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastDistributionRound")
+            .original_result()
+    }
+
+    pub fn get_reward_rate<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardRate")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn is_reward_token<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isRewardToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn get_active_reward_token_ids(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActiveRewardTokenIds")
+            .original_result()
+    }
+
+    /// The treasury fees are sent to, if any. Otherwise fees accumulate in the contract. 
+    pub fn get_treasury_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTreasuryAddress")
+            .original_result()
+    }
+
+    /// Lists the amount contributed by the given depositor for each reward token. 
+    pub fn get_reward_contributions<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        depositor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardContributions")
+            .argument(&depositor)
+            .original_result()
+    }
+
+    /// Pending rewards of the token, net of the reward fee deducted when claiming. 
+    pub fn get_pending_token_reward<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+        token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingTokenReward")
+            .argument(&address)
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Distributed rewards of the token that were not claimed yet. 
+    pub fn get_outstanding_rewards<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOutstandingRewards")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn get_unstored_rewards_for_token<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        reward_token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnstoredRewardsForToken")
+            .argument(&user)
+            .argument(&reward_token_id)
+            .original_result()
+    }
+
+    pub fn user_staked_score<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserStakedScore")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn aggregated_staked_score(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAggregatedStakedScore")
+            .original_result()
+    }
+
+    pub fn current_reward_rate<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        reward_token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentRewardRate")
+            .argument(&reward_token_id)
+            .original_result()
+    }
+
+    pub fn user_reward_rate<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        reward_token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserRewardRate")
+            .argument(&user)
+            .argument(&reward_token_id)
+            .original_result()
+    }
+
+    /// Set for the tokens registered since the outstanding amount is tracked, 
+    /// whose outstanding amount therefore covers all their distributed rewards. 
+    pub fn outstanding_rewards_tracked<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        reward_token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isOutstandingRewardsTracked")
+            .argument(&reward_token_id)
+            .original_result()
+    }
+
+    pub fn user_stored_rewards<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        reward_token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserStoredRewards")
+            .argument(&user)
+            .argument(&reward_token_id)
+            .original_result()
+    }
+
+    pub fn get_amount_per_round<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        start_round: Arg0,
+        end_round: Arg1,
+        total_distribution_amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDistributionAmountPerRound")
+            .argument(&start_round)
+            .argument(&end_round)
+            .argument(&total_distribution_amount)
+            .original_result()
+    }
+
+    pub fn last_distribution_round(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastDistributionRoundRaw")
+            .original_result()
+    }
+
+    pub fn distribution_plans(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, (EgldOrEsdtTokenIdentifier<Env::Api>, u64, u64, BigUint<Env::Api>)>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDistributionPlanRaw")
+            .original_result()
+    }
+
+    pub fn disable_staking(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("disableStaking")
+            .original_result()
+    }
+
+    pub fn enable_staking(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("enableStaking")
+            .original_result()
+    }
+
+    /// Let users withdraw their NFTs through `emergencyWithdraw`, without any reward accounting. 
+    /// New stakes are rejected while emergency mode is active. 
+    pub fn enable_emergency_mode(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("enableEmergencyMode")
+            .original_result()
+    }
+
+    pub fn disable_emergency_mode(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("disableEmergencyMode")
+            .original_result()
+    }
+
+    /// Enable or disable `transferStakedPosition`. 
+    pub fn set_position_transfer_enabled<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPositionTransferEnabled")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    pub fn allow_collections<
+        Arg0: ProxyArg<MultiValueManagedVec<Env::Api, TokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        collections: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("allowCollections")
+            .argument(&collections)
+            .original_result()
+    }
+
+    /// I don't recommend using this function as it won't update user's storage. 
+    /// Its effect as of now is to stop other users from staking NFTs from the given collections. 
+    pub fn disallow_collections<
+        Arg0: ProxyArg<MultiValueManagedVec<Env::Api, TokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        collections: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("disallowCollections")
             .argument(&collections)
             .original_result()
     }
 
-    /// Distribute rewards to all stakers. 
-    /// Expects at least a payment that consists of the total amount of tokens to be distributed. 
-    /// Used for unscheduled reward distributions (e.g. airdrop, campaigns, module integrations etc). 
-    pub fn distribute_rewards(
+    /// Register a reward token, or reactivate a retired one. 
+    /// Only active reward tokens can be distributed, either manually or through a plan. 
+    pub fn add_reward_token<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addRewardToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Stop a reward token from accruing new rewards. 
+    /// Already accrued rewards remain claimable until the token is removed. 
+    /// Distribution plans for the token must be removed beforehand. 
+    pub fn retire_reward_token<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("retireRewardToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Remove a retired reward token from the registry, once all its distributed rewards are claimed. 
+    /// Tokens registered before the unclaimed rewards were tracked cannot be removed, 
+    /// as their rewards distributed back then could still be unclaimed. 
+    pub fn remove_reward_token<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeRewardToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Allow an address (e.g. a royalties or sales contract) to deposit rewards. 
+    pub fn add_reward_depositor<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        depositor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addRewardDepositor")
+            .argument(&depositor)
+            .original_result()
+    }
+
+    /// Revoke the reward depositor role. 
+    /// Contributions made so far are kept for attribution. 
+    pub fn remove_reward_depositor<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        depositor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeRewardDepositor")
+            .argument(&depositor)
+            .original_result()
+    }
+
+    /// Set the protocol fee deducted from claimed rewards of the token, in basis points. 0 disables it. 
+    pub fn set_reward_fee<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token_id: Arg0,
+        fee_bps: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRewardFee")
+            .argument(&token_id)
+            .argument(&fee_bps)
+            .original_result()
+    }
+
+    /// Set the treasury accumulated fees can be swept to. 
+    /// Without an address, fees stay in the contract until withdrawn by the owner. 
+    pub fn set_treasury_address<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        treasury: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTreasuryAddress")
+            .argument(&treasury)
+            .original_result()
+    }
+
+    /// Withdraw the fees accumulated for the given tokens. 
+    pub fn withdraw_fees<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        token_ids: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawFees")
+            .argument(&token_ids)
+            .original_result()
+    }
+
+    /// Send the fees accumulated for the given tokens to the treasury. Can be called by anyone. 
+    pub fn sweep_fees_to_treasury<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        token_ids: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("sweepFeesToTreasury")
+            .argument(&token_ids)
+            .original_result()
+    }
+
+    /// Distribute rewards to all stakers. 
+    /// Expects at least a payment that consists of the total amount of tokens to be distributed. 
+    /// Accepts both EGLD and ESDT payments, as long as they are active reward tokens. 
+    /// Used for unscheduled reward distributions (e.g. airdrop, campaigns, module integrations etc). 
+    /// Can be called by the owner or by reward depositors; each deposit is attributed to the caller. 
+    pub fn distribute_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("distributeRewards")
+            .original_result()
+    }
+
+    /// Set the unstaking penalty. 
+    /// A period of time in seconds that users have to wait before they can claim their unstaked NFTs. 
+    /// Changing this value will affect all users and ongoing unstaking processes. 
+    pub fn set_unstaking_penalty<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        penalty: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setUnstakingPenalty")
+            .argument(&penalty)
+            .original_result()
+    }
+
+    /// Change the score for all NFTs in the collection. 
+    /// Will also add the collection to the list of allowed collections. 
+    /// This will *NOT* update the score for already staked NFTs. 
+    pub fn set_collection_score<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        collection: Arg0,
+        score: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCollectionScore")
+            .argument(&collection)
+            .argument(&score)
+            .original_result()
+    }
+
+    /// Change the score for a specific nonce of an NFT in the collection. 
+    /// Will also add the collection to the list of allowed collections. 
+    /// This will *NOT* update the score for already staked NFTs. 
+    pub fn set_collection_nonce_score<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        collection: Arg0,
+        nonce: Arg1,
+        score: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCollectionNonceScore")
+            .argument(&collection)
+            .argument(&nonce)
+            .argument(&score)
+            .original_result()
+    }
+
+    /// Cap the raw staking score of a single user. Zero removes the cap. 
+    /// Only enforced on new stakes: users already above a lowered cap keep their score, 
+    /// but cannot stake more until they are back under it. 
+    pub fn set_max_user_score<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_score: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxUserScore")
+            .argument(&max_score)
+            .original_result()
+    }
+
+    /// Cap the quantity a single user can stake of the given nonce. Zero removes the cap. 
+    /// Only enforced on new stakes. 
+    pub fn set_max_stake_quantity_per_nonce<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        collection: Arg0,
+        nonce: Arg1,
+        max_quantity: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxStakeQuantityPerNonce")
+            .argument(&collection)
+            .argument(&nonce)
+            .argument(&max_quantity)
+            .original_result()
+    }
+
+    /// Only `rate_bps` of the part of a user's raw score above `threshold` counts towards their staked score. 
+    /// This will *NOT* update the score of users until their next stake or unstake, 
+    /// or until `recomputeUserScore` is called for them. 
+    pub fn set_diminishing_returns<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        threshold: Arg0,
+        rate_bps: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDiminishingReturns")
+            .argument(&threshold)
+            .argument(&rate_bps)
+            .original_result()
+    }
+
+    /// This will *NOT* update the score of users until their next stake or unstake, 
+    /// or until `recomputeUserScore` is called for them. 
+    pub fn clear_diminishing_returns(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearDiminishingReturns")
+            .original_result()
+    }
+
+    /// Create a new distribution plan. 
+    /// Expects a single payment (EGLD or ESDT) that consists of the total amount of tokens to be distributed. 
+    /// The amount per round will be calculated based on the total amount and the number of rounds. 
+    /// Can be called by the owner or by reward depositors; the deposit is attributed to the caller. 
+    pub fn create_distribution_plan<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        start_round: Arg0,
+        end_round: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("createDistributionPlan")
+            .argument(&start_round)
+            .argument(&end_round)
+            .original_result()
+    }
+
+    /// Remove a distribution plan. 
+    /// Must provide the exact plan configuration to remove. 
+    pub fn remove_distribution_plan<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        reward_token_id: Arg0,
+        start_round: Arg1,
+        end_round: Arg2,
+        amount_per_round: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeDistributionPlan")
+            .argument(&reward_token_id)
+            .argument(&start_round)
+            .argument(&end_round)
+            .argument(&amount_per_round)
+            .original_result()
+    }
+
+    pub fn grant_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("grantRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn revoke_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn has_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_all_role_holders(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<Role, ManagedVec<Env::Api, ManagedAddress<Env::Api>>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllRoleHolders")
+            .original_result()
+    }
+
+    pub fn role_holders<
+        Arg0: ProxyArg<Role>,
+    >(
+        self,
+        role: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRoleHolders")
+            .argument(&role)
+            .original_result()
+    }
+
+    pub fn pause_operations<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, Operation>>,
+    >(
+        self,
+        operations: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseOperations")
+            .argument(&operations)
+            .original_result()
+    }
+
+    pub fn unpause_operations<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, Operation>>,
+    >(
+        self,
+        operations: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpauseOperations")
+            .argument(&operations)
+            .original_result()
+    }
+
+    pub fn get_pause_state(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PauseState> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPauseState")
+            .original_result()
+    }
+
+    pub fn operation_paused<
+        Arg0: ProxyArg<Operation>,
+    >(
+        self,
+        operation: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isOperationPaused")
+            .argument(&operation)
+            .original_result()
+    }
+
+    /// Stop a staked item of the given address from earning rewards and from being unstaked. 
+    pub fn freeze_item<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        address: Arg0,
+        token_id: Arg1,
+        nonce: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("freezeItem")
+            .argument(&address)
+            .argument(&token_id)
+            .argument(&nonce)
+            .original_result()
+    }
+
+    pub fn release_item<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        address: Arg0,
+        token_id: Arg1,
+        nonce: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("releaseItem")
+            .argument(&address)
+            .argument(&token_id)
+            .argument(&nonce)
+            .original_result()
+    }
+
+    /// Stop the given address from earning rewards and from staking, unstaking or claiming. 
+    pub fn freeze_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("freezeAddress")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn release_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("releaseAddress")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Lists every frozen item along with the address that staked it. 
+    pub fn get_frozen_items(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<ManagedAddress<Env::Api>, TokenIdentifier<Env::Api>, u64>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFrozenItems")
+            .original_result()
+    }
+
+    /// Create a raffle drawn at `draw_timestamp`, open to stakers with at least `min_entry_score` staked score. 
+    /// Winners can claim their prizes during `claim_period` seconds after the draw timestamp; 
+    /// prizes not claimed by then can be rolled over to another raffle. 
+    pub fn create_raffle<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        draw_timestamp: Arg0,
+        claim_period: Arg1,
+        min_entry_score: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createRaffle")
+            .argument(&draw_timestamp)
+            .argument(&claim_period)
+            .argument(&min_entry_score)
+            .original_result()
+    }
+
+    /// Deposit prizes (EGLD, ESDTs or NFTs) for a raffle that was not drawn yet. 
+    pub fn add_raffle_prizes<
+        Arg0: ProxyArg<u64>,
+    >(
         self,
+        raffle_id: Arg0,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
-            .raw_call("distributeRewards")
+            .raw_call("addRafflePrizes")
+            .argument(&raffle_id)
             .original_result()
     }
 
-    /// Set the unstaking penalty. 
-    /// A period of time in seconds that users have to wait before they can claim their unstaked NFTs. 
-    /// Changing this value will affect all users and ongoing unstaking processes. 
-    pub fn set_unstaking_penalty<
+    /// Return the prizes of a raffle that was not drawn yet to the owner. The raffle can no longer be drawn. 
+    pub fn cancel_raffle<
         Arg0: ProxyArg<u64>,
     >(
         self,
-        penalty: Arg0,
+        raffle_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setUnstakingPenalty")
-            .argument(&penalty)
+            .raw_call("cancelRaffle")
+            .argument(&raffle_id)
             .original_result()
     }
 
-    /// Change the score for all NFTs in the collection. 
-    /// Will also add the collection to the list of allowed collections. 
-    /// This will *NOT* update the score for already staked NFTs. 
-    pub fn set_collection_score<
-        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
-        Arg1: ProxyArg<u64>,
+    /// Enter the caller in a raffle, until its draw timestamp. 
+    /// If the raffle is full, the caller replaces the entrant with the lowest score, provided theirs is higher. 
+    pub fn enter_raffle<
+        Arg0: ProxyArg<u64>,
     >(
         self,
-        collection: Arg0,
-        score: Arg1,
+        raffle_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setCollectionScore")
-            .argument(&collection)
-            .argument(&score)
+            .raw_call("enterRaffle")
+            .argument(&raffle_id)
             .original_result()
     }
 
-    /// Change the score for a specific nonce of an NFT in the collection. 
-    /// Will also add the collection to the list of allowed collections. 
-    /// This will *NOT* update the score for already staked NFTs. 
-    pub fn set_collection_nonce_score<
-        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
-        Arg1: ProxyArg<u64>,
-        Arg2: ProxyArg<u64>,
+    /// Pick the winners of a raffle once its draw timestamp has passed. 
+    pub fn draw_raffle<
+        Arg0: ProxyArg<u64>,
     >(
         self,
-        collection: Arg0,
-        nonce: Arg1,
-        score: Arg2,
+        raffle_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setCollectionNonceScore")
-            .argument(&collection)
-            .argument(&nonce)
-            .argument(&score)
+            .raw_call("drawRaffle")
+            .argument(&raffle_id)
             .original_result()
     }
 
-    /// Create a new distribution plan. 
-    /// Expects a single payment that consists of the total amount of tokens to be distributed. 
-    /// The amount per round will be calculated based on the total amount and the number of rounds. 
-    pub fn create_distribution_plan<
+    pub fn claim_raffle_prizes<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        raffle_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimRafflePrizes")
+            .argument(&raffle_id)
+            .original_result()
+    }
+
+    /// Move prizes that were not won or not claimed during the claim period to a raffle that was not drawn yet. 
+    pub fn rollover_raffle_prizes<
         Arg0: ProxyArg<u64>,
         Arg1: ProxyArg<u64>,
     >(
         self,
-        start_round: Arg0,
-        end_round: Arg1,
+        from_raffle_id: Arg0,
+        to_raffle_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rolloverRafflePrizes")
+            .argument(&from_raffle_id)
+            .argument(&to_raffle_id)
+            .original_result()
+    }
+
+    pub fn get_raffle<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        raffle_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RaffleInfo<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRaffle")
+            .argument(&raffle_id)
+            .original_result()
+    }
+
+    pub fn last_raffle_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastRaffleId")
+            .original_result()
+    }
+
+    pub fn raffle_entries<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        raffle_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRaffleEntries")
+            .argument(&raffle_id)
+            .original_result()
+    }
+
+    /// Start a new season, running from now until `end_timestamp`, for at least `MIN_SEASON_DURATION`. 
+    /// The previous season must have ended; its score-time is final from then on. 
+    pub fn start_season<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        end_timestamp: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("startSeason")
+            .argument(&end_timestamp)
+            .original_result()
+    }
+
+    /// Add fungible tokens (EGLD or ESDT) to the reward allocation of a season that has not ended yet. 
+    pub fn add_season_rewards<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        season_id: Arg0,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
-            .raw_call("createDistributionPlan")
-            .argument(&start_round)
-            .argument(&end_round)
+            .raw_call("addSeasonRewards")
+            .argument(&season_id)
             .original_result()
     }
 
-    /// Remove a distribution plan. 
-    /// Must provide the exact plan configuration to remove. 
-    pub fn remove_distribution_plan<
-        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
-        Arg1: ProxyArg<u64>,
-        Arg2: ProxyArg<u64>,
-        Arg3: ProxyArg<BigUint<Env::Api>>,
+    /// Claim the caller's share of the rewards of an ended season. 
+    pub fn claim_season_rewards<
+        Arg0: ProxyArg<u64>,
     >(
         self,
-        reward_token_id: Arg0,
-        start_round: Arg1,
-        end_round: Arg2,
-        amount_per_round: Arg3,
+        season_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("removeDistributionPlan")
-            .argument(&reward_token_id)
-            .argument(&start_round)
-            .argument(&end_round)
-            .argument(&amount_per_round)
+            .raw_call("claimSeasonRewards")
+            .argument(&season_id)
+            .original_result()
+    }
+
+    /// Send the season rewards that were not claimed to the owner, once the claim period is over. 
+    /// Rewards of an ended season in which nothing was staked can be swept right away. 
+    pub fn sweep_season_rewards<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        season_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("sweepSeasonRewards")
+            .argument(&season_id)
+            .original_result()
+    }
+
+    pub fn get_current_season(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<SeasonInfo<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentSeason")
+            .original_result()
+    }
+
+    pub fn get_season<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        season_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, SeasonInfo<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSeason")
+            .argument(&season_id)
+            .original_result()
+    }
+
+    /// Seasons in which the user accumulated score-time, oldest first. 
+    /// Seasons whose claim period was over when the user's score last changed may be missing. 
+    pub fn get_user_season_history<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, UserSeasonInfo<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserSeasonHistory")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// The user's share of the season rewards. Before the season ends, this is an estimate based on the score-time so far. 
+    pub fn get_season_rewards_for_user<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        user: Arg0,
+        season_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSeasonRewardsForUser")
+            .argument(&user)
+            .argument(&season_id)
+            .original_result()
+    }
+
+    pub fn get_season_score_time<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        season_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSeasonScoreTime")
+            .argument(&season_id)
+            .original_result()
+    }
+
+    pub fn get_user_season_score_time<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        user: Arg0,
+        season_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserSeasonScoreTime")
+            .argument(&user)
+            .argument(&season_id)
+            .original_result()
+    }
+
+    pub fn current_season_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentSeasonId")
             .original_result()
     }
 }
@@ -644,7 +1852,7 @@ where
     pub staked_items: ManagedVec<Api, EsdtTokenPayment<Api>>,
     pub staked_score: BigUint<Api>,
     pub aggregated_staked_score: BigUint<Api>,
-    pub pending_rewards: ManagedVec<Api, EsdtTokenPayment<Api>>,
+    pub pending_rewards: ManagedVec<Api, EgldOrEsdtTokenPayment<Api>>,
     pub unstaking_items: ManagedVec<Api, UnstakingBatch<Api>>,
 }
 
@@ -657,3 +1865,107 @@ where
     pub unstake_timestamp: u64,
     pub unstake_items: ManagedVec<Api, EsdtTokenPayment<Api>>,
 }
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, ManagedVecItem)]
+pub struct StakedItemDetails<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_identifier: TokenIdentifier<Api>,
+    pub nonce: u64,
+    pub amount: BigUint<Api>,
+    pub name: ManagedBuffer<Api>,
+    pub uris: ManagedVec<Api, ManagedBuffer<Api>>,
+    pub attributes: ManagedBuffer<Api>,
+    pub royalties: BigUint<Api>,
+    pub score: BigUint<Api>,
+    pub effective_score_bps: u64,
+    pub total_score: BigUint<Api>,
+    pub frozen: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    ScoreManager,
+    RewardManager,
+    Pauser,
+    CollectionManager,
+    Moderator,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Stake,
+    Unstake,
+    ClaimUnstaked,
+    ClaimRewards,
+    Distribution,
+    Transfer,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct PauseState {
+    pub staking_disabled: bool,
+    pub emergency_mode: bool,
+    pub stake_paused: bool,
+    pub unstake_paused: bool,
+    pub claim_unstaked_paused: bool,
+    pub claim_rewards_paused: bool,
+    pub distribution_paused: bool,
+    pub transfer_paused: bool,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct RaffleInfo<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub draw_timestamp: u64,
+    pub claim_period: u64,
+    pub min_entry_score: BigUint<Api>,
+    pub drawn: bool,
+    pub cancelled: bool,
+    pub entries: usize,
+    pub prizes: ManagedVec<Api, RafflePrize<Api>>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, ManagedVecItem)]
+pub struct RafflePrize<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub prize: EgldOrEsdtTokenPayment<Api>,
+    pub winner: ManagedAddress<Api>,
+    pub claimed: bool,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct SeasonInfo<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub season_id: u64,
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+    pub ended: bool,
+    pub total_score_time: BigUint<Api>,
+    pub rewards: ManagedVec<Api, EgldOrEsdtTokenPayment<Api>>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, ManagedVecItem)]
+pub struct UserSeasonInfo<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub season_id: u64,
+    pub score_time: BigUint<Api>,
+    pub claimed: bool,
+}
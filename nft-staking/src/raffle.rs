@@ -0,0 +1,370 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::constants::{
+    ERR_INVALID_DRAW_TIMESTAMP, ERR_NO_RAFFLE_PRIZES_TO_CLAIM, ERR_NO_STAKED_SCORE,
+    ERR_RAFFLE_ALREADY_DRAWN, ERR_RAFFLE_ALREADY_ENTERED, ERR_RAFFLE_CANCELLED,
+    ERR_RAFFLE_CLAIM_PERIOD_NOT_OVER, ERR_RAFFLE_DRAW_TOO_EARLY, ERR_RAFFLE_ENTRIES_CLOSED,
+    ERR_RAFFLE_ENTRY_SCORE_TOO_LOW, ERR_RAFFLE_NOT_DRAWN, ERR_RAFFLE_NOT_FOUND,
+    ERR_TOO_MANY_RAFFLE_ENTRIES, ERR_TOO_MANY_RAFFLE_PRIZES, MAX_RAFFLE_ENTRIES, MAX_RAFFLE_PRIZES,
+};
+use crate::roles::Role;
+
+const RANDOM_BYTES_LEN: usize = 32;
+
+/// `winner` is the zero address until the raffle is drawn, or if there were not enough entrants.
+/// `claimed` is also set when the prize is rolled over to another raffle or refunded.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, ManagedVecItem)]
+pub struct RafflePrize<M: ManagedTypeApi> {
+    pub prize: EgldOrEsdtTokenPayment<M>,
+    pub winner: ManagedAddress<M>,
+    pub claimed: bool,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct RaffleInfo<M: ManagedTypeApi> {
+    pub draw_timestamp: u64,
+    pub claim_period: u64,
+    pub min_entry_score: BigUint<M>,
+    pub drawn: bool,
+    pub cancelled: bool,
+    pub entries: usize,
+    pub prizes: ManagedVec<M, RafflePrize<M>>,
+}
+
+/// Giveaways for stakers.
+/// Stakers enter a raffle before its draw timestamp. Each prize goes to a different entrant, picked at random
+/// with a probability proportional to the lower of their staked score at entry and at the draw,
+/// so that score staked only around the entry or only around the draw does not count.
+/// Once a raffle is full, a new entrant takes the place of the entrant with the lowest such score, if theirs is higher.
+#[multiversx_sc::module]
+pub trait RaffleModule:
+    crate::storage::StorageModule
+    + crate::utils::UtilsModule
+    + crate::reward::reward_rate::RewardRateModule
+    + crate::roles::RolesModule
+    + crate::events::EventsModule
+{
+    /// Create a raffle drawn at `draw_timestamp`, open to stakers with at least `min_entry_score` staked score.
+    /// Winners can claim their prizes during `claim_period` seconds after the draw timestamp;
+    /// prizes not claimed by then can be rolled over to another raffle.
+    #[only_owner]
+    #[endpoint(createRaffle)]
+    fn create_raffle(&self, draw_timestamp: u64, claim_period: u64, min_entry_score: u64) -> u64 {
+        require!(
+            draw_timestamp > self.blockchain().get_block_timestamp(),
+            ERR_INVALID_DRAW_TIMESTAMP
+        );
+
+        let raffle_id = self.last_raffle_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        self.raffle_draw_timestamp(raffle_id).set(draw_timestamp);
+        self.raffle_claim_period(raffle_id).set(claim_period);
+        self.raffle_min_entry_score(raffle_id)
+            .set(BigUint::from(min_entry_score));
+
+        raffle_id
+    }
+
+    /// Deposit prizes (EGLD, ESDTs or NFTs) for a raffle that was not drawn yet.
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(addRafflePrizes)]
+    fn add_raffle_prizes(&self, raffle_id: u64) {
+        self.require_raffle_not_drawn(raffle_id);
+
+        for payment in self.call_value().all_transfers().iter() {
+            self.push_raffle_prize(raffle_id, payment.clone());
+        }
+    }
+
+    /// Return the prizes of a raffle that was not drawn yet to the owner. The raffle can no longer be drawn.
+    #[only_owner]
+    #[endpoint(cancelRaffle)]
+    fn cancel_raffle(&self, raffle_id: u64) {
+        self.require_raffle_not_drawn(raffle_id);
+
+        let mut prizes = ManagedVec::new();
+        for prize_index in 1..=self.raffle_prizes(raffle_id).len() {
+            self.raffle_prize_claimed(raffle_id, prize_index).set(true);
+            prizes.push(self.raffle_prizes(raffle_id).get(prize_index));
+        }
+        self.raffle_cancelled(raffle_id).set(true);
+
+        if !prizes.is_empty() {
+            self.tx()
+                .to(&self.blockchain().get_owner_address())
+                .payment(&prizes)
+                .transfer();
+        }
+    }
+
+    /// Enter the caller in a raffle, until its draw timestamp.
+    /// If the raffle is full, the caller replaces the entrant with the lowest score, provided theirs is higher.
+    #[endpoint(enterRaffle)]
+    fn enter_raffle(&self, raffle_id: u64) {
+        self.require_raffle_not_drawn(raffle_id);
+        require!(
+            self.blockchain().get_block_timestamp() < self.raffle_draw_timestamp(raffle_id).get(),
+            ERR_RAFFLE_ENTRIES_CLOSED
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.require_address_not_frozen(&caller);
+
+        let staked_score = self.user_staked_score(&caller).get();
+        require!(staked_score > 0, ERR_NO_STAKED_SCORE);
+        require!(
+            staked_score >= self.raffle_min_entry_score(raffle_id).get(),
+            ERR_RAFFLE_ENTRY_SCORE_TOO_LOW
+        );
+        require!(
+            !self.raffle_entries(raffle_id).contains(&caller),
+            ERR_RAFFLE_ALREADY_ENTERED
+        );
+
+        if self.raffle_entries(raffle_id).len() >= MAX_RAFFLE_ENTRIES {
+            let (lowest_entrant, lowest_weight) = self.get_lowest_raffle_entry(raffle_id);
+            require!(staked_score > lowest_weight, ERR_TOO_MANY_RAFFLE_ENTRIES);
+
+            self.raffle_entries(raffle_id).swap_remove(&lowest_entrant);
+            self.raffle_entry_score(raffle_id, &lowest_entrant).clear();
+        }
+
+        self.raffle_entries(raffle_id).insert(caller.clone());
+        self.raffle_entry_score(raffle_id, &caller)
+            .set(staked_score);
+    }
+
+    /// Pick the winners of a raffle once its draw timestamp has passed.
+    #[endpoint(drawRaffle)]
+    fn draw_raffle(&self, raffle_id: u64) {
+        self.require_role(Role::RewardManager);
+        self.require_raffle_not_drawn(raffle_id);
+        require!(
+            self.blockchain().get_block_timestamp() >= self.raffle_draw_timestamp(raffle_id).get(),
+            ERR_RAFFLE_DRAW_TOO_EARLY
+        );
+
+        let mut candidates = ManagedVec::<Self::Api, ManagedAddress>::new();
+        let mut weights = ManagedVec::<Self::Api, BigUint>::new();
+        let mut total_weight = BigUint::zero();
+        for entrant in self.raffle_entries(raffle_id).iter() {
+            let weight = self.get_raffle_entry_weight(raffle_id, &entrant);
+            total_weight += &weight;
+            candidates.push(entrant);
+            weights.push(weight);
+        }
+
+        let mut randomness = RandomnessSource::new();
+        let mut winners = ManagedVec::new();
+        for prize_index in 1..=self.raffle_prizes(raffle_id).len() {
+            if total_weight == 0 {
+                break;
+            }
+
+            let random = BigUint::from_bytes_be_buffer(&randomness.next_bytes(RANDOM_BYTES_LEN));
+            let mut target = random % &total_weight;
+            for (candidate_index, weight) in weights.iter().enumerate() {
+                if *weight > target {
+                    let winner = candidates.get(candidate_index).clone();
+                    self.raffle_prize_winner(raffle_id, prize_index)
+                        .set(&winner);
+                    winners.push(winner);
+
+                    // Each staker can win at most one prize per raffle.
+                    total_weight -= &*weight;
+                    let _ = weights.set(candidate_index, BigUint::zero());
+                    break;
+                }
+                target -= &*weight;
+            }
+        }
+
+        self.raffle_drawn(raffle_id).set(true);
+        self.emit_raffle_drawn_event(raffle_id, &winners);
+    }
+
+    #[endpoint(claimRafflePrizes)]
+    fn claim_raffle_prizes(&self, raffle_id: u64) {
+        self.require_raffle_drawn(raffle_id);
+
+        let caller = self.blockchain().get_caller();
+        self.require_address_not_frozen(&caller);
+        let mut prizes = ManagedVec::new();
+        for prize_index in 1..=self.raffle_prizes(raffle_id).len() {
+            if self.raffle_prize_claimed(raffle_id, prize_index).get()
+                || self.get_raffle_prize_winner(raffle_id, prize_index) != caller
+            {
+                continue;
+            }
+
+            self.raffle_prize_claimed(raffle_id, prize_index).set(true);
+            prizes.push(self.raffle_prizes(raffle_id).get(prize_index));
+        }
+
+        require!(!prizes.is_empty(), ERR_NO_RAFFLE_PRIZES_TO_CLAIM);
+        self.tx().to(&caller).payment(&prizes).transfer();
+        self.emit_raffle_prizes_claimed_event(raffle_id, &caller, &prizes);
+    }
+
+    /// Move prizes that were not won or not claimed during the claim period to a raffle that was not drawn yet.
+    #[only_owner]
+    #[endpoint(rolloverRafflePrizes)]
+    fn rollover_raffle_prizes(&self, from_raffle_id: u64, to_raffle_id: u64) {
+        self.require_raffle_drawn(from_raffle_id);
+        self.require_raffle_not_drawn(to_raffle_id);
+
+        let claim_deadline = self.raffle_draw_timestamp(from_raffle_id).get()
+            + self.raffle_claim_period(from_raffle_id).get();
+        require!(
+            self.blockchain().get_block_timestamp() >= claim_deadline,
+            ERR_RAFFLE_CLAIM_PERIOD_NOT_OVER
+        );
+
+        for prize_index in 1..=self.raffle_prizes(from_raffle_id).len() {
+            if self.raffle_prize_claimed(from_raffle_id, prize_index).get() {
+                continue;
+            }
+
+            self.raffle_prize_claimed(from_raffle_id, prize_index)
+                .set(true);
+            let prize = self.raffle_prizes(from_raffle_id).get(prize_index);
+            self.push_raffle_prize(to_raffle_id, prize);
+        }
+    }
+
+    #[view(getRaffle)]
+    fn get_raffle(&self, raffle_id: u64) -> RaffleInfo<Self::Api> {
+        self.require_raffle_exists(raffle_id);
+
+        let mut prizes = ManagedVec::new();
+        for (prize_index, prize) in self.raffle_prizes(raffle_id).iter().enumerate() {
+            let prize_index = prize_index + 1;
+            prizes.push(RafflePrize {
+                prize,
+                winner: self.get_raffle_prize_winner(raffle_id, prize_index),
+                claimed: self.raffle_prize_claimed(raffle_id, prize_index).get(),
+            });
+        }
+
+        RaffleInfo {
+            draw_timestamp: self.raffle_draw_timestamp(raffle_id).get(),
+            claim_period: self.raffle_claim_period(raffle_id).get(),
+            min_entry_score: self.raffle_min_entry_score(raffle_id).get(),
+            drawn: self.raffle_drawn(raffle_id).get(),
+            cancelled: self.raffle_cancelled(raffle_id).get(),
+            entries: self.raffle_entries(raffle_id).len(),
+            prizes,
+        }
+    }
+
+    /// Lower of the staked score of the entrant at entry and now.
+    fn get_raffle_entry_weight(&self, raffle_id: u64, entrant: &ManagedAddress) -> BigUint {
+        let entry_score = self.raffle_entry_score(raffle_id, entrant).get();
+        core::cmp::min(entry_score, self.user_staked_score(entrant).get())
+    }
+
+    fn get_lowest_raffle_entry(&self, raffle_id: u64) -> (ManagedAddress, BigUint) {
+        let mut lowest_entry: Option<(ManagedAddress, BigUint)> = None;
+        for entrant in self.raffle_entries(raffle_id).iter() {
+            let weight = self.get_raffle_entry_weight(raffle_id, &entrant);
+            match &lowest_entry {
+                Some((_, lowest_weight)) if *lowest_weight <= weight => {}
+                _ => lowest_entry = Some((entrant, weight)),
+            }
+        }
+
+        lowest_entry.unwrap_or_else(|| sc_panic!(ERR_TOO_MANY_RAFFLE_ENTRIES))
+    }
+
+    fn get_raffle_prize_winner(&self, raffle_id: u64, prize_index: usize) -> ManagedAddress {
+        let winner = self.raffle_prize_winner(raffle_id, prize_index);
+        if winner.is_empty() {
+            return ManagedAddress::zero();
+        }
+
+        winner.get()
+    }
+
+    fn push_raffle_prize(&self, raffle_id: u64, prize: EgldOrEsdtTokenPayment) {
+        require!(
+            self.raffle_prizes(raffle_id).len() < MAX_RAFFLE_PRIZES,
+            ERR_TOO_MANY_RAFFLE_PRIZES
+        );
+
+        self.raffle_prizes(raffle_id).push(&prize);
+    }
+
+    fn require_raffle_exists(&self, raffle_id: u64) {
+        require!(
+            !self.raffle_draw_timestamp(raffle_id).is_empty(),
+            ERR_RAFFLE_NOT_FOUND
+        );
+    }
+
+    fn require_raffle_not_drawn(&self, raffle_id: u64) {
+        self.require_raffle_exists(raffle_id);
+        require!(
+            !self.raffle_drawn(raffle_id).get(),
+            ERR_RAFFLE_ALREADY_DRAWN
+        );
+        require!(
+            !self.raffle_cancelled(raffle_id).get(),
+            ERR_RAFFLE_CANCELLED
+        );
+    }
+
+    fn require_raffle_drawn(&self, raffle_id: u64) {
+        self.require_raffle_exists(raffle_id);
+        require!(self.raffle_drawn(raffle_id).get(), ERR_RAFFLE_NOT_DRAWN);
+    }
+
+    #[view(getLastRaffleId)]
+    #[storage_mapper("lastRaffleId")]
+    fn last_raffle_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("raffleDrawTimestamp")]
+    fn raffle_draw_timestamp(&self, raffle_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("raffleClaimPeriod")]
+    fn raffle_claim_period(&self, raffle_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("raffleMinEntryScore")]
+    fn raffle_min_entry_score(&self, raffle_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("raffleDrawn")]
+    fn raffle_drawn(&self, raffle_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("raffleCancelled")]
+    fn raffle_cancelled(&self, raffle_id: u64) -> SingleValueMapper<bool>;
+
+    #[view(getRaffleEntries)]
+    #[storage_mapper("raffleEntries")]
+    fn raffle_entries(&self, raffle_id: u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Staked score of the entrant when entering the raffle.
+    #[storage_mapper("raffleEntryScore")]
+    fn raffle_entry_score(
+        &self,
+        raffle_id: u64,
+        entrant: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("rafflePrizes")]
+    fn raffle_prizes(&self, raffle_id: u64) -> VecMapper<EgldOrEsdtTokenPayment>;
+
+    #[storage_mapper("rafflePrizeWinner")]
+    fn raffle_prize_winner(
+        &self,
+        raffle_id: u64,
+        prize_index: usize,
+    ) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("rafflePrizeClaimed")]
+    fn raffle_prize_claimed(&self, raffle_id: u64, prize_index: usize) -> SingleValueMapper<bool>;
+}
@@ -2,13 +2,13 @@ use super::reward_rate::REWARD_RATE_DENOMINATION;
 
 multiversx_sc::imports!();
 
-pub type DistributionPlan<M> = (TokenIdentifier<M>, u64, u64, BigUint<M>); // token, start round, end round, denominated amount (*REWARD_RATE_DENOMINATION)
+pub type DistributionPlan<M> = (EgldOrEsdtTokenIdentifier<M>, u64, u64, BigUint<M>); // token, start round, end round, denominated amount (*REWARD_RATE_DENOMINATION)
 
 #[multiversx_sc::module]
 pub trait PlannedDistributionModule: super::reward_rate::RewardRateModule {
     fn create_plan(
         &self,
-        token: TokenIdentifier,
+        token: EgldOrEsdtTokenIdentifier,
         start_round: u64,
         end_round: u64,
         total_distribution_amount: BigUint,
@@ -31,7 +31,7 @@ pub trait PlannedDistributionModule: super::reward_rate::RewardRateModule {
 
     fn remove_plan(
         &self,
-        token: TokenIdentifier,
+        token: EgldOrEsdtTokenIdentifier,
         start_round: u64,
         end_round: u64,
         amount_per_round: BigUint,
@@ -48,6 +48,12 @@ pub trait PlannedDistributionModule: super::reward_rate::RewardRateModule {
         self.last_distribution_round().clear();
     }
 
+    fn has_distribution_plan(&self, token: &EgldOrEsdtTokenIdentifier) -> bool {
+        self.distribution_plans()
+            .iter()
+            .any(|(plan_token, _, _, _)| &plan_token == token)
+    }
+
     fn distribute_as_planned(&self) {
         let current_round = self.blockchain().get_block_round();
         for plan in self.distribution_plans().iter() {
@@ -88,13 +94,15 @@ pub trait PlannedDistributionModule: super::reward_rate::RewardRateModule {
         Some(amount)
     }
 
-    fn get_all_planned_undistributed_rewards(&self) -> ManagedVec<EsdtTokenPayment<Self::Api>> {
+    fn get_all_planned_undistributed_rewards(
+        &self,
+    ) -> ManagedVec<EgldOrEsdtTokenPayment<Self::Api>> {
         let mut rewards = ManagedVec::new();
         for plan in self.distribution_plans().iter() {
             if let Some(amount) =
                 self.get_amount_to_distribute(&plan, self.blockchain().get_block_round())
             {
-                rewards.push(EsdtTokenPayment::new(plan.0, 0, amount));
+                rewards.push(EgldOrEsdtTokenPayment::new(plan.0, 0, amount));
             }
         }
         rewards
@@ -103,7 +111,7 @@ pub trait PlannedDistributionModule: super::reward_rate::RewardRateModule {
     fn get_user_undistributed_rewards_share(
         &self,
         user: &ManagedAddress,
-    ) -> ManagedVec<EsdtTokenPayment<Self::Api>> {
+    ) -> ManagedVec<EgldOrEsdtTokenPayment<Self::Api>> {
         let mut rewards = ManagedVec::new();
 
         let undistributed_rewards = self.get_all_planned_undistributed_rewards();
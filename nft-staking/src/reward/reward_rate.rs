@@ -16,14 +16,28 @@ pub trait RewardRateModule {
             .update(|prev| *prev -= amount);
     }
 
-    fn handle_increase_reward_rate(&self, payment: &EsdtTokenPayment) {
+    /// Users that staked before raw scores were tracked have no diminishing returns applied yet,
+    /// so their staked score is their raw score.
+    fn get_user_raw_score(&self, user: &ManagedAddress) -> BigUint {
+        if self.user_raw_score(user).is_empty() {
+            return self.user_staked_score(user).get();
+        }
+
+        self.user_raw_score(user).get()
+    }
+
+    fn handle_increase_reward_rate(&self, payment: &EgldOrEsdtTokenPayment) {
         self.handle_increase_reward_rate_raw(
             &payment.token_identifier,
             &payment.amount * REWARD_RATE_DENOMINATION,
         );
     }
 
-    fn handle_increase_reward_rate_raw(&self, token_id: &TokenIdentifier, amount: BigUint) {
+    fn handle_increase_reward_rate_raw(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
         let aggregated_stake_score = self.aggregated_staked_score().get();
         if aggregated_stake_score == 0 {
             return;
@@ -31,16 +45,53 @@ pub trait RewardRateModule {
 
         let distribution_rate_increase = &amount / &aggregated_stake_score;
 
+        self.outstanding_rewards_scaled(token_id)
+            .update(|prev| *prev += &distribution_rate_increase * &aggregated_stake_score);
         self.current_reward_rate(token_id)
             .update(|prev| *prev += &distribution_rate_increase);
     }
 
+    /// Rewards leave the outstanding amount when they are claimed or forfeited.
+    /// Rewards distributed before the outstanding amount was tracked are not part of it,
+    /// see `outstanding_rewards_tracked`.
+    fn release_outstanding_rewards(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        amount_scaled: &BigUint,
+    ) {
+        self.outstanding_rewards_scaled(token_id).update(|prev| {
+            if *prev >= *amount_scaled {
+                *prev -= amount_scaled;
+            } else {
+                *prev = BigUint::zero();
+            }
+        });
+    }
+
+    /// Distributed rewards of the token that were not claimed yet.
+    #[view(getOutstandingRewards)]
+    fn get_outstanding_rewards(&self, token_id: &EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.outstanding_rewards_scaled(token_id).get() / REWARD_RATE_DENOMINATION
+    }
+
     fn handle_store_pending_rewards(
         &self,
         user: &ManagedAddress,
-        reward_token_id: &TokenIdentifier,
+        reward_token_id: &EgldOrEsdtTokenIdentifier,
     ) {
-        let rewards = self.get_unstored_rewards_for_token(user, reward_token_id);
+        let rewards_scaled = self.get_unstored_rewards_scaled(user, reward_token_id);
+        let rewards = &rewards_scaled / REWARD_RATE_DENOMINATION;
+
+        // The rounding remainder can never be claimed
+        self.release_outstanding_rewards(
+            reward_token_id,
+            &(rewards_scaled - &rewards * REWARD_RATE_DENOMINATION),
+        );
+
+        // The snapshot is moved forward even without rewards, so that a score added later
+        // does not earn rewards distributed before it was staked.
+        self.user_reward_rate(user, reward_token_id)
+            .set(self.current_reward_rate(reward_token_id).get());
 
         if rewards == 0 {
             return;
@@ -48,15 +99,21 @@ pub trait RewardRateModule {
 
         self.user_stored_rewards(user, reward_token_id)
             .update(|prev| *prev += &rewards);
-        self.user_reward_rate(user, reward_token_id)
-            .set(self.current_reward_rate(reward_token_id).get());
     }
 
     #[view(getUnstoredRewardsForToken)]
     fn get_unstored_rewards_for_token(
         &self,
         user: &ManagedAddress,
-        reward_token_id: &TokenIdentifier,
+        reward_token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> BigUint {
+        self.get_unstored_rewards_scaled(user, reward_token_id) / REWARD_RATE_DENOMINATION
+    }
+
+    fn get_unstored_rewards_scaled(
+        &self,
+        user: &ManagedAddress,
+        reward_token_id: &EgldOrEsdtTokenIdentifier,
     ) -> BigUint {
         let current_rate = self.current_reward_rate(reward_token_id).get();
         // if the user has no reward rate set, they start from 0. This should not affect reward distribution
@@ -73,14 +130,14 @@ pub trait RewardRateModule {
             return BigUint::zero();
         }
 
-        rate_diff * self.user_staked_score(user).get() / REWARD_RATE_DENOMINATION
+        rate_diff * self.user_staked_score(user).get()
     }
 
     fn handle_claim_pending_rewards(
         &self,
         user: &ManagedAddress,
-        reward_token_id: TokenIdentifier,
-    ) -> Option<EsdtTokenPayment> {
+        reward_token_id: EgldOrEsdtTokenIdentifier,
+    ) -> Option<EgldOrEsdtTokenPayment> {
         self.handle_store_pending_rewards(user, &reward_token_id);
 
         let rewards = self.user_stored_rewards(user, &reward_token_id).get();
@@ -89,15 +146,16 @@ pub trait RewardRateModule {
         }
 
         self.user_stored_rewards(user, &reward_token_id).clear();
+        self.release_outstanding_rewards(&reward_token_id, &(&rewards * REWARD_RATE_DENOMINATION));
 
-        Some(EsdtTokenPayment::new(reward_token_id, 0, rewards))
+        Some(EgldOrEsdtTokenPayment::new(reward_token_id, 0, rewards))
     }
 
     fn get_pending_rewards(
         &self,
         user: &ManagedAddress,
-        reward_token_ids: &ManagedVec<TokenIdentifier>,
-    ) -> ManagedVec<EsdtTokenPayment<Self::Api>> {
+        reward_token_ids: &ManagedVec<EgldOrEsdtTokenIdentifier>,
+    ) -> ManagedVec<EgldOrEsdtTokenPayment<Self::Api>> {
         let mut pending_rewards = ManagedVec::new();
         for reward_token_id in reward_token_ids.iter() {
             let reward_payment =
@@ -112,8 +170,8 @@ pub trait RewardRateModule {
     fn get_pending_rewards_for_token(
         &self,
         user: &ManagedAddress,
-        reward_token_id: TokenIdentifier,
-    ) -> Option<EsdtTokenPayment<Self::Api>> {
+        reward_token_id: EgldOrEsdtTokenIdentifier,
+    ) -> Option<EgldOrEsdtTokenPayment<Self::Api>> {
         let stored_rewards = self.user_stored_rewards(user, &reward_token_id).get();
         let unstored_rewards = self.get_unstored_rewards_for_token(user, &reward_token_id);
 
@@ -123,34 +181,62 @@ pub trait RewardRateModule {
             return None;
         }
 
-        Some(EsdtTokenPayment::new(reward_token_id, 0, total_rewards))
+        Some(EgldOrEsdtTokenPayment::new(
+            reward_token_id,
+            0,
+            total_rewards,
+        ))
     }
 
     #[view(getUserStakedScore)]
     #[storage_mapper("userStakedScore")]
     fn user_staked_score(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
 
+    /// Sum of the scores of the user's staked items, before diminishing returns are applied.
+    #[storage_mapper("userRawScore")]
+    fn user_raw_score(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
     #[view(getAggregatedStakedScore)]
     #[storage_mapper("aggregatedStakedScore")]
     fn aggregated_staked_score(&self) -> SingleValueMapper<BigUint>;
 
     #[view(getCurrentRewardRate)]
     #[storage_mapper("currentRewardRate")]
-    fn current_reward_rate(&self, reward_token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+    fn current_reward_rate(
+        &self,
+        reward_token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
 
     #[view(getUserRewardRate)]
     #[storage_mapper("userRewardRate")]
     fn user_reward_rate(
         &self,
         user: &ManagedAddress,
-        reward_token_id: &TokenIdentifier,
+        reward_token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// Distributed rewards that were not claimed yet, scaled by `REWARD_RATE_DENOMINATION`
+    /// so that rounding remainders can be released exactly.
+    #[storage_mapper("outstandingRewardsScaled")]
+    fn outstanding_rewards_scaled(
+        &self,
+        reward_token_id: &EgldOrEsdtTokenIdentifier,
     ) -> SingleValueMapper<BigUint>;
 
+    /// Set for the tokens registered since the outstanding amount is tracked,
+    /// whose outstanding amount therefore covers all their distributed rewards.
+    #[view(isOutstandingRewardsTracked)]
+    #[storage_mapper("outstandingRewardsTracked")]
+    fn outstanding_rewards_tracked(
+        &self,
+        reward_token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<bool>;
+
     #[view(getUserStoredRewards)]
     #[storage_mapper("userStoredRewards")]
     fn user_stored_rewards(
         &self,
         user: &ManagedAddress,
-        reward_token_id: &TokenIdentifier,
+        reward_token_id: &EgldOrEsdtTokenIdentifier,
     ) -> SingleValueMapper<BigUint>;
 }
@@ -0,0 +1,70 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::constants::ERR_MISSING_ROLE;
+
+/// Roles that can be delegated by the owner for routine administration.
+/// The owner implicitly holds every role.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    ScoreManager,
+    RewardManager,
+    Pauser,
+    CollectionManager,
+    Moderator,
+}
+
+pub const ALL_ROLES: [Role; 5] = [
+    Role::ScoreManager,
+    Role::RewardManager,
+    Role::Pauser,
+    Role::CollectionManager,
+    Role::Moderator,
+];
+
+#[multiversx_sc::module]
+pub trait RolesModule: crate::events::EventsModule {
+    #[only_owner]
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: Role, address: ManagedAddress) {
+        if self.role_holders(role).insert(address.clone()) {
+            self.emit_role_granted_event(role, &address);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: Role, address: ManagedAddress) {
+        if self.role_holders(role).remove(&address) {
+            self.emit_role_revoked_event(role, &address);
+        }
+    }
+
+    #[view(hasRole)]
+    fn has_role(&self, role: Role, address: &ManagedAddress) -> bool {
+        address == &self.blockchain().get_owner_address()
+            || self.role_holders(role).contains(address)
+    }
+
+    #[view(getAllRoleHolders)]
+    fn get_all_role_holders(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<Role, ManagedVec<ManagedAddress>>> {
+        let mut all_role_holders = MultiValueEncoded::new();
+        for role in ALL_ROLES {
+            let holders = self.role_holders(role).iter().collect();
+            all_role_holders.push((role, holders).into());
+        }
+        all_role_holders
+    }
+
+    fn require_role(&self, role: Role) {
+        let caller = self.blockchain().get_caller();
+        require!(self.has_role(role, &caller), ERR_MISSING_ROLE);
+    }
+
+    #[view(getRoleHolders)]
+    #[storage_mapper("roleHolders")]
+    fn role_holders(&self, role: Role) -> SetMapper<ManagedAddress>;
+}
@@ -0,0 +1,409 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::constants::{
+    ERR_INVALID_SEASON_END, ERR_INVALID_SEASON_REWARD, ERR_NO_SEASON_REWARDS_TO_CLAIM,
+    ERR_SEASON_CLAIM_PERIOD_NOT_OVER, ERR_SEASON_CLAIM_PERIOD_OVER, ERR_SEASON_ENDED,
+    ERR_SEASON_NOT_ENDED, ERR_SEASON_NOT_FOUND, ERR_SEASON_REWARDS_ALREADY_CLAIMED,
+    ERR_SEASON_REWARDS_ALREADY_SWEPT, MIN_SEASON_DURATION, SEASON_CLAIM_PERIOD,
+};
+use crate::pause::Operation;
+use crate::roles::Role;
+
+/// `total_score_time` is the sum of all staked scores multiplied by the seconds they were staked during the season.
+/// Once the season has ended it is the final snapshot the season rewards are split by.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct SeasonInfo<M: ManagedTypeApi> {
+    pub season_id: u64,
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+    pub ended: bool,
+    pub total_score_time: BigUint<M>,
+    pub rewards: ManagedVec<M, EgldOrEsdtTokenPayment<M>>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, ManagedVecItem)]
+pub struct UserSeasonInfo<M: ManagedTypeApi> {
+    pub season_id: u64,
+    pub score_time: BigUint<M>,
+    pub claimed: bool,
+}
+
+/// Staking seasons, each with its own reward allocation.
+/// During a season every staker accumulates score × time; once the season has ended its rewards are split
+/// proportionally to the accumulated score-time of each staker.
+/// Accumulators are settled lazily, before any staked score changes, so idle stakers keep accruing at their current score.
+/// Only seasons that can still be claimed are settled, which `MIN_SEASON_DURATION` keeps to a handful.
+/// Rewards can be claimed during `SEASON_CLAIM_PERIOD` after the season end; what is left can then be swept by the owner.
+///
+/// Unlike `RewardRateModule`, rewards are not spread through a reward-per-score index:
+/// season rewards can be topped up until the season ends and are split by the final score-time,
+/// so the amount per unit of score is only known once the season is over.
+#[multiversx_sc::module]
+pub trait SeasonModule:
+    crate::storage::StorageModule
+    + crate::utils::UtilsModule
+    + crate::reward::reward_rate::RewardRateModule
+    + crate::roles::RolesModule
+    + crate::pause::PauseModule
+    + crate::events::EventsModule
+{
+    /// Start a new season, running from now until `end_timestamp`, for at least `MIN_SEASON_DURATION`.
+    /// The previous season must have ended; its score-time is final from then on.
+    #[endpoint(startSeason)]
+    fn start_season(&self, end_timestamp: u64) -> u64 {
+        self.require_role(Role::RewardManager);
+
+        let block_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            end_timestamp >= block_timestamp + MIN_SEASON_DURATION,
+            ERR_INVALID_SEASON_END
+        );
+
+        let current_season_id = self.current_season_id().get();
+        if current_season_id > 0 {
+            require!(
+                block_timestamp >= self.season_end_timestamp(current_season_id).get(),
+                ERR_SEASON_NOT_ENDED
+            );
+            self.update_season_score_time();
+        }
+
+        let season_id = current_season_id + 1;
+        self.current_season_id().set(season_id);
+        self.season_start_timestamp(season_id).set(block_timestamp);
+        self.season_end_timestamp(season_id).set(end_timestamp);
+        self.season_score_time_updated_at().set(block_timestamp);
+
+        self.emit_season_started_event(season_id, block_timestamp, end_timestamp);
+
+        season_id
+    }
+
+    /// Add fungible tokens (EGLD or ESDT) to the reward allocation of a season that has not ended yet.
+    #[payable("*")]
+    #[endpoint(addSeasonRewards)]
+    fn add_season_rewards(&self, season_id: u64) {
+        self.require_role(Role::RewardManager);
+        self.require_season_exists(season_id);
+        require!(!self.is_season_ended(season_id), ERR_SEASON_ENDED);
+
+        for payment in self.call_value().all_transfers().iter() {
+            require!(payment.token_nonce == 0, ERR_INVALID_SEASON_REWARD);
+
+            self.season_reward_tokens(season_id)
+                .insert(payment.token_identifier.clone());
+            self.season_reward_amount(season_id, &payment.token_identifier)
+                .update(|amount| *amount += &payment.amount);
+        }
+    }
+
+    /// Claim the caller's share of the rewards of an ended season.
+    #[endpoint(claimSeasonRewards)]
+    fn claim_season_rewards(&self, season_id: u64) {
+        self.require_staking_enabled();
+        self.require_operation_not_paused(Operation::ClaimRewards);
+        self.require_season_exists(season_id);
+        require!(self.is_season_ended(season_id), ERR_SEASON_NOT_ENDED);
+        require!(
+            !self.is_season_claim_period_over(season_id),
+            ERR_SEASON_CLAIM_PERIOD_OVER
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.require_address_not_frozen(&caller);
+        require!(
+            !self.user_season_claimed(&caller, season_id).get(),
+            ERR_SEASON_REWARDS_ALREADY_CLAIMED
+        );
+
+        let rewards = self.get_season_rewards_for_user(&caller, season_id);
+        require!(!rewards.is_empty(), ERR_NO_SEASON_REWARDS_TO_CLAIM);
+
+        self.user_season_claimed(&caller, season_id).set(true);
+        for reward in rewards.iter() {
+            self.season_claimed_amount(season_id, &reward.token_identifier)
+                .update(|amount| *amount += &reward.amount);
+        }
+        self.tx().to(&caller).payment(&rewards).transfer();
+        self.emit_season_rewards_claimed_event(season_id, &caller, &rewards);
+    }
+
+    /// Send the season rewards that were not claimed to the owner, once the claim period is over.
+    /// Rewards of an ended season in which nothing was staked can be swept right away.
+    #[only_owner]
+    #[endpoint(sweepSeasonRewards)]
+    fn sweep_season_rewards(&self, season_id: u64) {
+        self.require_season_exists(season_id);
+        require!(self.is_season_ended(season_id), ERR_SEASON_NOT_ENDED);
+        require!(
+            self.is_season_claim_period_over(season_id)
+                || self.get_season_score_time(season_id) == 0,
+            ERR_SEASON_CLAIM_PERIOD_NOT_OVER
+        );
+        require!(
+            !self.season_swept(season_id).get(),
+            ERR_SEASON_REWARDS_ALREADY_SWEPT
+        );
+        self.season_swept(season_id).set(true);
+
+        let mut leftovers = ManagedVec::new();
+        for token_id in self.season_reward_tokens(season_id).iter() {
+            let amount = self.season_reward_amount(season_id, &token_id).get()
+                - self.season_claimed_amount(season_id, &token_id).get();
+            if amount > 0 {
+                leftovers.push(EgldOrEsdtTokenPayment::new(token_id, 0, amount));
+            }
+        }
+
+        if !leftovers.is_empty() {
+            self.tx()
+                .to(&self.blockchain().get_owner_address())
+                .payment(&leftovers)
+                .transfer();
+        }
+    }
+
+    #[view(getCurrentSeason)]
+    fn get_current_season(&self) -> OptionalValue<SeasonInfo<Self::Api>> {
+        let current_season_id = self.current_season_id().get();
+        if current_season_id == 0 {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.get_season(current_season_id))
+    }
+
+    #[view(getSeason)]
+    fn get_season(&self, season_id: u64) -> SeasonInfo<Self::Api> {
+        self.require_season_exists(season_id);
+
+        let mut rewards = ManagedVec::new();
+        for token_id in self.season_reward_tokens(season_id).iter() {
+            let amount = self.season_reward_amount(season_id, &token_id).get();
+            rewards.push(EgldOrEsdtTokenPayment::new(token_id, 0, amount));
+        }
+
+        SeasonInfo {
+            season_id,
+            start_timestamp: self.season_start_timestamp(season_id).get(),
+            end_timestamp: self.season_end_timestamp(season_id).get(),
+            ended: self.is_season_ended(season_id),
+            total_score_time: self.get_season_score_time(season_id),
+            rewards,
+        }
+    }
+
+    /// Seasons in which the user accumulated score-time, oldest first.
+    /// Seasons whose claim period was over when the user's score last changed may be missing.
+    #[view(getUserSeasonHistory)]
+    fn get_user_season_history(
+        &self,
+        user: ManagedAddress,
+    ) -> MultiValueEncoded<UserSeasonInfo<Self::Api>> {
+        let mut history = MultiValueEncoded::new();
+        for season_id in 1..=self.current_season_id().get() {
+            let score_time = self.get_user_season_score_time(&user, season_id);
+            if score_time == 0 {
+                continue;
+            }
+
+            history.push(UserSeasonInfo {
+                season_id,
+                score_time,
+                claimed: self.user_season_claimed(&user, season_id).get(),
+            });
+        }
+
+        history
+    }
+
+    /// The user's share of the season rewards. Before the season ends, this is an estimate based on the score-time so far.
+    #[view(getSeasonRewardsForUser)]
+    fn get_season_rewards_for_user(
+        &self,
+        user: &ManagedAddress,
+        season_id: u64,
+    ) -> ManagedVec<EgldOrEsdtTokenPayment> {
+        let mut rewards = ManagedVec::new();
+        if self.user_season_claimed(user, season_id).get() {
+            return rewards;
+        }
+
+        let user_score_time = self.get_user_season_score_time(user, season_id);
+        let total_score_time = self.get_season_score_time(season_id);
+        if user_score_time == 0 || total_score_time == 0 {
+            return rewards;
+        }
+
+        for token_id in self.season_reward_tokens(season_id).iter() {
+            let amount = self.season_reward_amount(season_id, &token_id).get() * &user_score_time
+                / &total_score_time;
+            if amount > 0 {
+                rewards.push(EgldOrEsdtTokenPayment::new(token_id, 0, amount));
+            }
+        }
+
+        rewards
+    }
+
+    #[view(getSeasonScoreTime)]
+    fn get_season_score_time(&self, season_id: u64) -> BigUint {
+        let score_time = self.season_score_time(season_id).get();
+        if season_id != self.current_season_id().get() {
+            return score_time;
+        }
+
+        let elapsed =
+            self.get_season_elapsed_since(season_id, self.season_score_time_updated_at().get());
+        score_time + self.aggregated_staked_score().get() * elapsed
+    }
+
+    #[view(getUserSeasonScoreTime)]
+    fn get_user_season_score_time(&self, user: &ManagedAddress, season_id: u64) -> BigUint {
+        let (checkpoint_season_id, checkpoint_timestamp) = self.get_user_season_checkpoint(user);
+        let score_time = self.user_season_score_time(user, season_id).get();
+        if season_id < checkpoint_season_id {
+            return score_time;
+        }
+
+        // The staked score has not changed since the checkpoint, so it applies to the whole rest of the period.
+        let accrued_since = if season_id == checkpoint_season_id {
+            checkpoint_timestamp
+        } else {
+            0
+        };
+        let elapsed = self.get_season_elapsed_since(season_id, accrued_since);
+        score_time + self.user_staked_score(user).get() * elapsed
+    }
+
+    /// Settles the season accumulators of the user and of the whole contract.
+    /// Seasons whose claim period is over, or that the user already claimed, are left as they are.
+    /// Must be called before the user's staked score changes.
+    fn checkpoint_season_score(&self, user: &ManagedAddress) {
+        let current_season_id = self.current_season_id().get();
+        if current_season_id == 0 {
+            return;
+        }
+
+        self.update_season_score_time();
+
+        let (checkpoint_season_id, _) = self.get_user_season_checkpoint(user);
+        if self.user_staked_score(user).get() > 0 {
+            // Seasons end in order, so once a claim period is over all the older ones are over too.
+            let mut season_id = current_season_id;
+            while season_id >= checkpoint_season_id.max(1)
+                && !self.is_season_claim_period_over(season_id)
+            {
+                if !self.user_season_claimed(user, season_id).get() {
+                    let score_time = self.get_user_season_score_time(user, season_id);
+                    self.user_season_score_time(user, season_id).set(score_time);
+                }
+                season_id -= 1;
+            }
+        }
+
+        self.user_season_checkpoint(user)
+            .set((current_season_id, self.blockchain().get_block_timestamp()));
+    }
+
+    fn update_season_score_time(&self) {
+        let current_season_id = self.current_season_id().get();
+        let score_time = self.get_season_score_time(current_season_id);
+        self.season_score_time(current_season_id).set(score_time);
+        self.season_score_time_updated_at()
+            .set(self.blockchain().get_block_timestamp());
+    }
+
+    /// Seconds of the season that passed since `timestamp`, capped at the season bounds.
+    fn get_season_elapsed_since(&self, season_id: u64, timestamp: u64) -> u64 {
+        let from = timestamp.max(self.season_start_timestamp(season_id).get());
+        let until = self
+            .blockchain()
+            .get_block_timestamp()
+            .min(self.season_end_timestamp(season_id).get());
+
+        until.saturating_sub(from)
+    }
+
+    fn get_user_season_checkpoint(&self, user: &ManagedAddress) -> (u64, u64) {
+        let checkpoint = self.user_season_checkpoint(user);
+        if checkpoint.is_empty() {
+            return (0, 0);
+        }
+
+        checkpoint.get()
+    }
+
+    fn is_season_ended(&self, season_id: u64) -> bool {
+        self.blockchain().get_block_timestamp() >= self.season_end_timestamp(season_id).get()
+    }
+
+    fn is_season_claim_period_over(&self, season_id: u64) -> bool {
+        self.blockchain().get_block_timestamp()
+            >= self.season_end_timestamp(season_id).get() + SEASON_CLAIM_PERIOD
+            || self.season_swept(season_id).get()
+    }
+
+    fn require_season_exists(&self, season_id: u64) {
+        require!(
+            season_id > 0 && season_id <= self.current_season_id().get(),
+            ERR_SEASON_NOT_FOUND
+        );
+    }
+
+    #[view(getCurrentSeasonId)]
+    #[storage_mapper("currentSeasonId")]
+    fn current_season_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("seasonStartTimestamp")]
+    fn season_start_timestamp(&self, season_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("seasonEndTimestamp")]
+    fn season_end_timestamp(&self, season_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("seasonRewardTokens")]
+    fn season_reward_tokens(&self, season_id: u64)
+        -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("seasonRewardAmount")]
+    fn season_reward_amount(
+        &self,
+        season_id: u64,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("seasonClaimedAmount")]
+    fn season_claimed_amount(
+        &self,
+        season_id: u64,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("seasonSwept")]
+    fn season_swept(&self, season_id: u64) -> SingleValueMapper<bool>;
+
+    /// Score-time of the current season up to `seasonScoreTimeUpdatedAt`.
+    #[storage_mapper("seasonScoreTime")]
+    fn season_score_time(&self, season_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("seasonScoreTimeUpdatedAt")]
+    fn season_score_time_updated_at(&self) -> SingleValueMapper<u64>;
+
+    /// Season id and timestamp up to which the user's season score-time is stored.
+    #[storage_mapper("userSeasonCheckpoint")]
+    fn user_season_checkpoint(&self, user: &ManagedAddress) -> SingleValueMapper<(u64, u64)>;
+
+    #[storage_mapper("userSeasonScoreTime")]
+    fn user_season_score_time(
+        &self,
+        user: &ManagedAddress,
+        season_id: u64,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("userSeasonClaimed")]
+    fn user_season_claimed(&self, user: &ManagedAddress, season_id: u64)
+        -> SingleValueMapper<bool>;
+}
@@ -10,7 +10,54 @@ pub trait StorageModule {
 
     #[view(getRewardTokenIds)]
     #[storage_mapper("rewardTokenIds")]
-    fn reward_token_ids(&self) -> SetMapper<TokenIdentifier>;
+    fn reward_token_ids(&self) -> SetMapper<EgldOrEsdtTokenIdentifier>;
+
+    /// Subset of `reward_token_ids` that no longer accrue rewards but can still be claimed.
+    #[view(getRetiredRewardTokenIds)]
+    #[storage_mapper("retiredRewardTokenIds")]
+    fn retired_reward_token_ids(&self) -> SetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getRewardDepositors)]
+    #[storage_mapper("rewardDepositors")]
+    fn reward_depositors(&self) -> SetMapper<ManagedAddress>;
+
+    /// Every address that has ever deposited rewards, including the owner and former depositors.
+    #[view(getRewardContributors)]
+    #[storage_mapper("rewardContributors")]
+    fn reward_contributors(&self) -> SetMapper<ManagedAddress>;
+
+    #[view(getRewardContributorTokens)]
+    #[storage_mapper("rewardContributorTokens")]
+    fn reward_contributor_tokens(
+        &self,
+        contributor: &ManagedAddress,
+    ) -> SetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getRewardContribution)]
+    #[storage_mapper("rewardContribution")]
+    fn reward_contribution(
+        &self,
+        contributor: &ManagedAddress,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// Protocol fee deducted from claimed rewards of the token, in basis points.
+    #[view(getRewardFee)]
+    #[storage_mapper("rewardFee")]
+    fn reward_fee(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<u64>;
+
+    /// Accumulated fees can be swept to this address by anyone. When not set, fees are kept for the owner to withdraw.
+    #[storage_mapper("treasuryAddress")]
+    fn treasury_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getAccumulatedFees)]
+    #[storage_mapper("accumulatedFees")]
+    fn accumulated_fees(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Total fees collected for the token, whether sent to the treasury or accumulated.
+    #[view(getCollectedFees)]
+    #[storage_mapper("collectedFees")]
+    fn collected_fees(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
 
     #[view(getStakeQuantityRaw)]
     #[storage_mapper("stakeQuantity")]
@@ -21,6 +68,16 @@ pub trait StorageModule {
         nonce: u64,
     ) -> SingleValueMapper<BigUint>;
 
+    /// Raw score credited for the staked quantity of the item, at the scores in effect when it was staked.
+    #[view(getStakedItemScoreRaw)]
+    #[storage_mapper("stakedItemScore")]
+    fn staked_item_score(
+        &self,
+        address: &ManagedAddress,
+        token_id: &TokenIdentifier,
+        nonce: u64,
+    ) -> SingleValueMapper<BigUint>;
+
     #[view(getStakedItemsRaw)]
     #[storage_mapper("stakedItems")]
     fn staked_items(&self, address: &ManagedAddress)
@@ -38,13 +95,80 @@ pub trait StorageModule {
         nonce: u64,
     ) -> SingleValueMapper<BigUint>;
 
+    /// Maximum raw staking score a single user can reach. No cap if empty.
+    #[view(getMaxUserScore)]
+    #[storage_mapper("maxUserScore")]
+    fn max_user_score(&self) -> SingleValueMapper<BigUint>;
+
+    /// Maximum quantity a single user can stake of the given nonce. No cap if empty.
+    #[view(getMaxStakeQuantityPerNonce)]
+    #[storage_mapper("maxStakeQuantityPerNonce")]
+    fn max_stake_quantity_per_nonce(
+        &self,
+        collection: &TokenIdentifier,
+        nonce: u64,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// Raw score threshold and rate (in bps) applied to the part of a user's raw score above the threshold.
+    #[view(getDiminishingReturns)]
+    #[storage_mapper("diminishingReturns")]
+    fn diminishing_returns(&self) -> SingleValueMapper<(BigUint, u64)>;
+
+    /// Addresses whose whole position is frozen: they earn no rewards and cannot interact with the contract.
+    #[view(getFrozenAddresses)]
+    #[storage_mapper("frozenAddresses")]
+    fn frozen_addresses(&self) -> SetMapper<ManagedAddress>;
+
+    /// Staked items of the user that no longer contribute score and cannot be unstaked.
+    #[view(getFrozenItemsRaw)]
+    #[storage_mapper("frozenItems")]
+    fn frozen_items(&self, address: &ManagedAddress)
+        -> SetMapper<StakedAssetIdentifier<Self::Api>>;
+
+    /// Raw score removed from the user when the item was frozen, given back on release.
+    #[view(getFrozenItemScore)]
+    #[storage_mapper("frozenItemScore")]
+    fn frozen_item_score(
+        &self,
+        address: &ManagedAddress,
+        token_id: &TokenIdentifier,
+        nonce: u64,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(getAddressesWithFrozenItems)]
+    #[storage_mapper("addressesWithFrozenItems")]
+    fn addresses_with_frozen_items(&self) -> SetMapper<ManagedAddress>;
+
+    #[view(isPositionTransferEnabled)]
+    #[storage_mapper("positionTransferEnabled")]
+    fn position_transfer_enabled(&self) -> SingleValueMapper<bool>;
+
     #[view(getStakingDisabled)]
     #[storage_mapper("stakingDisabled")]
     fn staking_disabled(&self) -> SingleValueMapper<bool>;
 
-    #[view(getUnstakingItemsRaw)]
+    /// When set, users can withdraw all their NFTs through `emergencyWithdraw`, bypassing reward accounting.
+    #[view(isEmergencyMode)]
+    #[storage_mapper("emergencyMode")]
+    fn emergency_mode(&self) -> SingleValueMapper<bool>;
+
+    /// Timestamps of the user's unstaking batches, oldest first.
+    #[view(getUnstakingQueueRaw)]
+    #[storage_mapper("unstakingQueue")]
+    fn unstaking_queue(&self, address: &ManagedAddress) -> QueueMapper<u64>;
+
+    #[view(getUnstakingBatchRaw)]
+    #[storage_mapper("unstakingBatch")]
+    fn unstaking_batch(
+        &self,
+        address: &ManagedAddress,
+        timestamp: u64,
+    ) -> SingleValueMapper<ManagedVec<EsdtTokenPayment>>;
+
+    /// Unstaking batches stored before the queue was introduced.
+    /// They are moved into the queue on the user's next unstake, claim or emergency withdrawal.
     #[storage_mapper("unstakingItems")]
-    fn unstaking_items(
+    fn legacy_unstaking_items(
         &self,
         address: &ManagedAddress,
     ) -> SetMapper<(u64, ManagedVec<EsdtTokenPayment>)>;
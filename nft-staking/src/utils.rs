@@ -21,10 +21,146 @@ pub trait UtilsModule: crate::storage::StorageModule {
         BigUint::from(DEFAULT_NFT_SCORE)
     }
 
+    /// Raw score credited for the staked quantity of the item.
+    /// Items staked before the credited score was stored fall back to their current score.
+    fn get_staked_item_score(
+        &self,
+        user: &ManagedAddress,
+        token_id: &TokenIdentifier,
+        nonce: u64,
+    ) -> BigUint {
+        if !self.staked_item_score(user, token_id, nonce).is_empty() {
+            return self.staked_item_score(user, token_id, nonce).get();
+        }
+
+        self.get_nft_score(token_id, nonce) * self.stake_quantity(user, token_id, nonce).get()
+    }
+
+    /// Must be called before the staked quantity is increased.
+    fn credit_staked_item_score(
+        &self,
+        user: &ManagedAddress,
+        token_id: &TokenIdentifier,
+        nonce: u64,
+        score: &BigUint,
+    ) {
+        let item_score = self.get_staked_item_score(user, token_id, nonce) + score;
+        self.staked_item_score(user, token_id, nonce)
+            .set(item_score);
+    }
+
+    /// Removes the share of the credited score matching `amount` and returns it.
+    /// Must be called before the staked quantity is decreased.
+    fn debit_staked_item_score(
+        &self,
+        user: &ManagedAddress,
+        token_id: &TokenIdentifier,
+        nonce: u64,
+        amount: &BigUint,
+    ) -> BigUint {
+        let quantity = self.stake_quantity(user, token_id, nonce).get();
+        if quantity == 0 {
+            return BigUint::zero();
+        }
+
+        let item_score = self.get_staked_item_score(user, token_id, nonce);
+        let debited_score = &item_score * amount / quantity;
+        self.staked_item_score(user, token_id, nonce)
+            .set(item_score - &debited_score);
+
+        debited_score
+    }
+
+    /// Applies the diminishing returns curve, if any, to a raw staking score.
+    fn get_effective_score(&self, raw_score: &BigUint) -> BigUint {
+        if self.diminishing_returns().is_empty() {
+            return raw_score.clone();
+        }
+
+        let (threshold, rate_bps) = self.diminishing_returns().get();
+        if raw_score <= &threshold {
+            return raw_score.clone();
+        }
+
+        let excess = raw_score - &threshold;
+        threshold + excess * rate_bps / MAX_BPS
+    }
+
+    fn require_within_max_user_score(&self, raw_score: &BigUint) {
+        if self.max_user_score().is_empty() {
+            return;
+        }
+
+        require!(
+            raw_score <= &self.max_user_score().get(),
+            ERR_MAX_USER_SCORE_EXCEEDED
+        );
+    }
+
+    fn require_within_max_stake_quantity(
+        &self,
+        token_id: &TokenIdentifier,
+        nonce: u64,
+        quantity: &BigUint,
+    ) {
+        if self
+            .max_stake_quantity_per_nonce(token_id, nonce)
+            .is_empty()
+        {
+            return;
+        }
+
+        require!(
+            quantity <= &self.max_stake_quantity_per_nonce(token_id, nonce).get(),
+            ERR_MAX_STAKE_QUANTITY_EXCEEDED
+        );
+    }
+
+    fn get_reward_fee_amount(&self, payment: &EgldOrEsdtTokenPayment) -> BigUint {
+        let fee_bps = self.reward_fee(&payment.token_identifier).get();
+        &payment.amount * fee_bps / MAX_BPS
+    }
+
     fn require_staking_enabled(&self) {
         require!(!self.staking_disabled().get(), ERR_STAKING_DISABLED);
     }
 
+    fn require_not_emergency_mode(&self) {
+        require!(!self.emergency_mode().get(), ERR_EMERGENCY_MODE_ACTIVE);
+    }
+
+    fn require_emergency_mode(&self) {
+        require!(self.emergency_mode().get(), ERR_EMERGENCY_MODE_NOT_ACTIVE);
+    }
+
+    fn require_address_not_frozen(&self, address: &ManagedAddress) {
+        require!(
+            !self.frozen_addresses().contains(address),
+            ERR_ADDRESS_FROZEN
+        );
+    }
+
+    fn require_item_not_frozen(
+        &self,
+        address: &ManagedAddress,
+        token_id: &TokenIdentifier,
+        nonce: u64,
+    ) {
+        require!(
+            !self
+                .frozen_items(address)
+                .contains(&(token_id.clone(), nonce)),
+            ERR_ITEM_FROZEN
+        );
+    }
+
+    fn require_position_transfer_enabled(&self) {
+        require!(
+            self.position_transfer_enabled().get(),
+            ERR_POSITION_TRANSFERS_DISABLED
+        );
+    }
+
     fn require_can_stake(&self, token_id: &TokenIdentifier) {
         require!(
             self.allowed_nft_collections().contains(token_id),
@@ -32,6 +168,30 @@ pub trait UtilsModule: crate::storage::StorageModule {
         );
     }
 
+    fn is_active_reward_token(&self, token_id: &EgldOrEsdtTokenIdentifier) -> bool {
+        self.reward_token_ids().contains(token_id)
+            && !self.retired_reward_token_ids().contains(token_id)
+    }
+
+    fn get_active_reward_token_count(&self) -> usize {
+        self.reward_token_ids().len() - self.retired_reward_token_ids().len()
+    }
+
+    fn require_active_reward_token(&self, token_id: &EgldOrEsdtTokenIdentifier) {
+        require!(
+            self.is_active_reward_token(token_id),
+            ERR_REWARD_TOKEN_NOT_ACTIVE
+        );
+    }
+
+    fn require_owner_or_reward_depositor(&self, caller: &ManagedAddress) {
+        require!(
+            caller == &self.blockchain().get_owner_address()
+                || self.reward_depositors().contains(caller),
+            ERR_NOT_REWARD_DEPOSITOR
+        );
+    }
+
     fn require_user_has_enough_staked_balance(
         &self,
         user: &ManagedAddress,
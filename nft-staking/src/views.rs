@@ -1,9 +1,12 @@
 use multiversx_sc::derive_imports::*;
 use multiversx_sc::imports::*;
 
+use crate::constants::MAX_BPS;
+
 #[multiversx_sc::module]
 pub trait ViewsModule:
     crate::storage::StorageModule
+    + crate::utils::UtilsModule
     + crate::reward::reward_rate::RewardRateModule
     + crate::reward::planned_distribution::PlannedDistributionModule
 {
@@ -18,11 +21,12 @@ pub trait ViewsModule:
         }
     }
 
+    /// Pending rewards, net of the reward fee deducted when claiming.
     #[view(getPendingRewards)]
     fn get_pending_rewards_view(
         &self,
         address: &ManagedAddress,
-    ) -> ManagedVec<EsdtTokenPayment<Self::Api>> {
+    ) -> ManagedVec<EgldOrEsdtTokenPayment<Self::Api>> {
         let mut reward_token_ids = ManagedVec::new();
         for reward_token_id in self.reward_token_ids().iter() {
             reward_token_ids.push(reward_token_id.clone());
@@ -32,7 +36,12 @@ pub trait ViewsModule:
         let udr_rewards = self.get_user_undistributed_rewards_share(address);
         rr_rewards.append_vec(udr_rewards);
 
-        rr_rewards
+        let mut net_rewards = ManagedVec::new();
+        for mut reward in rr_rewards.into_iter() {
+            reward.amount -= self.get_reward_fee_amount(&reward);
+            net_rewards.push(reward);
+        }
+        net_rewards
     }
 
     #[view(getStakedItems)]
@@ -58,18 +67,101 @@ pub trait ViewsModule:
         staked_items
     }
 
+    /// Staked items of the user along with their on-chain metadata and the score credited for them.
+    /// Returns at most `size` items, starting from the `from` index.
+    #[view(getStakedItemsDetailed)]
+    fn get_staked_items_detailed(
+        &self,
+        address: &ManagedAddress,
+        from: usize,
+        size: usize,
+    ) -> ManagedVec<StakedItemDetails<Self::Api>> {
+        let sc_address = self.blockchain().get_sc_address();
+        let effective_score_bps = self.get_effective_score_bps(address);
+        let mut staked_items = ManagedVec::new();
+
+        for staked_item in self.staked_items(address).iter().skip(from).take(size) {
+            let (token_id, nonce) = staked_item.clone();
+            let amount = self.stake_quantity(address, &token_id, nonce).get();
+            let token_data = self
+                .blockchain()
+                .get_esdt_token_data(&sc_address, &token_id, nonce);
+            let score = self.get_staked_item_score(address, &token_id, nonce);
+            let frozen = self.frozen_items(address).contains(&staked_item);
+            let total_score = if frozen {
+                BigUint::zero()
+            } else {
+                &score * effective_score_bps / MAX_BPS
+            };
+
+            staked_items.push(StakedItemDetails {
+                token_identifier: token_id,
+                nonce,
+                amount,
+                name: token_data.name,
+                uris: token_data.uris,
+                attributes: token_data.attributes,
+                royalties: token_data.royalties,
+                score,
+                effective_score_bps,
+                total_score,
+                frozen,
+            });
+        }
+
+        staked_items
+    }
+
+    #[view(getStakedItemCount)]
+    fn get_staked_item_count(&self, address: &ManagedAddress) -> usize {
+        self.staked_items(address).len()
+    }
+
     #[view(getUnstakingItems)]
     fn get_unstaking_items(
         &self,
         address: &ManagedAddress,
     ) -> ManagedVec<UnstakingBatch<Self::Api>> {
         let mut unstaking_items = ManagedVec::new();
-        for (unstake_timestamp, unstake_items) in self.unstaking_items(address).iter() {
+        for (unstake_timestamp, unstake_items) in self.legacy_unstaking_items(address).iter() {
+            unstaking_items.push(UnstakingBatch::new(unstake_timestamp, unstake_items));
+        }
+        for unstake_timestamp in self.unstaking_queue(address).iter() {
+            let unstake_items = self.unstaking_batch(address, unstake_timestamp).get();
             unstaking_items.push(UnstakingBatch::new(unstake_timestamp, unstake_items));
         }
         unstaking_items
     }
 
+    /// Same batches as `getUnstakingItems`, kept with the encoding of the former storage view.
+    #[view(getUnstakingItemsRaw)]
+    fn get_unstaking_items_raw(
+        &self,
+        address: &ManagedAddress,
+    ) -> MultiValueEncoded<(u64, ManagedVec<EsdtTokenPayment>)> {
+        let mut unstaking_items = MultiValueEncoded::new();
+        for batch in self.get_unstaking_items(address).iter() {
+            unstaking_items.push((batch.unstake_timestamp, batch.unstake_items.clone()));
+        }
+        unstaking_items
+    }
+
+    /// Timestamp at which the oldest unstaking batch of the user can be claimed, if any.
+    #[view(getNextUnlockTime)]
+    fn get_next_unlock_time(&self, address: &ManagedAddress) -> OptionalValue<u64> {
+        let oldest_timestamp = match self.legacy_unstaking_items(address).iter().next() {
+            Some((unstake_timestamp, _)) => Some(unstake_timestamp),
+            None => self.unstaking_queue(address).front(),
+        };
+
+        match oldest_timestamp {
+            Some(unstake_timestamp) => {
+                OptionalValue::Some(unstake_timestamp + self.unstaking_penalty().get())
+            }
+            None => OptionalValue::None,
+        }
+    }
+
     #[view(getStakeQuantity)]
     fn get_stake_quantity(
         &self,
@@ -85,6 +177,27 @@ pub trait ViewsModule:
         self.user_staked_score(address).get()
     }
 
+    /// Share of the raw score of the user that counts towards their staked score, in bps.
+    /// Reflects the diminishing returns applied to the user, and is zero for frozen addresses.
+    #[view(getEffectiveScoreBps)]
+    fn get_effective_score_bps(&self, address: &ManagedAddress) -> u64 {
+        let raw_score = self.get_user_raw_score(address);
+        if raw_score == 0 {
+            return MAX_BPS;
+        }
+
+        let staked_score = self.user_staked_score(address).get();
+        (staked_score * MAX_BPS / raw_score)
+            .to_u64()
+            .unwrap_or(MAX_BPS)
+    }
+
+    /// Staking score of the user before diminishing returns are applied.
+    #[view(getUserRawStakingScore)]
+    fn get_user_raw_staking_score(&self, address: &ManagedAddress) -> BigUint<Self::Api> {
+        self.get_user_raw_score(address)
+    }
+
     #[view(getAggregatedStakingScore)]
     fn get_aggregated_staking_score(&self) -> BigUint<Self::Api> {
         self.aggregated_staked_score().get()
@@ -96,23 +209,59 @@ pub trait ViewsModule:
     }
 
     #[view(getRewardRate)]
-    fn get_reward_rate(&self, token_id: &TokenIdentifier) -> BigUint<Self::Api> {
+    fn get_reward_rate(&self, token_id: &EgldOrEsdtTokenIdentifier) -> BigUint<Self::Api> {
         self.current_reward_rate(token_id).get()
     }
 
     #[view(isRewardToken)]
-    fn is_reward_token(&self, token_id: &TokenIdentifier) -> bool {
+    fn is_reward_token(&self, token_id: &EgldOrEsdtTokenIdentifier) -> bool {
         self.reward_token_ids().contains(token_id)
     }
 
+    #[view(getActiveRewardTokenIds)]
+    fn get_active_reward_token_ids(&self) -> MultiValueEncoded<EgldOrEsdtTokenIdentifier> {
+        let mut active_reward_token_ids = MultiValueEncoded::new();
+        for reward_token_id in self.reward_token_ids().iter() {
+            if !self.retired_reward_token_ids().contains(&reward_token_id) {
+                active_reward_token_ids.push(reward_token_id);
+            }
+        }
+        active_reward_token_ids
+    }
+
+    /// The treasury fees are sent to, if any. Otherwise fees accumulate in the contract.
+    #[view(getTreasuryAddress)]
+    fn get_treasury_address(&self) -> OptionalValue<ManagedAddress> {
+        if self.treasury_address().is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.treasury_address().get())
+    }
+
+    /// Lists the amount contributed by the given depositor for each reward token.
+    #[view(getRewardContributions)]
+    fn get_reward_contributions(
+        &self,
+        depositor: &ManagedAddress,
+    ) -> ManagedVec<EgldOrEsdtTokenPayment<Self::Api>> {
+        let mut contributions = ManagedVec::new();
+        for token_id in self.reward_contributor_tokens(depositor).iter() {
+            let amount = self.reward_contribution(depositor, &token_id).get();
+            contributions.push(EgldOrEsdtTokenPayment::new(token_id, 0, amount));
+        }
+        contributions
+    }
+
+    /// Pending rewards of the token, net of the reward fee deducted when claiming.
     #[view(getPendingTokenReward)]
     fn get_pending_token_reward(
         &self,
         address: ManagedAddress,
-        token_id: TokenIdentifier,
+        token_id: EgldOrEsdtTokenIdentifier,
     ) -> BigUint<Self::Api> {
         if let Some(payment) = self.get_pending_rewards_for_token(&address, token_id) {
-            payment.amount
+            &payment.amount - &self.get_reward_fee_amount(&payment)
         } else {
             BigUint::zero()
         }
@@ -125,10 +274,29 @@ pub struct StakingInfo<M: ManagedTypeApi> {
     pub staked_items: ManagedVec<M, EsdtTokenPayment<M>>,
     pub staked_score: BigUint<M>,
     pub aggregated_staked_score: BigUint<M>,
-    pub pending_rewards: ManagedVec<M, EsdtTokenPayment<M>>,
+    pub pending_rewards: ManagedVec<M, EgldOrEsdtTokenPayment<M>>,
     pub unstaking_items: ManagedVec<M, UnstakingBatch<M>>,
 }
 
+/// `score` is the raw score credited for the staked amount when it was staked.
+/// `total_score` is its contribution to the staked score of the user once `effective_score_bps` is applied,
+/// and is zero for frozen items.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, ManagedVecItem)]
+pub struct StakedItemDetails<M: ManagedTypeApi> {
+    pub token_identifier: TokenIdentifier<M>,
+    pub nonce: u64,
+    pub amount: BigUint<M>,
+    pub name: ManagedBuffer<M>,
+    pub uris: ManagedVec<M, ManagedBuffer<M>>,
+    pub attributes: ManagedBuffer<M>,
+    pub royalties: BigUint<M>,
+    pub score: BigUint<M>,
+    pub effective_score_bps: u64,
+    pub total_score: BigUint<M>,
+    pub frozen: bool,
+}
+
 #[type_abi]
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, ManagedVecItem)]
 pub struct UnstakingBatch<M: ManagedTypeApi> {
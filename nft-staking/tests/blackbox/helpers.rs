@@ -1,6 +1,7 @@
 use multiversx_sc::types::TestTokenIdentifier;
 use multiversx_sc_scenario::imports::*;
 use multiversx_sc_scenario::{ExpectValue, ScenarioWorld};
+use nft_staking::proxy::{Operation, Role};
 
 use crate::config::{OWNER_ADDRESS, SC_ADDRESS};
 
@@ -98,6 +99,58 @@ pub fn check_if_token_is_reward_token(world: &mut ScenarioWorld, token_id: &Test
         .run();
 }
 
+pub fn check_egld_reward_rate(world: &mut ScenarioWorld, expected_rate: RustBigUint) {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_reward_rate(EgldOrEsdtTokenIdentifier::egld())
+        .returns(ExpectValue(expected_rate))
+        .run();
+}
+
+pub fn check_if_egld_is_reward_token(world: &mut ScenarioWorld) {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .is_reward_token(EgldOrEsdtTokenIdentifier::egld())
+        .returns(ExpectValue(true))
+        .run();
+}
+
+pub fn check_pending_egld_reward(
+    world: &mut ScenarioWorld,
+    user: &TestAddress,
+    expected_reward: RustBigUint,
+) {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_pending_token_reward(user.to_address(), EgldOrEsdtTokenIdentifier::egld())
+        .returns(ExpectValue(expected_reward))
+        .run();
+}
+
+pub fn check_reward_contribution(
+    world: &mut ScenarioWorld,
+    depositor: &TestAddress,
+    token_id: &TestTokenIdentifier,
+    expected_amount: u64,
+) {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .reward_contribution(
+            depositor.to_address(),
+            EgldOrEsdtTokenIdentifier::esdt(token_id.to_token_identifier()),
+        )
+        .returns(ExpectValue(expected_amount))
+        .run();
+}
+
 pub fn check_pending_reward(
     world: &mut ScenarioWorld,
     user: &TestAddress,
@@ -140,6 +193,28 @@ pub fn send_disable_staking_tx(world: &mut ScenarioWorld) {
         .run();
 }
 
+pub fn send_enable_emergency_mode_tx(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .enable_emergency_mode()
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+pub fn send_disable_emergency_mode_tx(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .disable_emergency_mode()
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
 pub fn send_allow_collection_tx(world: &mut ScenarioWorld, token_id: &TestTokenIdentifier) {
     world
         .tx()
@@ -166,6 +241,122 @@ pub fn send_disallow_collection_tx(world: &mut ScenarioWorld, token_id: &TestTok
         .run();
 }
 
+pub fn send_add_reward_token_tx(world: &mut ScenarioWorld, token_id: &TestTokenIdentifier) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .add_reward_token(EgldOrEsdtTokenIdentifier::esdt(
+            token_id.to_token_identifier(),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+pub fn send_add_egld_reward_token_tx(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .add_reward_token(EgldOrEsdtTokenIdentifier::egld())
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+pub fn send_retire_reward_token_tx(world: &mut ScenarioWorld, token_id: &TestTokenIdentifier) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .retire_reward_token(EgldOrEsdtTokenIdentifier::esdt(
+            token_id.to_token_identifier(),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+pub fn send_remove_reward_token_tx(world: &mut ScenarioWorld, token_id: &TestTokenIdentifier) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .remove_reward_token(EgldOrEsdtTokenIdentifier::esdt(
+            token_id.to_token_identifier(),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+pub fn send_add_reward_depositor_tx(world: &mut ScenarioWorld, depositor: &TestAddress) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .add_reward_depositor(depositor.to_address())
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+pub fn send_remove_reward_depositor_tx(world: &mut ScenarioWorld, depositor: &TestAddress) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .remove_reward_depositor(depositor.to_address())
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+pub fn send_grant_role_tx(world: &mut ScenarioWorld, role: Role, address: &TestAddress) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .grant_role(role, address.to_address())
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+pub fn send_revoke_role_tx(world: &mut ScenarioWorld, role: Role, address: &TestAddress) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .revoke_role(role, address.to_address())
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+pub fn send_pause_operations_tx(world: &mut ScenarioWorld, operations: &[Operation]) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .pause_operations(MultiValueEncoded::from_iter(operations.iter().copied()))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+pub fn send_unpause_operations_tx(world: &mut ScenarioWorld, operations: &[Operation]) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .unpause_operations(MultiValueEncoded::from_iter(operations.iter().copied()))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
 /// User transactions
 pub fn send_stake_tx(
     world: &mut ScenarioWorld,
@@ -269,6 +460,18 @@ pub fn send_distribute_rewards_tx(
         .run();
 }
 
+pub fn send_distribute_egld_rewards_tx(world: &mut ScenarioWorld, amount: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .distribute_rewards()
+        .egld(amount)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
 pub fn send_set_distribution_plan_tx(
     world: &mut ScenarioWorld,
     token_id: TestTokenIdentifier,
@@ -290,13 +493,78 @@ pub fn send_set_distribution_plan_tx(
         .run();
 }
 
+pub fn send_set_egld_distribution_plan_tx(
+    world: &mut ScenarioWorld,
+    start_round: u64,
+    end_round: u64,
+    total_amount: u64,
+) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .create_distribution_plan(start_round, end_round)
+        .egld(total_amount)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
 pub fn send_claim_rewards_tx(world: &mut ScenarioWorld, user: &TestAddress) {
     world
         .tx()
         .from(user.to_address())
         .to(SC_ADDRESS)
         .typed(nft_staking::proxy::NftStakingProxy)
-        .claim_rewards()
+        .claim_rewards(MultiValueEncoded::new())
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+pub fn send_claim_selected_rewards_tx(
+    world: &mut ScenarioWorld,
+    user: &TestAddress,
+    token_ids: &[&TestTokenIdentifier],
+) {
+    let mut token_ids_arg = MultiValueEncoded::new();
+    for token_id in token_ids.iter() {
+        token_ids_arg.push(EgldOrEsdtTokenIdentifier::esdt(
+            token_id.to_token_identifier(),
+        ));
+    }
+
+    world
+        .tx()
+        .from(user.to_address())
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_rewards(token_ids_arg)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+pub fn send_emergency_withdraw_tx(world: &mut ScenarioWorld, user: &TestAddress) {
+    world
+        .tx()
+        .from(user.to_address())
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .emergency_withdraw()
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+pub fn send_claim_unstaked_tx(
+    world: &mut ScenarioWorld,
+    user: &TestAddress,
+    max_batches: Option<usize>,
+) {
+    world
+        .tx()
+        .from(user.to_address())
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_unstaked(OptionalValue::from(max_batches))
         .returns(ExpectStatus(0u64))
         .run();
 }
@@ -2,6 +2,7 @@ use test_setup::setup_world_with_contract;
 
 // pub mod permissions;
 // pub mod stake;
+#[allow(clippy::empty_line_after_doc_comments)] // section banners in the helpers
 pub mod helpers;
 pub mod scenarios;
 pub mod test_setup;
@@ -0,0 +1,200 @@
+use multiversx_sc::types::EsdtTokenPayment;
+use multiversx_sc_scenario::{
+    managed_biguint, ExpectError, ExpectStatus, ExpectValue, ScenarioTxRun, ScenarioWorld,
+};
+use nft_staking::constants::{
+    DEFAULT_NFT_SCORE, ERR_INVALID_DIMINISHING_RATE, ERR_MAX_STAKE_QUANTITY_EXCEEDED,
+    ERR_MAX_USER_SCORE_EXCEEDED, MAX_BPS,
+};
+
+use crate::{
+    blackbox::{
+        helpers::{
+            check_aggregated_staking_score, check_user_staking_score, send_stake_tx,
+            send_unstake_tx,
+        },
+        test_setup::setup_world_with_contract,
+    },
+    config::{
+        MANAGER_ADDRESS, NFT_TOKEN_ID, OWNER_ADDRESS, SC_ADDRESS, SFT_TOKEN_ID, USER_ADDRESS,
+    },
+};
+
+fn send_set_max_user_score_tx(world: &mut ScenarioWorld, max_score: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .set_max_user_score(max_score)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn send_set_diminishing_returns_tx(world: &mut ScenarioWorld, threshold: u64, rate_bps: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .set_diminishing_returns(threshold, rate_bps)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn check_user_raw_staking_score(world: &mut ScenarioWorld, expected_score: u64) {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_user_raw_staking_score(USER_ADDRESS.to_address())
+        .returns(ExpectValue(expected_score))
+        .run();
+}
+
+#[test]
+fn staking_above_max_user_score_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    send_set_max_user_score_tx(&mut world, DEFAULT_NFT_SCORE * 2);
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(NFT_TOKEN_ID, 2, 1)],
+    );
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .stake()
+        .esdt(EsdtTokenPayment::new(
+            NFT_TOKEN_ID.to_token_identifier(),
+            3,
+            managed_biguint!(1),
+        ))
+        .returns(ExpectError(4u64, ERR_MAX_USER_SCORE_EXCEEDED))
+        .run();
+}
+
+#[test]
+fn removing_max_user_score_should_allow_staking_again() {
+    let mut world = setup_world_with_contract();
+
+    send_set_max_user_score_tx(&mut world, DEFAULT_NFT_SCORE);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_set_max_user_score_tx(&mut world, 0);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 2, 1)]);
+
+    check_user_staking_score(&mut world, &USER_ADDRESS, DEFAULT_NFT_SCORE * 2);
+}
+
+#[test]
+fn staking_above_max_quantity_per_nonce_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .set_max_stake_quantity_per_nonce(SFT_TOKEN_ID.to_token_identifier(), 1u64, 3u64)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 2)]);
+    // the cap only applies to nonce 1
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(SFT_TOKEN_ID, 2, 5)]);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .stake()
+        .esdt(EsdtTokenPayment::new(
+            SFT_TOKEN_ID.to_token_identifier(),
+            1,
+            managed_biguint!(2),
+        ))
+        .returns(ExpectError(4u64, ERR_MAX_STAKE_QUANTITY_EXCEEDED))
+        .run();
+}
+
+#[test]
+fn diminishing_returns_should_reduce_score_above_threshold() {
+    let mut world = setup_world_with_contract();
+
+    send_set_diminishing_returns_tx(&mut world, DEFAULT_NFT_SCORE * 2, MAX_BPS / 2);
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[
+            &(NFT_TOKEN_ID, 1, 1),
+            &(NFT_TOKEN_ID, 2, 1),
+            &(NFT_TOKEN_ID, 3, 1),
+            &(NFT_TOKEN_ID, 4, 1),
+        ],
+    );
+
+    check_user_raw_staking_score(&mut world, DEFAULT_NFT_SCORE * 4);
+    check_user_staking_score(&mut world, &USER_ADDRESS, DEFAULT_NFT_SCORE * 3);
+    check_aggregated_staking_score(&mut world, DEFAULT_NFT_SCORE * 3);
+
+    send_unstake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 3, 1), &(NFT_TOKEN_ID, 4, 1)],
+    );
+
+    check_user_raw_staking_score(&mut world, DEFAULT_NFT_SCORE * 2);
+    check_user_staking_score(&mut world, &USER_ADDRESS, DEFAULT_NFT_SCORE * 2);
+    check_aggregated_staking_score(&mut world, DEFAULT_NFT_SCORE * 2);
+}
+
+#[test]
+fn recomputing_user_score_should_apply_changed_diminishing_returns() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[
+            &(NFT_TOKEN_ID, 1, 1),
+            &(NFT_TOKEN_ID, 2, 1),
+            &(NFT_TOKEN_ID, 3, 1),
+            &(NFT_TOKEN_ID, 4, 1),
+        ],
+    );
+    send_set_diminishing_returns_tx(&mut world, DEFAULT_NFT_SCORE * 2, MAX_BPS / 2);
+
+    check_user_staking_score(&mut world, &USER_ADDRESS, DEFAULT_NFT_SCORE * 4);
+
+    world
+        .tx()
+        .from(MANAGER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .recompute_user_score(USER_ADDRESS.to_address())
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    check_user_raw_staking_score(&mut world, DEFAULT_NFT_SCORE * 4);
+    check_user_staking_score(&mut world, &USER_ADDRESS, DEFAULT_NFT_SCORE * 3);
+    check_aggregated_staking_score(&mut world, DEFAULT_NFT_SCORE * 3);
+}
+
+#[test]
+fn diminishing_returns_rate_above_max_bps_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .set_diminishing_returns(DEFAULT_NFT_SCORE, MAX_BPS + 1)
+        .returns(ExpectError(4u64, ERR_INVALID_DIMINISHING_RATE))
+        .run();
+}
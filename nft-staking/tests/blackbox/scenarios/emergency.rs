@@ -0,0 +1,141 @@
+use multiversx_sc::types::EsdtTokenPayment;
+use multiversx_sc_scenario::{managed_biguint, ExpectError, ExpectValue, ScenarioTxRun};
+use nft_staking::constants::{
+    DEFAULT_NFT_SCORE, ERR_EMERGENCY_MODE_ACTIVE, ERR_EMERGENCY_MODE_NOT_ACTIVE,
+    ERR_NOTHING_TO_WITHDRAW,
+};
+
+use crate::{
+    blackbox::{
+        helpers::{
+            check_aggregated_staking_score, check_staked_amount, check_user_staking_score,
+            send_disable_emergency_mode_tx, send_distribute_rewards_tx, send_emergency_withdraw_tx,
+            send_enable_emergency_mode_tx, send_stake_tx, send_unstake_tx,
+        },
+        test_setup::setup_world_with_contract,
+    },
+    config::{
+        INITIAL_SFT_BALANCE, NFT_TOKEN_ID, OWNER_ADDRESS, REWARD_TOKEN_ID_1, SC_ADDRESS,
+        SFT_TOKEN_ID, USER_ADDRESS,
+    },
+};
+
+#[test]
+fn emergency_withdraw_without_emergency_mode_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .emergency_withdraw()
+        .returns(ExpectError(4u64, ERR_EMERGENCY_MODE_NOT_ACTIVE))
+        .run();
+}
+
+#[test]
+fn emergency_withdraw_should_return_staked_and_unstaking_items() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(SFT_TOKEN_ID, 1, 3)],
+    );
+    send_stake_tx(&mut world, &OWNER_ADDRESS, &[&(SFT_TOKEN_ID, 2, 1)]);
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 2)]);
+
+    send_enable_emergency_mode_tx(&mut world);
+    send_emergency_withdraw_tx(&mut world, &USER_ADDRESS);
+
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 1, 1, "")
+        .esdt_nft_balance_and_attributes(SFT_TOKEN_ID, 1, INITIAL_SFT_BALANCE, "");
+
+    check_staked_amount(&mut world, &USER_ADDRESS, &NFT_TOKEN_ID, 1, 0);
+    check_staked_amount(&mut world, &USER_ADDRESS, &SFT_TOKEN_ID, 1, 0);
+    check_user_staking_score(&mut world, &USER_ADDRESS, 0);
+    check_aggregated_staking_score(&mut world, DEFAULT_NFT_SCORE);
+}
+
+#[test]
+fn emergency_withdraw_with_nothing_staked_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    send_enable_emergency_mode_tx(&mut world);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .emergency_withdraw()
+        .returns(ExpectError(4u64, ERR_NOTHING_TO_WITHDRAW))
+        .run();
+}
+
+#[test]
+fn staking_during_emergency_mode_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    send_enable_emergency_mode_tx(&mut world);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .stake()
+        .esdt(EsdtTokenPayment::new(
+            NFT_TOKEN_ID.to_token_identifier(),
+            1,
+            managed_biguint!(1),
+        ))
+        .returns(ExpectError(4u64, ERR_EMERGENCY_MODE_ACTIVE))
+        .run();
+}
+
+#[test]
+fn restaking_after_emergency_withdraw_should_not_earn_past_rewards() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_stake_tx(&mut world, &OWNER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+
+    send_enable_emergency_mode_tx(&mut world);
+    send_emergency_withdraw_tx(&mut world, &USER_ADDRESS);
+    send_disable_emergency_mode_tx(&mut world);
+
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_pending_token_reward(
+            USER_ADDRESS.to_address(),
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+        )
+        .returns(ExpectValue(0u64))
+        .run();
+}
+
+#[test]
+fn only_owner_can_enable_emergency_mode() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .enable_emergency_mode()
+        .returns(ExpectError(4u64, "Endpoint can only be called by owner"))
+        .run();
+}
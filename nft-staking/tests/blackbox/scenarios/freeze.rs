@@ -0,0 +1,246 @@
+use multiversx_sc::types::{
+    EsdtTokenPayment, MultiValueEncoded, MultiValueManagedVec, ReturnsResult,
+};
+use multiversx_sc_scenario::{
+    managed_biguint, rust_biguint, ExpectError, ExpectStatus, ScenarioTxRun, ScenarioWorld,
+};
+use nft_staking::constants::{
+    DEFAULT_NFT_SCORE, ERR_ADDRESS_FROZEN, ERR_ITEM_FROZEN, ERR_MISSING_ROLE,
+};
+
+use crate::{
+    blackbox::{
+        helpers::{
+            check_aggregated_staking_score, check_pending_reward, check_staked_amount,
+            check_user_staking_score, send_distribute_rewards_tx, send_emergency_withdraw_tx,
+            send_enable_emergency_mode_tx, send_stake_tx, send_unstake_tx,
+        },
+        test_setup::setup_world_with_contract,
+    },
+    config::{
+        MANAGER_ADDRESS, NFT_TOKEN_ID, OWNER_ADDRESS, REWARD_TOKEN_ID_1, SC_ADDRESS, SFT_TOKEN_ID,
+        USER_ADDRESS,
+    },
+};
+
+fn send_freeze_item_tx(world: &mut ScenarioWorld, nonce: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .freeze_item(
+            USER_ADDRESS.to_address(),
+            NFT_TOKEN_ID.to_token_identifier(),
+            nonce,
+        )
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn send_release_item_tx(world: &mut ScenarioWorld, nonce: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .release_item(
+            USER_ADDRESS.to_address(),
+            NFT_TOKEN_ID.to_token_identifier(),
+            nonce,
+        )
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn send_freeze_address_tx(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .freeze_address(USER_ADDRESS.to_address())
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn send_release_address_tx(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .release_address(USER_ADDRESS.to_address())
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+#[test]
+fn frozen_item_should_stop_earning_rewards_after_settlement() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(NFT_TOKEN_ID, 2, 1)],
+    );
+    send_stake_tx(&mut world, &OWNER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 300);
+
+    send_freeze_item_tx(&mut world, 1);
+
+    check_user_staking_score(&mut world, &USER_ADDRESS, DEFAULT_NFT_SCORE);
+    check_aggregated_staking_score(&mut world, DEFAULT_NFT_SCORE * 2);
+
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 200);
+
+    check_pending_reward(
+        &mut world,
+        &USER_ADDRESS,
+        &REWARD_TOKEN_ID_1,
+        rust_biguint!(300),
+    );
+}
+
+#[test]
+fn frozen_item_cannot_be_unstaked_until_released() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_freeze_item_tx(&mut world, 1);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .unstake(MultiValueManagedVec::from_single_item(
+            EsdtTokenPayment::new(NFT_TOKEN_ID.to_token_identifier(), 1, managed_biguint!(1)),
+        ))
+        .returns(ExpectError(4u64, ERR_ITEM_FROZEN))
+        .run();
+
+    send_release_item_tx(&mut world, 1);
+
+    check_user_staking_score(&mut world, &USER_ADDRESS, DEFAULT_NFT_SCORE);
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+}
+
+#[test]
+fn frozen_address_should_lose_score_and_be_locked_out() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+    send_freeze_address_tx(&mut world);
+
+    check_user_staking_score(&mut world, &USER_ADDRESS, 0);
+    check_aggregated_staking_score(&mut world, 0);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_rewards(MultiValueEncoded::new())
+        .returns(ExpectError(4u64, ERR_ADDRESS_FROZEN))
+        .run();
+
+    send_release_address_tx(&mut world);
+
+    check_user_staking_score(&mut world, &USER_ADDRESS, DEFAULT_NFT_SCORE);
+    check_pending_reward(
+        &mut world,
+        &USER_ADDRESS,
+        &REWARD_TOKEN_ID_1,
+        rust_biguint!(100),
+    );
+}
+
+#[test]
+fn frozen_items_should_be_listed() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(NFT_TOKEN_ID, 2, 1)],
+    );
+    send_freeze_item_tx(&mut world, 2);
+
+    let frozen_items = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_frozen_items()
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    assert_eq!(frozen_items.len(), 1);
+    let (address, token_id, nonce) = frozen_items[0].clone().into_tuple();
+    assert_eq!(address, USER_ADDRESS.to_managed_address());
+    assert_eq!(token_id, NFT_TOKEN_ID.to_token_identifier());
+    assert_eq!(nonce, 2);
+}
+
+#[test]
+fn emergency_withdraw_should_keep_frozen_items() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(NFT_TOKEN_ID, 2, 1)],
+    );
+    send_freeze_item_tx(&mut world, 1);
+    send_enable_emergency_mode_tx(&mut world);
+    send_emergency_withdraw_tx(&mut world, &USER_ADDRESS);
+
+    check_staked_amount(&mut world, &USER_ADDRESS, &NFT_TOKEN_ID, 1, 1);
+    check_staked_amount(&mut world, &USER_ADDRESS, &NFT_TOKEN_ID, 2, 0);
+}
+
+#[test]
+fn freezing_requires_moderator_role() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(MANAGER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .freeze_address(USER_ADDRESS.to_address())
+        .returns(ExpectError(4u64, ERR_MISSING_ROLE))
+        .run();
+}
+
+#[test]
+fn frozen_item_should_not_contribute_to_detailed_score() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(NFT_TOKEN_ID, 2, 1)],
+    );
+    send_freeze_item_tx(&mut world, 1);
+
+    let staked_items = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_staked_items_detailed(USER_ADDRESS.to_address(), 0usize, 10usize)
+        .returns(ReturnsResult)
+        .run();
+
+    assert_eq!(staked_items.len(), 2);
+    let frozen_item = staked_items.get(0);
+    assert!(frozen_item.frozen);
+    assert_eq!(frozen_item.score, managed_biguint!(DEFAULT_NFT_SCORE));
+    assert_eq!(frozen_item.total_score, managed_biguint!(0));
+    let item = staked_items.get(1);
+    assert!(!item.frozen);
+    assert_eq!(item.total_score, managed_biguint!(DEFAULT_NFT_SCORE));
+}
@@ -1,4 +1,12 @@
+pub mod caps;
+pub mod emergency;
+pub mod freeze;
+pub mod pause;
+pub mod raffle;
 pub mod reward;
+pub mod roles;
 pub mod score;
+pub mod season;
 pub mod stake;
+pub mod transfer;
 pub mod unstake;
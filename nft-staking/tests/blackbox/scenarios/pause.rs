@@ -0,0 +1,202 @@
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    types::{EsdtTokenPayment, MultiValueEncoded, MultiValueManagedVec, ReturnsResult},
+};
+use multiversx_sc_scenario::{
+    imports::SetStateStep, managed_biguint, ExpectError, ExpectStatus, ScenarioTxRun,
+};
+use nft_staking::{
+    constants::{ERR_MISSING_ROLE, ERR_OPERATION_PAUSED, UNSTAKE_PENALTY},
+    proxy::{Operation, Role},
+};
+
+use crate::{
+    blackbox::{
+        helpers::{
+            send_claim_rewards_tx, send_distribute_rewards_tx, send_grant_role_tx,
+            send_pause_operations_tx, send_stake_tx, send_unpause_operations_tx, send_unstake_tx,
+        },
+        test_setup::setup_world_with_contract,
+    },
+    config::{
+        MANAGER_ADDRESS, NFT_TOKEN_ID, OWNER_ADDRESS, REWARD_TOKEN_ID_1, SC_ADDRESS, USER_ADDRESS,
+    },
+};
+
+#[test]
+fn paused_stake_should_still_allow_unstake_and_claims() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+    send_pause_operations_tx(&mut world, &[Operation::Stake]);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .stake()
+        .esdt(EsdtTokenPayment::new(
+            NFT_TOKEN_ID.to_token_identifier(),
+            2,
+            managed_biguint!(1),
+        ))
+        .returns(ExpectError(4u64, ERR_OPERATION_PAUSED))
+        .run();
+
+    send_claim_rewards_tx(&mut world, &USER_ADDRESS);
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(UNSTAKE_PENALTY + 1));
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_unstaked(OptionalValue::<usize>::None)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+#[test]
+fn paused_unstake_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_pause_operations_tx(&mut world, &[Operation::Unstake]);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .unstake(MultiValueManagedVec::from_single_item(
+            EsdtTokenPayment::new(NFT_TOKEN_ID.to_token_identifier(), 1, managed_biguint!(1)),
+        ))
+        .returns(ExpectError(4u64, ERR_OPERATION_PAUSED))
+        .run();
+}
+
+#[test]
+fn paused_claim_unstaked_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_pause_operations_tx(&mut world, &[Operation::ClaimUnstaked]);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(UNSTAKE_PENALTY + 1));
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_unstaked(OptionalValue::<usize>::None)
+        .returns(ExpectError(4u64, ERR_OPERATION_PAUSED))
+        .run();
+}
+
+#[test]
+fn paused_claim_rewards_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+    send_pause_operations_tx(&mut world, &[Operation::ClaimRewards]);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_rewards(MultiValueEncoded::new())
+        .returns(ExpectError(4u64, ERR_OPERATION_PAUSED))
+        .run();
+}
+
+#[test]
+fn paused_distribution_should_reject_new_rewards() {
+    let mut world = setup_world_with_contract();
+
+    send_pause_operations_tx(&mut world, &[Operation::Distribution]);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .distribute_rewards()
+        .esdt(EsdtTokenPayment::new(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+            0,
+            managed_biguint!(100),
+        ))
+        .returns(ExpectError(4u64, ERR_OPERATION_PAUSED))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .create_distribution_plan(0u64, 100u64)
+        .esdt(EsdtTokenPayment::new(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+            0,
+            managed_biguint!(100),
+        ))
+        .returns(ExpectError(4u64, ERR_OPERATION_PAUSED))
+        .run();
+}
+
+#[test]
+fn unpaused_operation_should_work_again() {
+    let mut world = setup_world_with_contract();
+
+    send_pause_operations_tx(&mut world, &[Operation::Stake, Operation::Unstake]);
+    send_unpause_operations_tx(&mut world, &[Operation::Stake]);
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+
+    let pause_state = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_pause_state()
+        .returns(ReturnsResult)
+        .run();
+
+    assert!(!pause_state.staking_disabled);
+    assert!(!pause_state.stake_paused);
+    assert!(pause_state.unstake_paused);
+    assert!(!pause_state.claim_unstaked_paused);
+    assert!(!pause_state.claim_rewards_paused);
+    assert!(!pause_state.distribution_paused);
+    assert!(!pause_state.transfer_paused);
+}
+
+#[test]
+fn pausing_operations_requires_pauser_role() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(MANAGER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .pause_operations(MultiValueEncoded::from_iter([Operation::Stake]))
+        .returns(ExpectError(4u64, ERR_MISSING_ROLE))
+        .run();
+
+    send_grant_role_tx(&mut world, Role::Pauser, &MANAGER_ADDRESS);
+
+    world
+        .tx()
+        .from(MANAGER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .pause_operations(MultiValueEncoded::from_iter([Operation::Stake]))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
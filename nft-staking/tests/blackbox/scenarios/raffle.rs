@@ -0,0 +1,471 @@
+use multiversx_sc::types::{Address, EsdtTokenPayment, ManagedAddress, ReturnsResult, TestAddress};
+use multiversx_sc_scenario::{
+    api::StaticApi, imports::SetStateStep, managed_biguint, managed_buffer, ExpectError,
+    ExpectStatus, ScenarioTxRun, ScenarioWorld,
+};
+use nft_staking::{
+    constants::{
+        DEFAULT_NFT_SCORE, ERR_ADDRESS_FROZEN, ERR_MISSING_ROLE, ERR_NO_RAFFLE_PRIZES_TO_CLAIM,
+        ERR_NO_STAKED_SCORE, ERR_RAFFLE_CANCELLED, ERR_RAFFLE_CLAIM_PERIOD_NOT_OVER,
+        ERR_RAFFLE_DRAW_TOO_EARLY, ERR_RAFFLE_ENTRIES_CLOSED, ERR_RAFFLE_ENTRY_SCORE_TOO_LOW,
+        ERR_TOO_MANY_RAFFLE_ENTRIES, MAX_RAFFLE_ENTRIES,
+    },
+    proxy::RaffleInfo,
+};
+
+use crate::{
+    blackbox::{
+        helpers::{send_stake_tx, send_unstake_tx},
+        test_setup::setup_world_with_contract,
+    },
+    config::{
+        INITIAL_SFT_BALANCE, NFT_TOKEN_ID, OWNER_ADDRESS, REWARD_TOKEN_ID_1, SC_ADDRESS,
+        SFT_TOKEN_ID, USER_ADDRESS,
+    },
+};
+
+const DRAW_TIMESTAMP: u64 = 100;
+const CLAIM_PERIOD: u64 = 1_000;
+
+fn send_create_raffle_tx(world: &mut ScenarioWorld) -> u64 {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .create_raffle(DRAW_TIMESTAMP, CLAIM_PERIOD, 0u64)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn send_add_raffle_prizes_tx(world: &mut ScenarioWorld, raffle_id: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .add_raffle_prizes(raffle_id)
+        .esdt(EsdtTokenPayment::new(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+            0,
+            managed_biguint!(1_000),
+        ))
+        .esdt(EsdtTokenPayment::new(
+            SFT_TOKEN_ID.to_token_identifier(),
+            1,
+            managed_biguint!(1),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn send_enter_raffle_tx(world: &mut ScenarioWorld, entrant: &TestAddress, raffle_id: u64) {
+    world
+        .tx()
+        .from(*entrant)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .enter_raffle(raffle_id)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn send_draw_raffle_tx(world: &mut ScenarioWorld, raffle_id: u64) {
+    world.set_state_step(SetStateStep::new().block_timestamp(DRAW_TIMESTAMP));
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .draw_raffle(raffle_id)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn get_raffle(world: &mut ScenarioWorld, raffle_id: u64) -> RaffleInfo<StaticApi> {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_raffle(raffle_id)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn raffle_cannot_be_drawn_before_draw_timestamp() {
+    let mut world = setup_world_with_contract();
+
+    let raffle_id = send_create_raffle_tx(&mut world);
+    send_add_raffle_prizes_tx(&mut world, raffle_id);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .draw_raffle(raffle_id)
+        .returns(ExpectError(4u64, ERR_RAFFLE_DRAW_TOO_EARLY))
+        .run();
+}
+
+#[test]
+fn each_entrant_should_win_at_most_one_prize() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_stake_tx(&mut world, &OWNER_ADDRESS, &[&(SFT_TOKEN_ID, 2, 1)]);
+
+    let raffle_id = send_create_raffle_tx(&mut world);
+    send_add_raffle_prizes_tx(&mut world, raffle_id);
+    send_enter_raffle_tx(&mut world, &USER_ADDRESS, raffle_id);
+    send_enter_raffle_tx(&mut world, &OWNER_ADDRESS, raffle_id);
+    send_draw_raffle_tx(&mut world, raffle_id);
+
+    let raffle = get_raffle(&mut world, raffle_id);
+    assert!(raffle.drawn);
+    assert_eq!(raffle.prizes.len(), 2);
+    let first_winner = raffle.prizes.get(0).winner.clone();
+    let second_winner = raffle.prizes.get(1).winner.clone();
+    assert_ne!(first_winner, second_winner);
+    assert!(
+        first_winner == USER_ADDRESS.to_managed_address()
+            || first_winner == OWNER_ADDRESS.to_managed_address()
+    );
+    assert!(
+        second_winner == USER_ADDRESS.to_managed_address()
+            || second_winner == OWNER_ADDRESS.to_managed_address()
+    );
+}
+
+#[test]
+fn winner_should_be_able_to_claim_prize() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+
+    let raffle_id = send_create_raffle_tx(&mut world);
+    send_add_raffle_prizes_tx(&mut world, raffle_id);
+    send_enter_raffle_tx(&mut world, &USER_ADDRESS, raffle_id);
+    send_draw_raffle_tx(&mut world, raffle_id);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_raffle_prizes(raffle_id)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    let raffle = get_raffle(&mut world, raffle_id);
+    assert_eq!(
+        raffle.prizes.get(0).winner,
+        USER_ADDRESS.to_managed_address()
+    );
+    assert!(raffle.prizes.get(0).claimed);
+    // a single staker can only win one of the two prizes
+    assert_eq!(raffle.prizes.get(1).winner, ManagedAddress::zero());
+
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(REWARD_TOKEN_ID_1, 1_000);
+}
+
+#[test]
+fn address_without_score_cannot_enter() {
+    let mut world = setup_world_with_contract();
+
+    let raffle_id = send_create_raffle_tx(&mut world);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .enter_raffle(raffle_id)
+        .returns(ExpectError(4u64, ERR_NO_STAKED_SCORE))
+        .run();
+}
+
+#[test]
+fn address_below_min_entry_score_cannot_enter() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    let raffle_id = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .create_raffle(DRAW_TIMESTAMP, CLAIM_PERIOD, DEFAULT_NFT_SCORE * 2)
+        .returns(ReturnsResult)
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .enter_raffle(raffle_id)
+        .returns(ExpectError(4u64, ERR_RAFFLE_ENTRY_SCORE_TOO_LOW))
+        .run();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 2, 1)]);
+    send_enter_raffle_tx(&mut world, &USER_ADDRESS, raffle_id);
+}
+
+#[test]
+fn full_raffle_should_replace_lowest_entry_with_higher_score() {
+    let mut world = setup_world_with_contract();
+
+    let raffle_id = send_create_raffle_tx(&mut world);
+    let mut entrants = Vec::new();
+    for index in 0..=MAX_RAFFLE_ENTRIES {
+        let mut address_bytes = [0u8; 32];
+        address_bytes[..8].copy_from_slice(&(index as u64 + 1).to_be_bytes());
+        let entrant = Address::from(address_bytes);
+        world
+            .account(&entrant)
+            .nonce(1)
+            .esdt_nft_balance(SFT_TOKEN_ID, 1, 1, managed_buffer!(b""));
+        world
+            .tx()
+            .from(&entrant)
+            .to(SC_ADDRESS)
+            .typed(nft_staking::proxy::NftStakingProxy)
+            .stake()
+            .esdt(EsdtTokenPayment::new(
+                SFT_TOKEN_ID.to_token_identifier(),
+                1,
+                managed_biguint!(1),
+            ))
+            .returns(ExpectStatus(0u64))
+            .run();
+        entrants.push(entrant);
+    }
+
+    for entrant in entrants.iter().take(MAX_RAFFLE_ENTRIES) {
+        world
+            .tx()
+            .from(entrant)
+            .to(SC_ADDRESS)
+            .typed(nft_staking::proxy::NftStakingProxy)
+            .enter_raffle(raffle_id)
+            .returns(ExpectStatus(0u64))
+            .run();
+    }
+
+    // An entrant with the same score as the lowest entry cannot take its place.
+    world
+        .tx()
+        .from(&entrants[MAX_RAFFLE_ENTRIES])
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .enter_raffle(raffle_id)
+        .returns(ExpectError(4u64, ERR_TOO_MANY_RAFFLE_ENTRIES))
+        .run();
+
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(NFT_TOKEN_ID, 2, 1)],
+    );
+    send_enter_raffle_tx(&mut world, &USER_ADDRESS, raffle_id);
+
+    let raffle = get_raffle(&mut world, raffle_id);
+    assert_eq!(raffle.entries, MAX_RAFFLE_ENTRIES);
+    let raffle_entries = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .raffle_entries(raffle_id)
+        .returns(ReturnsResult)
+        .run();
+    assert!(raffle_entries
+        .into_iter()
+        .any(|entrant| entrant == USER_ADDRESS.to_managed_address()));
+}
+
+#[test]
+fn entries_should_close_at_draw_timestamp() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    let raffle_id = send_create_raffle_tx(&mut world);
+    world.set_state_step(SetStateStep::new().block_timestamp(DRAW_TIMESTAMP));
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .enter_raffle(raffle_id)
+        .returns(ExpectError(4u64, ERR_RAFFLE_ENTRIES_CLOSED))
+        .run();
+}
+
+#[test]
+fn entrant_who_unstaked_before_draw_should_not_win() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+
+    let raffle_id = send_create_raffle_tx(&mut world);
+    send_add_raffle_prizes_tx(&mut world, raffle_id);
+    send_enter_raffle_tx(&mut world, &USER_ADDRESS, raffle_id);
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_draw_raffle_tx(&mut world, raffle_id);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_raffle_prizes(raffle_id)
+        .returns(ExpectError(4u64, ERR_NO_RAFFLE_PRIZES_TO_CLAIM))
+        .run();
+}
+
+#[test]
+fn unclaimed_prizes_can_be_rolled_over_after_claim_period() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+
+    let raffle_id = send_create_raffle_tx(&mut world);
+    let next_raffle_id = send_create_raffle_tx(&mut world);
+    send_add_raffle_prizes_tx(&mut world, raffle_id);
+    send_enter_raffle_tx(&mut world, &USER_ADDRESS, raffle_id);
+    send_draw_raffle_tx(&mut world, raffle_id);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .rollover_raffle_prizes(raffle_id, next_raffle_id)
+        .returns(ExpectError(4u64, ERR_RAFFLE_CLAIM_PERIOD_NOT_OVER))
+        .run();
+
+    world.set_state_step(SetStateStep::new().block_timestamp(DRAW_TIMESTAMP + CLAIM_PERIOD));
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .rollover_raffle_prizes(raffle_id, next_raffle_id)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    let next_raffle = get_raffle(&mut world, next_raffle_id);
+    assert_eq!(next_raffle.prizes.len(), 2);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_raffle_prizes(raffle_id)
+        .returns(ExpectError(4u64, ERR_NO_RAFFLE_PRIZES_TO_CLAIM))
+        .run();
+
+    world
+        .check_account(OWNER_ADDRESS)
+        .esdt_nft_balance_and_attributes(SFT_TOKEN_ID, 1, INITIAL_SFT_BALANCE - 1, "");
+}
+
+#[test]
+fn only_owner_can_create_raffle() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .create_raffle(DRAW_TIMESTAMP, CLAIM_PERIOD, 0u64)
+        .returns(ExpectError(4u64, "Endpoint can only be called by owner"))
+        .run();
+}
+
+#[test]
+fn address_without_role_cannot_draw_raffle() {
+    let mut world = setup_world_with_contract();
+
+    let raffle_id = send_create_raffle_tx(&mut world);
+    world.set_state_step(SetStateStep::new().block_timestamp(DRAW_TIMESTAMP));
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .draw_raffle(raffle_id)
+        .returns(ExpectError(4u64, ERR_MISSING_ROLE))
+        .run();
+}
+
+#[test]
+fn cancelled_raffle_should_refund_prizes_to_owner() {
+    let mut world = setup_world_with_contract();
+
+    let raffle_id = send_create_raffle_tx(&mut world);
+    send_add_raffle_prizes_tx(&mut world, raffle_id);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .cancel_raffle(raffle_id)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    let raffle = get_raffle(&mut world, raffle_id);
+    assert!(raffle.cancelled);
+    assert!(raffle.prizes.get(0).claimed);
+    world
+        .check_account(OWNER_ADDRESS)
+        .esdt_nft_balance_and_attributes(SFT_TOKEN_ID, 1, INITIAL_SFT_BALANCE, "");
+
+    world.set_state_step(SetStateStep::new().block_timestamp(DRAW_TIMESTAMP));
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .draw_raffle(raffle_id)
+        .returns(ExpectError(4u64, ERR_RAFFLE_CANCELLED))
+        .run();
+}
+
+#[test]
+fn frozen_winner_cannot_claim_prize() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+
+    let raffle_id = send_create_raffle_tx(&mut world);
+    send_add_raffle_prizes_tx(&mut world, raffle_id);
+    send_enter_raffle_tx(&mut world, &USER_ADDRESS, raffle_id);
+    send_draw_raffle_tx(&mut world, raffle_id);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .freeze_address(USER_ADDRESS.to_address())
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_raffle_prizes(raffle_id)
+        .returns(ExpectError(4u64, ERR_ADDRESS_FROZEN))
+        .run();
+}
@@ -1,3 +1,4 @@
+use multiversx_sc::types::MultiValueEncoded;
 use multiversx_sc_scenario::{imports::SetStateStep, rust_biguint, ExpectMessage, ScenarioTxRun};
 use nft_staking::constants::{DEFAULT_NFT_SCORE, ERR_NO_REWARDS_TO_CLAIM, ERR_STAKING_DISABLED};
 
@@ -109,7 +110,7 @@ fn cannot_double_claim_rewards() {
         .from(USER_ADDRESS)
         .to(SC_ADDRESS)
         .typed(nft_staking::proxy::NftStakingProxy)
-        .claim_rewards()
+        .claim_rewards(MultiValueEncoded::new())
         .returns(ExpectMessage(ERR_NO_REWARDS_TO_CLAIM))
         .run();
 }
@@ -127,7 +128,7 @@ fn cannot_claim_rewards_with_staking_disabled() {
         .from(USER_ADDRESS)
         .to(SC_ADDRESS)
         .typed(nft_staking::proxy::NftStakingProxy)
-        .claim_rewards()
+        .claim_rewards(MultiValueEncoded::new())
         .returns(ExpectMessage(ERR_STAKING_DISABLED))
         .run();
 }
@@ -142,7 +143,7 @@ fn cannot_claim_rewards_with_no_rewards_to_claim() {
         .from(USER_ADDRESS)
         .to(SC_ADDRESS)
         .typed(nft_staking::proxy::NftStakingProxy)
-        .claim_rewards()
+        .claim_rewards(MultiValueEncoded::new())
         .returns(ExpectMessage(ERR_NO_REWARDS_TO_CLAIM))
         .run();
 }
@@ -0,0 +1,171 @@
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, EsdtTokenPayment};
+use multiversx_sc_scenario::{
+    managed_biguint, rust_biguint, ExpectError, ExpectStatus, ExpectValue, ScenarioTxRun,
+    ScenarioWorld,
+};
+use nft_staking::constants::ERR_NOT_REWARD_DEPOSITOR;
+
+use crate::{
+    blackbox::{
+        helpers::{
+            check_pending_reward, check_reward_contribution, send_add_reward_depositor_tx,
+            send_distribute_rewards_tx, send_remove_reward_depositor_tx, send_stake_tx,
+        },
+        test_setup::setup_world_with_contract,
+    },
+    config::{
+        DEPOSITOR_ADDRESS, NFT_TOKEN_ID, OWNER_ADDRESS, REWARD_TOKEN_ID_1, REWARD_TOKEN_ID_2,
+        SC_ADDRESS, USER_ADDRESS,
+    },
+};
+
+fn send_depositor_distribute_rewards_tx(world: &mut ScenarioWorld, amount: u64) {
+    world
+        .tx()
+        .from(DEPOSITOR_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .distribute_rewards()
+        .with_esdt_transfer(EsdtTokenPayment::new(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+            0u64,
+            managed_biguint!(amount),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+#[test]
+fn non_depositor_cannot_distribute_rewards() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(DEPOSITOR_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .distribute_rewards()
+        .with_esdt_transfer(EsdtTokenPayment::new(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+            0u64,
+            managed_biguint!(100),
+        ))
+        .returns(ExpectError(4u64, ERR_NOT_REWARD_DEPOSITOR))
+        .run();
+}
+
+#[test]
+fn non_depositor_cannot_create_distribution_plan() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(DEPOSITOR_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .create_distribution_plan(0u64, 100u64)
+        .with_esdt_transfer(EsdtTokenPayment::new(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+            0u64,
+            managed_biguint!(100),
+        ))
+        .returns(ExpectError(4u64, ERR_NOT_REWARD_DEPOSITOR))
+        .run();
+}
+
+#[test]
+fn depositor_can_distribute_rewards_to_stakers() {
+    let mut world = setup_world_with_contract();
+
+    send_add_reward_depositor_tx(&mut world, &DEPOSITOR_ADDRESS);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_depositor_distribute_rewards_tx(&mut world, 100);
+
+    check_pending_reward(
+        &mut world,
+        &USER_ADDRESS,
+        &REWARD_TOKEN_ID_1,
+        rust_biguint!(100),
+    );
+    check_reward_contribution(&mut world, &DEPOSITOR_ADDRESS, &REWARD_TOKEN_ID_1, 100);
+}
+
+#[test]
+fn depositor_can_create_distribution_plan() {
+    let mut world = setup_world_with_contract();
+
+    send_add_reward_depositor_tx(&mut world, &DEPOSITOR_ADDRESS);
+
+    world
+        .tx()
+        .from(DEPOSITOR_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .create_distribution_plan(0u64, 100u64)
+        .with_esdt_transfer(EsdtTokenPayment::new(
+            REWARD_TOKEN_ID_2.to_token_identifier(),
+            0u64,
+            managed_biguint!(100),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    check_reward_contribution(&mut world, &DEPOSITOR_ADDRESS, &REWARD_TOKEN_ID_2, 100);
+}
+
+#[test]
+fn removed_depositor_cannot_distribute_rewards() {
+    let mut world = setup_world_with_contract();
+
+    send_add_reward_depositor_tx(&mut world, &DEPOSITOR_ADDRESS);
+    send_remove_reward_depositor_tx(&mut world, &DEPOSITOR_ADDRESS);
+
+    world
+        .tx()
+        .from(DEPOSITOR_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .distribute_rewards()
+        .with_esdt_transfer(EsdtTokenPayment::new(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+            0u64,
+            managed_biguint!(100),
+        ))
+        .returns(ExpectError(4u64, ERR_NOT_REWARD_DEPOSITOR))
+        .run();
+}
+
+#[test]
+fn contributions_are_attributed_per_depositor_and_token() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_add_reward_depositor_tx(&mut world, &DEPOSITOR_ADDRESS);
+    send_depositor_distribute_rewards_tx(&mut world, 100);
+    send_depositor_distribute_rewards_tx(&mut world, 50);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 30);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_2, 20);
+
+    check_reward_contribution(&mut world, &DEPOSITOR_ADDRESS, &REWARD_TOKEN_ID_1, 150);
+    check_reward_contribution(&mut world, &OWNER_ADDRESS, &REWARD_TOKEN_ID_1, 30);
+    check_reward_contribution(&mut world, &OWNER_ADDRESS, &REWARD_TOKEN_ID_2, 20);
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_reward_contributions(OWNER_ADDRESS.to_address())
+        .returns(ExpectValue(vec![
+            EgldOrEsdtTokenPayment::new(
+                EgldOrEsdtTokenIdentifier::esdt(REWARD_TOKEN_ID_1.to_token_identifier()),
+                0,
+                managed_biguint!(30),
+            ),
+            EgldOrEsdtTokenPayment::new(
+                EgldOrEsdtTokenIdentifier::esdt(REWARD_TOKEN_ID_2.to_token_identifier()),
+                0,
+                managed_biguint!(20),
+            ),
+        ]))
+        .run();
+}
@@ -0,0 +1,76 @@
+use multiversx_sc_scenario::{imports::SetStateStep, rust_biguint};
+use nft_staking::{constants::DEFAULT_NFT_SCORE, reward::reward_rate::REWARD_RATE_DENOMINATION};
+
+use crate::{
+    blackbox::{
+        helpers::{
+            check_egld_reward_rate, check_if_egld_is_reward_token, check_pending_egld_reward,
+            send_claim_rewards_tx, send_distribute_egld_rewards_tx, send_distribute_rewards_tx,
+            send_set_egld_distribution_plan_tx, send_stake_tx,
+        },
+        test_setup::setup_world_with_contract,
+    },
+    config::{INITIAL_ESDT_BALANCE, NFT_TOKEN_ID, REWARD_TOKEN_ID_1, USER_ADDRESS},
+};
+
+#[test]
+fn egld_can_be_registered_as_reward_token() {
+    let mut world = setup_world_with_contract();
+
+    check_if_egld_is_reward_token(&mut world);
+}
+
+#[test]
+fn manual_egld_distribution_correctly_updates_reward_rate() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 2, 1)]);
+
+    send_distribute_egld_rewards_tx(&mut world, DEFAULT_NFT_SCORE);
+
+    check_egld_reward_rate(&mut world, rust_biguint!(REWARD_RATE_DENOMINATION / 2));
+}
+
+#[test]
+fn pending_egld_reward_is_properly_calculated_for_planned_distribution() {
+    let mut world = setup_world_with_contract();
+
+    send_set_egld_distribution_plan_tx(&mut world, 0, 100, 100); // 1 EGLD unit per round
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+
+    world.set_state_step(SetStateStep::new().block_round(1));
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 2, 1)]);
+
+    check_pending_egld_reward(&mut world, &USER_ADDRESS, rust_biguint!(1));
+}
+
+#[test]
+fn claim_rewards_should_send_egld_rewards() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_egld_rewards_tx(&mut world, 1_000);
+
+    send_claim_rewards_tx(&mut world, &USER_ADDRESS);
+
+    world
+        .check_account(USER_ADDRESS)
+        .balance(INITIAL_ESDT_BALANCE + 1_000);
+}
+
+#[test]
+fn claim_rewards_should_send_egld_and_esdt_rewards_together() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_egld_rewards_tx(&mut world, 1_000);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 2_000);
+
+    send_claim_rewards_tx(&mut world, &USER_ADDRESS);
+
+    world
+        .check_account(USER_ADDRESS)
+        .balance(INITIAL_ESDT_BALANCE + 1_000)
+        .esdt_balance(REWARD_TOKEN_ID_1, 2_000);
+}
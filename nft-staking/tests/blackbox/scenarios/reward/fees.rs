@@ -0,0 +1,193 @@
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    types::{EgldOrEsdtTokenIdentifier, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    rust_biguint, ExpectError, ExpectStatus, ExpectValue, ScenarioTxRun, ScenarioWorld,
+};
+use nft_staking::constants::{ERR_INVALID_REWARD_FEE, ERR_NO_FEES_TO_WITHDRAW};
+
+use crate::{
+    blackbox::{
+        helpers::{
+            check_pending_reward, send_claim_rewards_tx, send_distribute_rewards_tx, send_stake_tx,
+        },
+        test_setup::setup_world_with_contract,
+    },
+    config::{
+        INITIAL_ESDT_BALANCE, MANAGER_ADDRESS, NFT_TOKEN_ID, OWNER_ADDRESS, REWARD_TOKEN_ID_1,
+        REWARD_TOKEN_ID_2, SC_ADDRESS, USER_ADDRESS,
+    },
+};
+
+const REWARD_FEE_BPS: u64 = 1_000; // 10%
+
+fn send_set_reward_fee_tx(world: &mut ScenarioWorld, fee_bps: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .set_reward_fee(
+            EgldOrEsdtTokenIdentifier::esdt(REWARD_TOKEN_ID_1.to_token_identifier()),
+            fee_bps,
+        )
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn send_withdraw_fees_tx(world: &mut ScenarioWorld) {
+    let mut token_ids = MultiValueEncoded::new();
+    token_ids.push(EgldOrEsdtTokenIdentifier::esdt(
+        REWARD_TOKEN_ID_1.to_token_identifier(),
+    ));
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .withdraw_fees(token_ids)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+#[test]
+fn claim_should_deduct_fee_and_accumulate_it_for_owner() {
+    let mut world = setup_world_with_contract();
+
+    send_set_reward_fee_tx(&mut world, REWARD_FEE_BPS);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_2, 100);
+
+    send_claim_rewards_tx(&mut world, &USER_ADDRESS);
+
+    // tokens without a fee are paid in full
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(REWARD_TOKEN_ID_1, 90)
+        .esdt_balance(REWARD_TOKEN_ID_2, 100);
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .accumulated_fees(EgldOrEsdtTokenIdentifier::esdt(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+        ))
+        .returns(ExpectValue(10u64))
+        .run();
+
+    send_withdraw_fees_tx(&mut world);
+    world
+        .check_account(OWNER_ADDRESS)
+        .esdt_balance(REWARD_TOKEN_ID_1, INITIAL_ESDT_BALANCE - 100 + 10);
+
+    let mut token_ids = MultiValueEncoded::new();
+    token_ids.push(EgldOrEsdtTokenIdentifier::esdt(
+        REWARD_TOKEN_ID_1.to_token_identifier(),
+    ));
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .withdraw_fees(token_ids)
+        .returns(ExpectError(4u64, ERR_NO_FEES_TO_WITHDRAW))
+        .run();
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .collected_fees(EgldOrEsdtTokenIdentifier::esdt(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+        ))
+        .returns(ExpectValue(10u64))
+        .run();
+}
+
+#[test]
+fn pending_rewards_should_be_net_of_fee() {
+    let mut world = setup_world_with_contract();
+
+    send_set_reward_fee_tx(&mut world, REWARD_FEE_BPS);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+
+    check_pending_reward(
+        &mut world,
+        &USER_ADDRESS,
+        &REWARD_TOKEN_ID_1,
+        rust_biguint!(90),
+    );
+}
+
+#[test]
+fn accumulated_fees_can_be_swept_to_treasury() {
+    let mut world = setup_world_with_contract();
+
+    send_set_reward_fee_tx(&mut world, REWARD_FEE_BPS);
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .set_treasury_address(OptionalValue::Some(MANAGER_ADDRESS.to_address()))
+        .returns(ExpectStatus(0u64))
+        .run();
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+
+    send_claim_rewards_tx(&mut world, &USER_ADDRESS);
+
+    // fees are only sent to the treasury when swept
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(REWARD_TOKEN_ID_1, 90);
+    world
+        .check_account(MANAGER_ADDRESS)
+        .esdt_balance(REWARD_TOKEN_ID_1, 0);
+
+    let mut token_ids = MultiValueEncoded::new();
+    token_ids.push(EgldOrEsdtTokenIdentifier::esdt(
+        REWARD_TOKEN_ID_1.to_token_identifier(),
+    ));
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .sweep_fees_to_treasury(token_ids)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world
+        .check_account(MANAGER_ADDRESS)
+        .esdt_balance(REWARD_TOKEN_ID_1, 10);
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .accumulated_fees(EgldOrEsdtTokenIdentifier::esdt(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+        ))
+        .returns(ExpectValue(0u64))
+        .run();
+}
+
+#[test]
+fn reward_fee_cannot_exceed_max_bps() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .set_reward_fee(
+            EgldOrEsdtTokenIdentifier::esdt(REWARD_TOKEN_ID_1.to_token_identifier()),
+            10_001u64,
+        )
+        .returns(ExpectError(4u64, ERR_INVALID_REWARD_FEE))
+        .run();
+}
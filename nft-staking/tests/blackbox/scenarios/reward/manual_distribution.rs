@@ -1,15 +1,25 @@
-use multiversx_sc_scenario::{imports::SetStateStep, rust_biguint};
-use nft_staking::{constants::DEFAULT_NFT_SCORE, reward::reward_rate::REWARD_RATE_DENOMINATION};
+use multiversx_sc::types::EsdtTokenPayment;
+use multiversx_sc_scenario::{
+    imports::SetStateStep, managed_biguint, rust_biguint, ExpectError, ExpectValue, ScenarioTxRun,
+    ScenarioWorld,
+};
+use nft_staking::{
+    constants::{DEFAULT_NFT_SCORE, ERR_NOTHING_STAKED, ERR_REWARD_TOKEN_NOT_ACTIVE},
+    reward::reward_rate::REWARD_RATE_DENOMINATION,
+};
 
 use crate::{
     blackbox::{
         helpers::{
-            check_if_token_is_reward_token, check_last_distribution_round, check_reward_rate,
-            send_distribute_rewards_tx, send_stake_tx,
+            check_last_distribution_round, check_reward_rate, send_distribute_rewards_tx,
+            send_stake_tx, send_unstake_tx,
         },
         test_setup::setup_world_with_contract,
     },
-    config::{NFT_TOKEN_ID, REWARD_TOKEN_ID_1, REWARD_TOKEN_ID_2, USER_ADDRESS},
+    config::{
+        NFT_TOKEN_ID, OWNER_ADDRESS, REWARD_TOKEN_ID_1, SC_ADDRESS, SFT_TOKEN_ID,
+        UNREGISTERED_REWARD_TOKEN_ID, USER_ADDRESS,
+    },
 };
 
 #[test]
@@ -31,27 +41,85 @@ fn manual_reward_distribution_correctly_updates_reward_rate() {
 fn manual_reward_distribution_does_not_update_last_distribution_round() {
     let mut world = setup_world_with_contract();
 
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
     send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, DEFAULT_NFT_SCORE);
     check_last_distribution_round(&mut world, 0);
 }
 
 #[test]
-fn manual_reward_distribution_does_not_fail_with_nothing_staked() {
+fn manual_reward_distribution_with_nothing_staked_should_fail() {
     let mut world = setup_world_with_contract();
     world.set_state_step(SetStateStep::new().block_round(100));
 
-    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, DEFAULT_NFT_SCORE);
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .distribute_rewards()
+        .with_esdt_transfer(EsdtTokenPayment::new(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+            0u64,
+            managed_biguint!(DEFAULT_NFT_SCORE),
+        ))
+        .returns(ExpectError(4u64, ERR_NOTHING_STAKED))
+        .run();
 
     check_last_distribution_round(&mut world, 0);
 }
 
 #[test]
-fn manual_reward_distribution_adds_new_token_to_reward_token_list() {
+fn manual_reward_distribution_of_unregistered_token_should_fail() {
     let mut world = setup_world_with_contract();
 
-    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, DEFAULT_NFT_SCORE);
-    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_2, DEFAULT_NFT_SCORE);
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .distribute_rewards()
+        .with_esdt_transfer(EsdtTokenPayment::new(
+            UNREGISTERED_REWARD_TOKEN_ID.to_token_identifier(),
+            0u64,
+            managed_biguint!(DEFAULT_NFT_SCORE),
+        ))
+        .returns(ExpectError(4u64, ERR_REWARD_TOKEN_NOT_ACTIVE))
+        .run();
+}
+
+#[test]
+fn staking_after_a_distribution_should_not_earn_it() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &OWNER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+
+    check_no_pending_reward(&mut world);
+}
+
+#[test]
+fn restaking_after_unstaking_everything_should_not_earn_past_distributions() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &OWNER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 1)]);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 2, 1)]);
+
+    check_no_pending_reward(&mut world);
+}
 
-    check_if_token_is_reward_token(&mut world, &REWARD_TOKEN_ID_1);
-    check_if_token_is_reward_token(&mut world, &REWARD_TOKEN_ID_2);
+fn check_no_pending_reward(world: &mut ScenarioWorld) {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_pending_token_reward(
+            USER_ADDRESS.to_address(),
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+        )
+        .returns(ExpectValue(0u64))
+        .run();
 }
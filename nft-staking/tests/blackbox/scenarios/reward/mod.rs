@@ -1,3 +1,8 @@
 pub mod manual_distribution;
 pub mod planned_distribution;
-pub mod common;
\ No newline at end of file
+pub mod common;
+pub mod depositors;
+pub mod egld;
+pub mod fees;
+pub mod registry;
+pub mod selective_claim;
\ No newline at end of file
@@ -1,16 +1,24 @@
-use multiversx_sc_scenario::{imports::SetStateStep, rust_biguint};
-use nft_staking::{constants::DEFAULT_NFT_SCORE, reward::reward_rate::REWARD_RATE_DENOMINATION};
+use multiversx_sc::types::EsdtTokenPayment;
+use multiversx_sc_scenario::{
+    imports::SetStateStep, managed_biguint, rust_biguint, ExpectError, ScenarioTxRun,
+};
+use nft_staking::{
+    constants::{DEFAULT_NFT_SCORE, ERR_REWARD_TOKEN_NOT_ACTIVE},
+    reward::reward_rate::REWARD_RATE_DENOMINATION,
+};
 
 use crate::{
     blackbox::{
         helpers::{
-            check_distribution_amount_per_round, check_if_token_is_reward_token,
-            check_last_distribution_round, check_reward_rate, send_set_distribution_plan_tx,
-            send_stake_tx,
+            check_distribution_amount_per_round, check_last_distribution_round, check_reward_rate,
+            send_set_distribution_plan_tx, send_stake_tx,
         },
         test_setup::setup_world_with_contract,
     },
-    config::{NFT_TOKEN_ID, OWNER_ADDRESS, REWARD_TOKEN_ID_1, SFT_TOKEN_ID, USER_ADDRESS},
+    config::{
+        NFT_TOKEN_ID, OWNER_ADDRESS, REWARD_TOKEN_ID_1, SC_ADDRESS, SFT_TOKEN_ID,
+        UNREGISTERED_REWARD_TOKEN_ID, USER_ADDRESS,
+    },
 };
 
 #[test]
@@ -27,12 +35,22 @@ fn planned_reward_distribution_round_distribution_amount_calculation_should_work
 }
 
 #[test]
-fn planned_reward_distribution_adds_new_token_to_reward_token_list() {
+fn planned_reward_distribution_of_unregistered_token_should_fail() {
     let mut world = setup_world_with_contract();
 
-    send_set_distribution_plan_tx(&mut world, REWARD_TOKEN_ID_1, 0, 100, 100); // 1 tokens per round
-
-    check_if_token_is_reward_token(&mut world, &REWARD_TOKEN_ID_1);
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .create_distribution_plan(0u64, 100u64)
+        .with_esdt_transfer(EsdtTokenPayment::new(
+            UNREGISTERED_REWARD_TOKEN_ID.to_token_identifier(),
+            0u64,
+            managed_biguint!(100),
+        ))
+        .returns(ExpectError(4u64, ERR_REWARD_TOKEN_NOT_ACTIVE))
+        .run();
 }
 
 #[test]
@@ -0,0 +1,297 @@
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, EsdtTokenPayment, TokenIdentifier};
+use multiversx_sc_scenario::{
+    api::StaticApi, managed_biguint, rust_biguint, ExpectError, ExpectValue, ScenarioTxRun,
+    ScenarioWorld,
+};
+use nft_staking::constants::{
+    ERR_REWARD_TOKEN_ALREADY_ACTIVE, ERR_REWARD_TOKEN_HAS_DISTRIBUTION_PLAN,
+    ERR_REWARD_TOKEN_HAS_OUTSTANDING_REWARDS, ERR_REWARD_TOKEN_NOT_ACTIVE,
+    ERR_REWARD_TOKEN_NOT_RETIRED, ERR_REWARD_TOKEN_OUTSTANDING_REWARDS_NOT_TRACKED,
+    ERR_TOO_MANY_ACTIVE_REWARD_TOKENS, ERR_TOO_MANY_REWARD_TOKENS, MAX_ACTIVE_REWARD_TOKENS,
+    MAX_REWARD_TOKENS,
+};
+
+use crate::{
+    blackbox::{
+        helpers::{
+            check_if_token_is_reward_token, check_pending_reward, send_add_reward_token_tx,
+            send_claim_rewards_tx, send_distribute_rewards_tx, send_remove_reward_token_tx,
+            send_retire_reward_token_tx, send_set_distribution_plan_tx, send_stake_tx,
+        },
+        test_setup::setup_world_with_contract,
+    },
+    config::{
+        NFT_TOKEN_ID, OWNER_ADDRESS, REWARD_TOKEN_ID_1, SC_ADDRESS, UNREGISTERED_REWARD_TOKEN_ID,
+        USER_ADDRESS,
+    },
+};
+
+const REGISTERED_TOKENS_AT_SETUP: usize = 3; // REWARD_TOKEN_ID_1, REWARD_TOKEN_ID_2, EGLD
+
+fn check_is_not_reward_token(world: &mut ScenarioWorld, token_id: &TokenIdentifier<StaticApi>) {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .is_reward_token(EgldOrEsdtTokenIdentifier::esdt(token_id.clone()))
+        .returns(ExpectValue(false))
+        .run();
+}
+
+#[test]
+fn added_reward_token_can_be_distributed() {
+    let mut world = setup_world_with_contract();
+
+    send_add_reward_token_tx(&mut world, &UNREGISTERED_REWARD_TOKEN_ID);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, UNREGISTERED_REWARD_TOKEN_ID, 100);
+
+    check_if_token_is_reward_token(&mut world, &UNREGISTERED_REWARD_TOKEN_ID);
+    check_pending_reward(
+        &mut world,
+        &USER_ADDRESS,
+        &UNREGISTERED_REWARD_TOKEN_ID,
+        rust_biguint!(100),
+    );
+}
+
+#[test]
+fn adding_an_active_reward_token_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .add_reward_token(EgldOrEsdtTokenIdentifier::esdt(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+        ))
+        .returns(ExpectError(4u64, ERR_REWARD_TOKEN_ALREADY_ACTIVE))
+        .run();
+}
+
+#[test]
+fn adding_more_than_the_maximum_active_reward_tokens_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    for i in REGISTERED_TOKENS_AT_SETUP..MAX_ACTIVE_REWARD_TOKENS {
+        let ticker = format!("RWD{i}-123456");
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(nft_staking::proxy::NftStakingProxy)
+            .add_reward_token(EgldOrEsdtTokenIdentifier::esdt(TokenIdentifier::from(
+                ticker.as_str(),
+            )))
+            .run();
+    }
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .add_reward_token(EgldOrEsdtTokenIdentifier::esdt(
+            UNREGISTERED_REWARD_TOKEN_ID.to_token_identifier(),
+        ))
+        .returns(ExpectError(4u64, ERR_TOO_MANY_ACTIVE_REWARD_TOKENS))
+        .run();
+}
+
+#[test]
+fn adding_more_than_the_maximum_reward_tokens_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    for i in REGISTERED_TOKENS_AT_SETUP..MAX_REWARD_TOKENS {
+        let ticker = format!("RWD{i}-123456");
+        let token_id = EgldOrEsdtTokenIdentifier::esdt(TokenIdentifier::from(ticker.as_str()));
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(nft_staking::proxy::NftStakingProxy)
+            .add_reward_token(token_id.clone())
+            .run();
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(nft_staking::proxy::NftStakingProxy)
+            .retire_reward_token(token_id)
+            .run();
+    }
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .add_reward_token(EgldOrEsdtTokenIdentifier::esdt(
+            UNREGISTERED_REWARD_TOKEN_ID.to_token_identifier(),
+        ))
+        .returns(ExpectError(4u64, ERR_TOO_MANY_REWARD_TOKENS))
+        .run();
+}
+
+#[test]
+fn retired_reward_token_cannot_be_distributed() {
+    let mut world = setup_world_with_contract();
+
+    send_retire_reward_token_tx(&mut world, &REWARD_TOKEN_ID_1);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .distribute_rewards()
+        .with_esdt_transfer(EsdtTokenPayment::new(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+            0u64,
+            managed_biguint!(100),
+        ))
+        .returns(ExpectError(4u64, ERR_REWARD_TOKEN_NOT_ACTIVE))
+        .run();
+}
+
+#[test]
+fn retired_reward_token_remains_claimable() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+    send_retire_reward_token_tx(&mut world, &REWARD_TOKEN_ID_1);
+
+    check_if_token_is_reward_token(&mut world, &REWARD_TOKEN_ID_1);
+    send_claim_rewards_tx(&mut world, &USER_ADDRESS);
+
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(REWARD_TOKEN_ID_1, 100);
+}
+
+#[test]
+fn retiring_reward_token_with_distribution_plan_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    send_set_distribution_plan_tx(&mut world, REWARD_TOKEN_ID_1, 0, 100, 100);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .retire_reward_token(EgldOrEsdtTokenIdentifier::esdt(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+        ))
+        .returns(ExpectError(4u64, ERR_REWARD_TOKEN_HAS_DISTRIBUTION_PLAN))
+        .run();
+}
+
+#[test]
+fn removing_active_reward_token_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .remove_reward_token(EgldOrEsdtTokenIdentifier::esdt(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+        ))
+        .returns(ExpectError(4u64, ERR_REWARD_TOKEN_NOT_RETIRED))
+        .run();
+}
+
+#[test]
+fn removing_reward_token_with_untracked_rewards_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .outstanding_rewards_tracked(EgldOrEsdtTokenIdentifier::esdt(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+        ))
+        .returns(ExpectValue(true))
+        .run();
+
+    // Only tokens registered through addRewardToken have their unclaimed rewards tracked
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .remove_reward_token(EgldOrEsdtTokenIdentifier::esdt(
+            UNREGISTERED_REWARD_TOKEN_ID.to_token_identifier(),
+        ))
+        .returns(ExpectError(
+            4u64,
+            ERR_REWARD_TOKEN_OUTSTANDING_REWARDS_NOT_TRACKED,
+        ))
+        .run();
+}
+
+#[test]
+fn removed_reward_token_is_no_longer_a_reward_token() {
+    let mut world = setup_world_with_contract();
+
+    send_retire_reward_token_tx(&mut world, &REWARD_TOKEN_ID_1);
+    send_remove_reward_token_tx(&mut world, &REWARD_TOKEN_ID_1);
+
+    check_is_not_reward_token(&mut world, &REWARD_TOKEN_ID_1.to_token_identifier());
+}
+
+#[test]
+fn removing_reward_token_with_unclaimed_rewards_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+    send_retire_reward_token_tx(&mut world, &REWARD_TOKEN_ID_1);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .remove_reward_token(EgldOrEsdtTokenIdentifier::esdt(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+        ))
+        .returns(ExpectError(4u64, ERR_REWARD_TOKEN_HAS_OUTSTANDING_REWARDS))
+        .run();
+}
+
+#[test]
+fn reward_token_can_be_removed_once_its_rewards_are_claimed() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+    send_retire_reward_token_tx(&mut world, &REWARD_TOKEN_ID_1);
+    send_claim_rewards_tx(&mut world, &USER_ADDRESS);
+    send_remove_reward_token_tx(&mut world, &REWARD_TOKEN_ID_1);
+
+    check_is_not_reward_token(&mut world, &REWARD_TOKEN_ID_1.to_token_identifier());
+}
+
+#[test]
+fn re_adding_retired_reward_token_reactivates_it() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_retire_reward_token_tx(&mut world, &REWARD_TOKEN_ID_1);
+    send_add_reward_token_tx(&mut world, &REWARD_TOKEN_ID_1);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+
+    check_pending_reward(
+        &mut world,
+        &USER_ADDRESS,
+        &REWARD_TOKEN_ID_1,
+        rust_biguint!(100),
+    );
+}
@@ -0,0 +1,129 @@
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, MultiValueEncoded};
+use multiversx_sc_scenario::{rust_biguint, ExpectError, ExpectValue, ScenarioTxRun};
+use nft_staking::constants::{ERR_INVALID_REWARD_TOKEN, ERR_NO_REWARDS_TO_CLAIM};
+
+use crate::{
+    blackbox::{
+        helpers::{
+            check_pending_reward, send_claim_selected_rewards_tx, send_distribute_rewards_tx,
+            send_stake_tx,
+        },
+        test_setup::setup_world_with_contract,
+    },
+    config::{
+        NFT_TOKEN_ID, REWARD_TOKEN_ID_1, REWARD_TOKEN_ID_2, SC_ADDRESS,
+        UNREGISTERED_REWARD_TOKEN_ID, USER_ADDRESS,
+    },
+};
+
+#[test]
+fn selective_claim_should_only_pay_selected_tokens() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_2, 200);
+
+    send_claim_selected_rewards_tx(&mut world, &USER_ADDRESS, &[&REWARD_TOKEN_ID_1]);
+
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(REWARD_TOKEN_ID_1, 100)
+        .esdt_balance(REWARD_TOKEN_ID_2, 0);
+    check_pending_reward(
+        &mut world,
+        &USER_ADDRESS,
+        &REWARD_TOKEN_ID_2,
+        rust_biguint!(200),
+    );
+}
+
+#[test]
+fn selective_claim_should_not_touch_reward_rate_of_other_tokens() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_2, 200);
+
+    send_claim_selected_rewards_tx(&mut world, &USER_ADDRESS, &[&REWARD_TOKEN_ID_1]);
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .user_reward_rate(
+            USER_ADDRESS.to_address(),
+            EgldOrEsdtTokenIdentifier::esdt(REWARD_TOKEN_ID_2.to_token_identifier()),
+        )
+        .returns(ExpectValue(0u64))
+        .run();
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .user_stored_rewards(
+            USER_ADDRESS.to_address(),
+            EgldOrEsdtTokenIdentifier::esdt(REWARD_TOKEN_ID_2.to_token_identifier()),
+        )
+        .returns(ExpectValue(0u64))
+        .run();
+}
+
+#[test]
+fn selective_claim_of_multiple_tokens_should_pay_all_of_them() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_2, 200);
+
+    send_claim_selected_rewards_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&REWARD_TOKEN_ID_1, &REWARD_TOKEN_ID_2],
+    );
+
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(REWARD_TOKEN_ID_1, 100)
+        .esdt_balance(REWARD_TOKEN_ID_2, 200);
+}
+
+#[test]
+fn selective_claim_of_unknown_token_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_rewards(MultiValueEncoded::from_iter([
+            EgldOrEsdtTokenIdentifier::esdt(UNREGISTERED_REWARD_TOKEN_ID.to_token_identifier()),
+        ]))
+        .returns(ExpectError(4u64, ERR_INVALID_REWARD_TOKEN))
+        .run();
+}
+
+#[test]
+fn selective_claim_of_token_without_rewards_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_rewards(MultiValueEncoded::from_iter([
+            EgldOrEsdtTokenIdentifier::esdt(REWARD_TOKEN_ID_2.to_token_identifier()),
+        ]))
+        .returns(ExpectError(4u64, ERR_NO_REWARDS_TO_CLAIM))
+        .run();
+}
@@ -0,0 +1,206 @@
+use multiversx_sc::{
+    codec::multi_types::MultiValueVec,
+    types::{EgldOrEsdtTokenIdentifier, MultiValueManagedVec},
+};
+use multiversx_sc_scenario::{ExpectError, ExpectStatus, ExpectValue, ScenarioTxRun};
+use nft_staking::{constants::ERR_MISSING_ROLE, proxy::Role};
+
+use crate::{
+    blackbox::{
+        helpers::{send_grant_role_tx, send_revoke_role_tx},
+        test_setup::setup_world_with_contract,
+    },
+    config::{
+        MANAGER_ADDRESS, NFT_TOKEN_ID, OWNER_ADDRESS, SC_ADDRESS, UNREGISTERED_REWARD_TOKEN_ID,
+        UNSUPPORTED_NFT_TOKEN_ID, USER_ADDRESS,
+    },
+};
+
+#[test]
+fn address_without_role_cannot_call_gated_endpoints() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(MANAGER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .disable_staking()
+        .returns(ExpectError(4u64, ERR_MISSING_ROLE))
+        .run();
+
+    world
+        .tx()
+        .from(MANAGER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .set_collection_score(NFT_TOKEN_ID.to_token_identifier(), 5u64)
+        .returns(ExpectError(4u64, ERR_MISSING_ROLE))
+        .run();
+}
+
+#[test]
+fn pauser_can_disable_and_enable_staking() {
+    let mut world = setup_world_with_contract();
+
+    send_grant_role_tx(&mut world, Role::Pauser, &MANAGER_ADDRESS);
+
+    world
+        .tx()
+        .from(MANAGER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .disable_staking()
+        .returns(ExpectStatus(0u64))
+        .run();
+    world
+        .tx()
+        .from(MANAGER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .enable_staking()
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+#[test]
+fn score_manager_can_set_scores_but_not_pause() {
+    let mut world = setup_world_with_contract();
+
+    send_grant_role_tx(&mut world, Role::ScoreManager, &MANAGER_ADDRESS);
+
+    world
+        .tx()
+        .from(MANAGER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .set_collection_nonce_score(NFT_TOKEN_ID.to_token_identifier(), 1u64, 5u64)
+        .returns(ExpectStatus(0u64))
+        .run();
+    world
+        .tx()
+        .from(MANAGER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .disable_staking()
+        .returns(ExpectError(4u64, ERR_MISSING_ROLE))
+        .run();
+}
+
+#[test]
+fn collection_manager_can_allow_collections() {
+    let mut world = setup_world_with_contract();
+
+    send_grant_role_tx(&mut world, Role::CollectionManager, &MANAGER_ADDRESS);
+
+    world
+        .tx()
+        .from(MANAGER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .allow_collections(MultiValueManagedVec::from_single_item(
+            UNSUPPORTED_NFT_TOKEN_ID.to_token_identifier(),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+#[test]
+fn reward_manager_can_register_reward_tokens() {
+    let mut world = setup_world_with_contract();
+
+    send_grant_role_tx(&mut world, Role::RewardManager, &MANAGER_ADDRESS);
+
+    world
+        .tx()
+        .from(MANAGER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .add_reward_token(EgldOrEsdtTokenIdentifier::esdt(
+            UNREGISTERED_REWARD_TOKEN_ID.to_token_identifier(),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+#[test]
+fn reward_manager_cannot_add_reward_depositors() {
+    let mut world = setup_world_with_contract();
+
+    send_grant_role_tx(&mut world, Role::RewardManager, &MANAGER_ADDRESS);
+
+    world
+        .tx()
+        .from(MANAGER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .add_reward_depositor(MANAGER_ADDRESS.to_address())
+        .returns(ExpectError(4u64, "Endpoint can only be called by owner"))
+        .run();
+}
+
+#[test]
+fn revoked_role_can_no_longer_be_used() {
+    let mut world = setup_world_with_contract();
+
+    send_grant_role_tx(&mut world, Role::Pauser, &MANAGER_ADDRESS);
+    send_revoke_role_tx(&mut world, Role::Pauser, &MANAGER_ADDRESS);
+
+    world
+        .tx()
+        .from(MANAGER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .disable_staking()
+        .returns(ExpectError(4u64, ERR_MISSING_ROLE))
+        .run();
+}
+
+#[test]
+fn only_owner_can_grant_roles() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .grant_role(Role::Pauser, USER_ADDRESS.to_address())
+        .returns(ExpectError(4u64, "Endpoint can only be called by owner"))
+        .run();
+}
+
+#[test]
+fn role_holders_are_listed_per_role() {
+    let mut world = setup_world_with_contract();
+
+    send_grant_role_tx(&mut world, Role::Pauser, &MANAGER_ADDRESS);
+    send_grant_role_tx(&mut world, Role::Pauser, &USER_ADDRESS);
+
+    let expected_holders = MultiValueVec::from(vec![
+        MANAGER_ADDRESS.to_address(),
+        USER_ADDRESS.to_address(),
+    ]);
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .role_holders(Role::Pauser)
+        .returns(ExpectValue(expected_holders))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .has_role(Role::ScoreManager, OWNER_ADDRESS.to_address())
+        .returns(ExpectValue(true))
+        .run();
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .has_role(Role::ScoreManager, MANAGER_ADDRESS.to_address())
+        .returns(ExpectValue(false))
+        .run();
+}
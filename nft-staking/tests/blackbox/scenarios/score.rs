@@ -87,3 +87,19 @@ fn unstaking_different_score_assets_should_be_accounted_for_correctly() {
 
     check_aggregated_staking_score(&mut world, 0);
 }
+
+#[test]
+fn unstaking_after_score_change_should_remove_credited_score() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(NFT_TOKEN_ID, 2, 1)],
+    );
+    send_set_collection_nonce_score_tx(&mut world, &NFT_TOKEN_ID, 1, DEFAULT_NFT_SCORE * 3);
+
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+
+    check_aggregated_staking_score(&mut world, DEFAULT_NFT_SCORE);
+}
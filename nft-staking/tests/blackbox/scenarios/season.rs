@@ -0,0 +1,330 @@
+use multiversx_sc::types::{EsdtTokenPayment, ReturnsResult};
+use multiversx_sc_scenario::{
+    imports::SetStateStep, managed_biguint, ExpectError, ExpectStatus, ScenarioTxRun, ScenarioWorld,
+};
+use nft_staking::{
+    constants::{
+        ERR_INVALID_SEASON_END, ERR_MISSING_ROLE, ERR_NO_SEASON_REWARDS_TO_CLAIM,
+        ERR_OPERATION_PAUSED, ERR_SEASON_CLAIM_PERIOD_NOT_OVER, ERR_SEASON_CLAIM_PERIOD_OVER,
+        ERR_SEASON_NOT_ENDED, ERR_SEASON_REWARDS_ALREADY_CLAIMED, MIN_SEASON_DURATION,
+        SEASON_CLAIM_PERIOD,
+    },
+    proxy::Operation,
+};
+
+use crate::{
+    blackbox::{
+        helpers::{send_pause_operations_tx, send_stake_tx, send_unstake_tx},
+        test_setup::setup_world_with_contract,
+    },
+    config::{OWNER_ADDRESS, REWARD_TOKEN_ID_1, SC_ADDRESS, SFT_TOKEN_ID, USER_ADDRESS},
+};
+
+const SEASON_DURATION: u64 = MIN_SEASON_DURATION;
+const SEASON_REWARDS: u64 = 1_500;
+
+fn set_block_timestamp(world: &mut ScenarioWorld, timestamp: u64) {
+    world.set_state_step(SetStateStep::new().block_timestamp(timestamp));
+}
+
+fn send_start_season_tx(world: &mut ScenarioWorld, end_timestamp: u64) -> u64 {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .start_season(end_timestamp)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn send_add_season_rewards_tx(world: &mut ScenarioWorld, season_id: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .add_season_rewards(season_id)
+        .esdt(EsdtTokenPayment::new(
+            REWARD_TOKEN_ID_1.to_token_identifier(),
+            0,
+            managed_biguint!(SEASON_REWARDS),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn send_claim_season_rewards_tx(world: &mut ScenarioWorld, season_id: u64) {
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_season_rewards(season_id)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+#[test]
+fn season_rewards_should_be_split_by_score_time() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 1)]);
+    let season_id = send_start_season_tx(&mut world, SEASON_DURATION);
+    send_add_season_rewards_tx(&mut world, season_id);
+
+    // the owner only stakes the same score for the second half of the season
+    set_block_timestamp(&mut world, SEASON_DURATION / 2);
+    send_stake_tx(&mut world, &OWNER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 1)]);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_season_rewards(season_id)
+        .returns(ExpectError(4u64, ERR_SEASON_NOT_ENDED))
+        .run();
+
+    // staking after the season has ended does not count
+    set_block_timestamp(&mut world, SEASON_DURATION);
+    send_stake_tx(&mut world, &OWNER_ADDRESS, &[&(SFT_TOKEN_ID, 2, 1)]);
+
+    send_claim_season_rewards_tx(&mut world, season_id);
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(REWARD_TOKEN_ID_1, SEASON_REWARDS * 2 / 3);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_season_rewards(season_id)
+        .returns(ExpectError(4u64, ERR_SEASON_REWARDS_ALREADY_CLAIMED))
+        .run();
+}
+
+#[test]
+fn score_time_should_reset_every_season() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 1)]);
+    let first_season_id = send_start_season_tx(&mut world, SEASON_DURATION);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .start_season(SEASON_DURATION * 2)
+        .returns(ExpectError(4u64, ERR_SEASON_NOT_ENDED))
+        .run();
+
+    // the user unstakes halfway through the second season
+    set_block_timestamp(&mut world, SEASON_DURATION);
+    let second_season_id = send_start_season_tx(&mut world, SEASON_DURATION * 2);
+    send_add_season_rewards_tx(&mut world, second_season_id);
+    set_block_timestamp(&mut world, SEASON_DURATION * 3 / 2);
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 1)]);
+    set_block_timestamp(&mut world, SEASON_DURATION * 2);
+
+    let score_time = |world: &mut ScenarioWorld, season_id: u64| {
+        world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(nft_staking::proxy::NftStakingProxy)
+            .get_user_season_score_time(USER_ADDRESS, season_id)
+            .returns(ReturnsResult)
+            .run()
+    };
+    let first_score_time = score_time(&mut world, first_season_id);
+    let second_score_time = score_time(&mut world, second_season_id);
+    assert_eq!(first_score_time, &second_score_time * 2u64);
+
+    let history = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_user_season_history(USER_ADDRESS)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].season_id, first_season_id);
+    assert_eq!(history[1].score_time, second_score_time);
+
+    let current_season = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_current_season()
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert_eq!(current_season.season_id, second_season_id);
+    assert!(current_season.ended);
+    assert_eq!(current_season.total_score_time, second_score_time);
+
+    // the user was the only staker of the second season
+    send_claim_season_rewards_tx(&mut world, second_season_id);
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(REWARD_TOKEN_ID_1, SEASON_REWARDS);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_season_rewards(first_season_id)
+        .returns(ExpectError(4u64, ERR_NO_SEASON_REWARDS_TO_CLAIM))
+        .run();
+}
+
+#[test]
+fn score_change_should_not_settle_seasons_past_their_claim_period() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 1)]);
+    let first_season_id = send_start_season_tx(&mut world, SEASON_DURATION);
+
+    set_block_timestamp(&mut world, SEASON_DURATION + SEASON_CLAIM_PERIOD);
+    let second_season_id =
+        send_start_season_tx(&mut world, SEASON_DURATION * 2 + SEASON_CLAIM_PERIOD);
+    set_block_timestamp(&mut world, SEASON_DURATION * 3 / 2 + SEASON_CLAIM_PERIOD);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(SFT_TOKEN_ID, 2, 1)]);
+
+    let history = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_user_season_history(USER_ADDRESS)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].season_id, second_season_id);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_season_rewards(first_season_id)
+        .returns(ExpectError(4u64, ERR_SEASON_CLAIM_PERIOD_OVER))
+        .run();
+}
+
+#[test]
+fn season_shorter_than_min_duration_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .start_season(MIN_SEASON_DURATION - 1)
+        .returns(ExpectError(4u64, ERR_INVALID_SEASON_END))
+        .run();
+}
+
+#[test]
+fn season_rewards_cannot_be_claimed_while_claims_are_paused() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 1)]);
+    let season_id = send_start_season_tx(&mut world, SEASON_DURATION);
+    send_add_season_rewards_tx(&mut world, season_id);
+    set_block_timestamp(&mut world, SEASON_DURATION);
+    send_pause_operations_tx(&mut world, &[Operation::ClaimRewards]);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_season_rewards(season_id)
+        .returns(ExpectError(4u64, ERR_OPERATION_PAUSED))
+        .run();
+}
+
+#[test]
+fn unclaimed_season_rewards_can_be_swept_after_claim_period() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 1)]);
+    let season_id = send_start_season_tx(&mut world, SEASON_DURATION);
+    send_add_season_rewards_tx(&mut world, season_id);
+    set_block_timestamp(&mut world, SEASON_DURATION);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .sweep_season_rewards(season_id)
+        .returns(ExpectError(4u64, ERR_SEASON_CLAIM_PERIOD_NOT_OVER))
+        .run();
+
+    set_block_timestamp(&mut world, SEASON_DURATION + SEASON_CLAIM_PERIOD);
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .claim_season_rewards(season_id)
+        .returns(ExpectError(4u64, ERR_SEASON_CLAIM_PERIOD_OVER))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .sweep_season_rewards(season_id)
+        .returns(ExpectStatus(0u64))
+        .run();
+    world
+        .check_account(SC_ADDRESS)
+        .esdt_balance(REWARD_TOKEN_ID_1, 0);
+}
+
+#[test]
+fn rewards_of_season_without_stakers_can_be_swept_right_away() {
+    let mut world = setup_world_with_contract();
+
+    let season_id = send_start_season_tx(&mut world, SEASON_DURATION);
+    send_add_season_rewards_tx(&mut world, season_id);
+    set_block_timestamp(&mut world, SEASON_DURATION);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .sweep_season_rewards(season_id)
+        .returns(ExpectStatus(0u64))
+        .run();
+    world
+        .check_account(SC_ADDRESS)
+        .esdt_balance(REWARD_TOKEN_ID_1, 0);
+}
+
+#[test]
+fn only_reward_manager_can_start_season() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .start_season(SEASON_DURATION)
+        .returns(ExpectError(4u64, ERR_MISSING_ROLE))
+        .run();
+}
@@ -1,14 +1,14 @@
-use multiversx_sc::types::EsdtTokenPayment;
-use multiversx_sc_scenario::{managed_biguint, ExpectError, ScenarioTxRun};
+use multiversx_sc::types::{EsdtTokenPayment, ReturnsResult, TestAddress};
+use multiversx_sc_scenario::{managed_biguint, managed_buffer, ExpectError, ScenarioTxRun};
 use nft_staking::constants::{
-    DEFAULT_NFT_SCORE, ERR_NFT_COLLECTION_NOT_ALLOWED, ERR_STAKING_DISABLED,
+    DEFAULT_NFT_SCORE, ERR_NFT_COLLECTION_NOT_ALLOWED, ERR_STAKING_DISABLED, MAX_BPS,
 };
 
 use crate::{
     blackbox::{
         helpers::{
             check_aggregated_staking_score, check_staked_amount, check_user_staking_score,
-            send_disable_staking_tx, send_stake_tx,
+            send_disable_staking_tx, send_set_collection_nonce_score_tx, send_stake_tx,
         },
         test_setup::setup_world_with_contract,
     },
@@ -163,3 +163,110 @@ fn increasing_stake_should_increase_staking_scores() {
     check_aggregated_staking_score(&mut world, DEFAULT_NFT_SCORE * 2);
     check_user_staking_score(&mut world, &USER_ADDRESS, DEFAULT_NFT_SCORE * 2);
 }
+
+#[test]
+fn staked_items_detailed_should_include_metadata_and_score() {
+    let mut world = setup_world_with_contract();
+
+    let collector = TestAddress::new("collector");
+    world.account(collector).nonce(1).esdt_nft_all_properties(
+        NFT_TOKEN_ID,
+        6,
+        1,
+        managed_buffer!(b"attributes"),
+        500u64,
+        None::<TestAddress>,
+        managed_buffer!(b""),
+        vec![managed_buffer!(b"https://uri.test/6.png")],
+    );
+    send_set_collection_nonce_score_tx(&mut world, &NFT_TOKEN_ID, 6, DEFAULT_NFT_SCORE * 2);
+    send_stake_tx(&mut world, &collector, &[&(NFT_TOKEN_ID, 6, 1)]);
+
+    let staked_items = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_staked_items_detailed(collector.to_address(), 0usize, 10usize)
+        .returns(ReturnsResult)
+        .run();
+
+    assert_eq!(staked_items.len(), 1);
+    let item = staked_items.get(0);
+    assert_eq!(item.token_identifier, NFT_TOKEN_ID.to_token_identifier());
+    assert_eq!(item.nonce, 6);
+    assert_eq!(item.attributes, managed_buffer!(b"attributes"));
+    assert_eq!(item.royalties, managed_biguint!(500));
+    assert_eq!(item.uris.len(), 1);
+    assert_eq!(item.score, managed_biguint!(DEFAULT_NFT_SCORE * 2));
+    assert_eq!(item.effective_score_bps, MAX_BPS);
+    assert_eq!(item.total_score, managed_biguint!(DEFAULT_NFT_SCORE * 2));
+    assert!(!item.frozen);
+}
+
+#[test]
+fn staked_items_detailed_should_report_credited_and_effective_score() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .set_diminishing_returns(DEFAULT_NFT_SCORE * 2, MAX_BPS / 2)
+        .run();
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[
+            &(NFT_TOKEN_ID, 1, 1),
+            &(NFT_TOKEN_ID, 2, 1),
+            &(NFT_TOKEN_ID, 3, 1),
+            &(NFT_TOKEN_ID, 4, 1),
+        ],
+    );
+    send_set_collection_nonce_score_tx(&mut world, &NFT_TOKEN_ID, 1, DEFAULT_NFT_SCORE * 2);
+
+    let staked_items = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_staked_items_detailed(USER_ADDRESS.to_address(), 0usize, 1usize)
+        .returns(ReturnsResult)
+        .run();
+
+    let item = staked_items.get(0);
+    assert_eq!(item.nonce, 1);
+    assert_eq!(item.score, managed_biguint!(DEFAULT_NFT_SCORE));
+    assert_eq!(item.effective_score_bps, MAX_BPS * 3 / 4);
+    assert_eq!(
+        item.total_score,
+        managed_biguint!(DEFAULT_NFT_SCORE * 3 / 4)
+    );
+}
+
+#[test]
+fn staked_items_detailed_should_be_paginated() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[
+            &(NFT_TOKEN_ID, 1, 1),
+            &(NFT_TOKEN_ID, 2, 1),
+            &(NFT_TOKEN_ID, 3, 1),
+        ],
+    );
+
+    let staked_items = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_staked_items_detailed(USER_ADDRESS.to_address(), 1usize, 5usize)
+        .returns(ReturnsResult)
+        .run();
+
+    assert_eq!(staked_items.len(), 2);
+    assert_eq!(staked_items.get(0).nonce, 2);
+    assert_eq!(staked_items.get(1).nonce, 3);
+}
@@ -0,0 +1,214 @@
+use multiversx_sc::types::{EsdtTokenPayment, MultiValueManagedVec, TestTokenIdentifier};
+use multiversx_sc_scenario::{
+    api::StaticApi, managed_biguint, rust_biguint, ExpectError, ExpectStatus, ScenarioTxRun,
+    ScenarioWorld,
+};
+use nft_staking::{
+    constants::{
+        DEFAULT_NFT_SCORE, ERR_INVALID_TRANSFER_RECEIVER, ERR_OPERATION_PAUSED,
+        ERR_POSITION_TRANSFERS_DISABLED, ERR_USER_HAS_NOT_ENOUGH_STAKED_BALANCE,
+    },
+    proxy::Operation,
+};
+
+use crate::{
+    blackbox::{
+        helpers::{
+            check_aggregated_staking_score, check_pending_reward, check_staked_amount,
+            check_user_staking_score, send_distribute_rewards_tx, send_pause_operations_tx,
+            send_stake_tx, send_unpause_operations_tx, send_unstake_tx,
+        },
+        test_setup::setup_world_with_contract,
+    },
+    config::{
+        MANAGER_ADDRESS, NFT_TOKEN_ID, OWNER_ADDRESS, REWARD_TOKEN_ID_1, SC_ADDRESS, SFT_TOKEN_ID,
+        USER_ADDRESS,
+    },
+};
+
+fn send_enable_position_transfer_tx(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .set_position_transfer_enabled(true)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn transfer_request(
+    token_id: &TestTokenIdentifier,
+    nonce: u64,
+    amount: u64,
+) -> MultiValueManagedVec<StaticApi, EsdtTokenPayment<StaticApi>> {
+    MultiValueManagedVec::from_single_item(EsdtTokenPayment::new(
+        token_id.to_token_identifier(),
+        nonce,
+        managed_biguint!(amount),
+    ))
+}
+
+#[test]
+fn position_transfer_should_be_disabled_by_default() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .transfer_staked_position(
+            MANAGER_ADDRESS.to_address(),
+            transfer_request(&NFT_TOKEN_ID, 1, 1),
+        )
+        .returns(ExpectError(4u64, ERR_POSITION_TRANSFERS_DISABLED))
+        .run();
+}
+
+#[test]
+fn paused_position_transfer_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    send_enable_position_transfer_tx(&mut world);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_pause_operations_tx(&mut world, &[Operation::Transfer]);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .transfer_staked_position(
+            MANAGER_ADDRESS.to_address(),
+            transfer_request(&NFT_TOKEN_ID, 1, 1),
+        )
+        .returns(ExpectError(4u64, ERR_OPERATION_PAUSED))
+        .run();
+
+    send_unpause_operations_tx(&mut world, &[Operation::Transfer]);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .transfer_staked_position(
+            MANAGER_ADDRESS.to_address(),
+            transfer_request(&NFT_TOKEN_ID, 1, 1),
+        )
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    check_staked_amount(&mut world, &MANAGER_ADDRESS, &NFT_TOKEN_ID, 1, 1);
+}
+
+#[test]
+fn position_transfer_should_move_items_and_score() {
+    let mut world = setup_world_with_contract();
+
+    send_enable_position_transfer_tx(&mut world);
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(SFT_TOKEN_ID, 1, 3)],
+    );
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .transfer_staked_position(
+            MANAGER_ADDRESS.to_address(),
+            transfer_request(&SFT_TOKEN_ID, 1, 2),
+        )
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    check_staked_amount(&mut world, &USER_ADDRESS, &SFT_TOKEN_ID, 1, 1);
+    check_staked_amount(&mut world, &MANAGER_ADDRESS, &SFT_TOKEN_ID, 1, 2);
+    check_user_staking_score(&mut world, &USER_ADDRESS, DEFAULT_NFT_SCORE * 2);
+    check_user_staking_score(&mut world, &MANAGER_ADDRESS, DEFAULT_NFT_SCORE * 2);
+    check_aggregated_staking_score(&mut world, DEFAULT_NFT_SCORE * 4);
+
+    send_unstake_tx(&mut world, &MANAGER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 2)]);
+}
+
+#[test]
+fn position_transfer_should_settle_rewards_of_both_parties() {
+    let mut world = setup_world_with_contract();
+
+    send_enable_position_transfer_tx(&mut world);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 100);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .transfer_staked_position(
+            MANAGER_ADDRESS.to_address(),
+            transfer_request(&NFT_TOKEN_ID, 1, 1),
+        )
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    send_distribute_rewards_tx(&mut world, REWARD_TOKEN_ID_1, 50);
+
+    check_pending_reward(
+        &mut world,
+        &USER_ADDRESS,
+        &REWARD_TOKEN_ID_1,
+        rust_biguint!(100),
+    );
+    check_pending_reward(
+        &mut world,
+        &MANAGER_ADDRESS,
+        &REWARD_TOKEN_ID_1,
+        rust_biguint!(50),
+    );
+}
+
+#[test]
+fn position_transfer_of_more_than_staked_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    send_enable_position_transfer_tx(&mut world);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(SFT_TOKEN_ID, 1, 1)]);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .transfer_staked_position(
+            MANAGER_ADDRESS.to_address(),
+            transfer_request(&SFT_TOKEN_ID, 1, 2),
+        )
+        .returns(ExpectError(4u64, ERR_USER_HAS_NOT_ENOUGH_STAKED_BALANCE))
+        .run();
+}
+
+#[test]
+fn position_transfer_to_self_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    send_enable_position_transfer_tx(&mut world);
+    send_stake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .transfer_staked_position(
+            USER_ADDRESS.to_address(),
+            transfer_request(&NFT_TOKEN_ID, 1, 1),
+        )
+        .returns(ExpectError(4u64, ERR_INVALID_TRANSFER_RECEIVER))
+        .run();
+}
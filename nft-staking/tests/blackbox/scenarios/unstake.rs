@@ -1,17 +1,20 @@
-use multiversx_sc::types::{EsdtTokenPayment, MultiValueManagedVec};
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    types::{EsdtTokenPayment, MultiValueManagedVec, ReturnsResult},
+};
 use multiversx_sc_scenario::{
-    imports::SetStateStep, managed_biguint, ExpectError, ExpectStatus, ScenarioTxRun,
+    imports::SetStateStep, managed_biguint, ExpectError, ExpectStatus, ExpectValue, ScenarioTxRun,
 };
 use nft_staking::constants::{
-    DEFAULT_NFT_SCORE, ERR_NO_UNSTAKED_ITEMS, ERR_STAKING_DISABLED,
-    ERR_USER_HAS_NOT_ENOUGH_STAKED_BALANCE, UNSTAKE_PENALTY,
+    DEFAULT_NFT_SCORE, ERR_NO_UNSTAKED_ITEMS, ERR_STAKING_DISABLED, ERR_TOO_MANY_UNSTAKING_BATCHES,
+    ERR_USER_HAS_NOT_ENOUGH_STAKED_BALANCE, MAX_UNSTAKING_BATCHES, UNSTAKE_PENALTY,
 };
 
 use crate::{
     blackbox::{
         helpers::{
-            check_aggregated_staking_score, check_user_staking_score, send_disable_staking_tx,
-            send_disallow_collection_tx, send_stake_tx, send_unstake_tx,
+            check_aggregated_staking_score, check_user_staking_score, send_claim_unstaked_tx,
+            send_disable_staking_tx, send_disallow_collection_tx, send_stake_tx, send_unstake_tx,
         },
         test_setup::setup_world_with_contract,
     },
@@ -163,7 +166,7 @@ fn should_not_be_able_to_claim_before_penalty_is_over() {
         .from(USER_ADDRESS.to_address())
         .to(SC_ADDRESS)
         .typed(nft_staking::proxy::NftStakingProxy)
-        .claim_unstaked()
+        .claim_unstaked(OptionalValue::<usize>::None)
         .returns(ExpectError(4u64, ERR_NO_UNSTAKED_ITEMS))
         .run();
 
@@ -175,7 +178,7 @@ fn should_not_be_able_to_claim_before_penalty_is_over() {
         .from(USER_ADDRESS.to_address())
         .to(SC_ADDRESS)
         .typed(nft_staking::proxy::NftStakingProxy)
-        .claim_unstaked()
+        .claim_unstaked(OptionalValue::<usize>::None)
         .returns(ExpectError(4u64, ERR_NO_UNSTAKED_ITEMS))
         .run();
 }
@@ -194,7 +197,7 @@ fn should_be_able_to_claim_after_penalty_is_over() {
         .from(USER_ADDRESS.to_address())
         .to(SC_ADDRESS)
         .typed(nft_staking::proxy::NftStakingProxy)
-        .claim_unstaked()
+        .claim_unstaked(OptionalValue::<usize>::None)
         .returns(ExpectStatus(0u64))
         .run();
 
@@ -301,3 +304,182 @@ fn double_sft_unstake_fails_if_not_enough_staked_balance() {
         .returns(ExpectError(4u64, ERR_USER_HAS_NOT_ENOUGH_STAKED_BALANCE))
         .run();
 }
+
+#[test]
+fn unstakes_at_the_same_timestamp_should_be_merged_into_one_batch() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(NFT_TOKEN_ID, 2, 1)],
+    );
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 2, 1)]);
+
+    let unstaking_batches = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_unstaking_items(USER_ADDRESS.to_address())
+        .returns(ReturnsResult)
+        .run();
+
+    assert_eq!(unstaking_batches.len(), 1);
+    assert_eq!(unstaking_batches.get(0).unstake_items.len(), 2);
+}
+
+#[test]
+fn unstaking_items_raw_view_lists_queued_batches() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(NFT_TOKEN_ID, 2, 1)],
+    );
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    world.set_state_step(SetStateStep::new().block_timestamp(100));
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 2, 1)]);
+
+    let unstaking_items = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_unstaking_items_raw(USER_ADDRESS.to_address())
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    assert_eq!(unstaking_items.len(), 2);
+    assert_eq!(unstaking_items[0].0, 0);
+    assert_eq!(unstaking_items[1].0, 100);
+    assert_eq!(unstaking_items[1].1.get(0).token_nonce, 2);
+}
+
+#[test]
+fn claim_unstaked_should_stop_at_first_locked_batch() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(NFT_TOKEN_ID, 2, 1)],
+    );
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    world.set_state_step(SetStateStep::new().block_timestamp(100));
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 2, 1)]);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(UNSTAKE_PENALTY));
+    send_claim_unstaked_tx(&mut world, &USER_ADDRESS, None);
+
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 1, 1, "")
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 2, 0, "");
+}
+
+#[test]
+fn claim_unstaked_should_claim_at_most_max_batches() {
+    let mut world = setup_world_with_contract();
+
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(NFT_TOKEN_ID, 2, 1)],
+    );
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    world.set_state_step(SetStateStep::new().block_timestamp(100));
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 2, 1)]);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(UNSTAKE_PENALTY + 100));
+    send_claim_unstaked_tx(&mut world, &USER_ADDRESS, Some(1));
+
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 1, 1, "")
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 2, 0, "");
+
+    send_claim_unstaked_tx(&mut world, &USER_ADDRESS, Some(1));
+
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 2, 1, "");
+}
+
+#[test]
+fn next_unlock_time_should_follow_oldest_batch() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_next_unlock_time(USER_ADDRESS.to_address())
+        .returns(ExpectValue(OptionalValue::<u64>::None))
+        .run();
+
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(NFT_TOKEN_ID, 2, 1)],
+    );
+    world.set_state_step(SetStateStep::new().block_timestamp(10));
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 1, 1)]);
+    world.set_state_step(SetStateStep::new().block_timestamp(100));
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 2, 1)]);
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_next_unlock_time(USER_ADDRESS.to_address())
+        .returns(ExpectValue(OptionalValue::Some(10 + UNSTAKE_PENALTY)))
+        .run();
+
+    world.set_state_step(SetStateStep::new().block_timestamp(10 + UNSTAKE_PENALTY));
+    send_claim_unstaked_tx(&mut world, &USER_ADDRESS, None);
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .get_next_unlock_time(USER_ADDRESS.to_address())
+        .returns(ExpectValue(OptionalValue::Some(100 + UNSTAKE_PENALTY)))
+        .run();
+}
+
+#[test]
+fn unstaking_beyond_max_batches_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    let sft_stake = NFTSFT_NONCES.map(|nonce| (SFT_TOKEN_ID, nonce, INITIAL_SFT_BALANCE));
+    send_stake_tx(&mut world, &USER_ADDRESS, &sft_stake.each_ref());
+    send_stake_tx(
+        &mut world,
+        &USER_ADDRESS,
+        &[&(NFT_TOKEN_ID, 1, 1), &(NFT_TOKEN_ID, 2, 1)],
+    );
+
+    for i in 0..MAX_UNSTAKING_BATCHES {
+        let nonce = NFTSFT_NONCES[i % NFTSFT_NONCES.len()];
+        world.set_state_step(SetStateStep::new().block_timestamp(i as u64));
+        send_unstake_tx(&mut world, &USER_ADDRESS, &[&(SFT_TOKEN_ID, nonce, 1)]);
+    }
+
+    // merging into the last batch is still allowed
+    send_unstake_tx(&mut world, &USER_ADDRESS, &[&(NFT_TOKEN_ID, 2, 1)]);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(MAX_UNSTAKING_BATCHES as u64));
+    world
+        .tx()
+        .from(USER_ADDRESS.to_address())
+        .to(SC_ADDRESS)
+        .typed(nft_staking::proxy::NftStakingProxy)
+        .unstake(MultiValueManagedVec::from_single_item(
+            EsdtTokenPayment::new(NFT_TOKEN_ID.to_token_identifier(), 1, managed_biguint!(1)),
+        ))
+        .returns(ExpectError(4u64, ERR_TOO_MANY_UNSTAKING_BATCHES))
+        .run();
+}
@@ -1,7 +1,9 @@
 use crate::config::*;
 use multiversx_sc_scenario::imports::*;
 
-use super::helpers::send_allow_collection_tx;
+use super::helpers::{
+    send_add_egld_reward_token_tx, send_add_reward_token_tx, send_allow_collection_tx,
+};
 
 fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
@@ -14,6 +16,9 @@ pub fn setup_world_with_contract() -> ScenarioWorld {
     deploy_contract(&mut world);
     send_allow_collection_tx(&mut world, &NFT_TOKEN_ID);
     send_allow_collection_tx(&mut world, &SFT_TOKEN_ID);
+    send_add_reward_token_tx(&mut world, &REWARD_TOKEN_ID_1);
+    send_add_reward_token_tx(&mut world, &REWARD_TOKEN_ID_2);
+    send_add_egld_reward_token_tx(&mut world);
 
     world
 }
@@ -52,12 +57,27 @@ fn add_accounts(world: &mut ScenarioWorld) {
         .balance(INITIAL_ESDT_BALANCE)
         .esdt_balance(REWARD_TOKEN_ID_1, INITIAL_ESDT_BALANCE)
         .esdt_balance(REWARD_TOKEN_ID_2, INITIAL_ESDT_BALANCE)
+        .esdt_balance(UNREGISTERED_REWARD_TOKEN_ID, INITIAL_ESDT_BALANCE)
         .esdt_nft_balance(SFT_TOKEN_ID, NFTSFT_NONCES[0], INITIAL_SFT_BALANCE, managed_buffer!(b""))
         .esdt_nft_balance(SFT_TOKEN_ID, NFTSFT_NONCES[1], INITIAL_SFT_BALANCE, managed_buffer!(b""))
         .esdt_nft_balance(SFT_TOKEN_ID, NFTSFT_NONCES[2], INITIAL_SFT_BALANCE, managed_buffer!(b""))
         .esdt_nft_balance(SFT_TOKEN_ID, NFTSFT_NONCES[3], INITIAL_SFT_BALANCE, managed_buffer!(b""))
         .esdt_nft_balance(SFT_TOKEN_ID, NFTSFT_NONCES[4], INITIAL_SFT_BALANCE, managed_buffer!(b""));
 
+    // Reward depositor account
+    world
+        .account(DEPOSITOR_ADDRESS)
+        .nonce(1)
+        .balance(INITIAL_ESDT_BALANCE)
+        .esdt_balance(REWARD_TOKEN_ID_1, INITIAL_ESDT_BALANCE)
+        .esdt_balance(REWARD_TOKEN_ID_2, INITIAL_ESDT_BALANCE);
+
+    // Manager account
+    world
+        .account(MANAGER_ADDRESS)
+        .nonce(1)
+        .balance(INITIAL_ESDT_BALANCE);
+
     // User account
     world
         .account(USER_ADDRESS)
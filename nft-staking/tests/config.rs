@@ -3,11 +3,15 @@ use multiversx_sc_scenario::imports::*;
 pub const SC_ADDRESS: TestSCAddress = TestSCAddress::new("sc");
 pub const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
 pub const USER_ADDRESS: TestAddress = TestAddress::new("user");
+pub const DEPOSITOR_ADDRESS: TestAddress = TestAddress::new("depositor");
+pub const MANAGER_ADDRESS: TestAddress = TestAddress::new("manager");
 
 pub const NFT_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("NFT-123456");
 pub const SFT_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("NFT-789012");
 pub const REWARD_TOKEN_ID_1: TestTokenIdentifier = TestTokenIdentifier::new("REWARD1-123456");
 pub const REWARD_TOKEN_ID_2: TestTokenIdentifier = TestTokenIdentifier::new("REWARD2-123456");
+pub const UNREGISTERED_REWARD_TOKEN_ID: TestTokenIdentifier =
+    TestTokenIdentifier::new("REWARD3-123456");
 pub const UNSUPPORTED_NFT_TOKEN_ID: TestTokenIdentifier =
     TestTokenIdentifier::new("UNSUPPORTED-NFT");
 
@@ -1,14 +1,44 @@
 #[allow(unused_imports)]
 use multiversx_sc::imports::*;
 
+use crate::errors::{
+    ERR_INVALID_BPS, ERR_INVALID_EXECUTION_GRACE_PERIOD, ERR_INVALID_MAX_OPEN_PROPOSALS,
+    ERR_TOO_MANY_WHITELISTED_LP_TOKENS,
+};
+use crate::voting::MAX_BPS;
+
+pub const MAX_WHITELISTED_LP_TOKENS: usize = 5; // bounds the gas used by voting power computations
+
 #[multiversx_sc::module]
-pub trait AdminModule: crate::storage::StorageModule {
+pub trait AdminModule: crate::storage::StorageModule + crate::stake::StakeModule {
+    /// At most `MAX_WHITELISTED_LP_TOKENS` LP tokens can be whitelisted, as voting power sums the stake of each of them.
     #[only_owner]
     #[endpoint(addWhitelistedLpTokens)]
     fn add_whitelisted_lp_tokens(&self, lp_token_identifiers: MultiValueEncoded<TokenIdentifier>) {
         for lp_token_id in lp_token_identifiers {
             self.whitelisted_lp_token_identifiers().insert(lp_token_id);
         }
+        require!(
+            self.whitelisted_lp_token_identifiers().len() <= MAX_WHITELISTED_LP_TOKENS,
+            ERR_TOO_MANY_WHITELISTED_LP_TOKENS
+        );
+    }
+
+    /// Count the stake of users who staked before the totals were recorded.
+    /// Users already counted are skipped.
+    #[only_owner]
+    #[endpoint(countUsersStakeInTotal)]
+    fn count_users_stake_in_total(&self, users: MultiValueEncoded<ManagedAddress>) {
+        for user in users {
+            self.count_user_stake_in_total(&user);
+        }
+    }
+
+    /// Enable the quorum and approval thresholds once every staker is counted in the totals
+    #[only_owner]
+    #[endpoint(setTotalStakedReconciled)]
+    fn set_total_staked_reconciled(&self) {
+        self.total_staked_reconciled().set(true);
     }
 
     #[only_owner]
@@ -16,4 +46,87 @@ pub trait AdminModule: crate::storage::StorageModule {
     fn set_tro_token_identifier(&self, tro_token_identifier: TokenIdentifier) {
         self.tro_token_identifier().set(tro_token_identifier);
     }
+
+    #[only_owner]
+    #[endpoint(setMinStakeToDelegate)]
+    fn set_min_stake_to_delegate(&self, min_stake: BigUint) {
+        self.min_stake_to_delegate().set(min_stake);
+    }
+
+    #[only_owner]
+    #[endpoint(setTimelockDelay)]
+    fn set_timelock_delay(&self, delay: u64) {
+        self.timelock_delay().set(delay);
+    }
+
+    #[only_owner]
+    #[endpoint(setExecutionGracePeriod)]
+    fn set_execution_grace_period(&self, grace_period: u64) {
+        require!(grace_period > 0, ERR_INVALID_EXECUTION_GRACE_PERIOD);
+        self.execution_grace_period().set(grace_period);
+    }
+
+    #[only_owner]
+    #[endpoint(addGuardian)]
+    fn add_guardian(&self, guardian: ManagedAddress) {
+        self.guardians().insert(guardian);
+    }
+
+    #[only_owner]
+    #[endpoint(removeGuardian)]
+    fn remove_guardian(&self, guardian: ManagedAddress) {
+        self.guardians().swap_remove(&guardian);
+    }
+
+    #[only_owner]
+    #[endpoint(setProposalCreationThreshold)]
+    fn set_proposal_creation_threshold(&self, threshold: BigUint) {
+        self.proposal_creation_threshold().set(threshold);
+    }
+
+    #[only_owner]
+    #[endpoint(setProposalDepositAmount)]
+    fn set_proposal_deposit_amount(&self, amount: BigUint) {
+        self.proposal_deposit_amount().set(amount);
+    }
+
+    #[only_owner]
+    #[endpoint(setMinVotingPowerToValidateVoteFloor)]
+    fn set_min_voting_power_to_validate_vote_floor(&self, floor: BigUint) {
+        self.min_voting_power_to_validate_vote_floor().set(floor);
+    }
+
+    #[only_owner]
+    #[endpoint(setMaxOpenProposalsPerCreator)]
+    fn set_max_open_proposals_per_creator(&self, max_open_proposals: usize) {
+        require!(max_open_proposals > 0, ERR_INVALID_MAX_OPEN_PROPOSALS);
+        self.max_open_proposals_per_creator()
+            .set(max_open_proposals);
+    }
+
+    #[only_owner]
+    #[endpoint(setTreasuryAddress)]
+    fn set_treasury_address(&self, treasury_address: ManagedAddress) {
+        self.treasury_address().set(treasury_address);
+    }
+
+    #[only_owner]
+    #[endpoint(setUnbondingPeriod)]
+    fn set_unbonding_period(&self, unbonding_period: u64) {
+        self.unbonding_period().set(unbonding_period);
+    }
+
+    #[only_owner]
+    #[endpoint(setQuorumBps)]
+    fn set_quorum_bps(&self, quorum_bps: u64) {
+        require!(quorum_bps <= MAX_BPS, ERR_INVALID_BPS);
+        self.quorum_bps().set(quorum_bps);
+    }
+
+    #[only_owner]
+    #[endpoint(setApprovalThresholdBps)]
+    fn set_approval_threshold_bps(&self, approval_threshold_bps: u64) {
+        require!(approval_threshold_bps <= MAX_BPS, ERR_INVALID_BPS);
+        self.approval_threshold_bps().set(approval_threshold_bps);
+    }
 }
@@ -0,0 +1,105 @@
+use crate::errors::*;
+use crate::voting::DIVISION_GUARD;
+
+multiversx_sc::imports!();
+
+pub const MAX_DELEGATORS_PER_DELEGATE: usize = 50; // bounds the gas used by vote, along with MAX_WHITELISTED_LP_TOKENS
+
+/// Users can delegate their voting power to another address.
+/// Delegates vote with their own voting power plus the power of their delegators,
+/// unless a delegator votes directly on the proposal. Delegation is not transitive.
+#[multiversx_sc::module]
+pub trait DelegationModule:
+    crate::storage::StorageModule + crate::voting::VotingModule + crate::events::EventsModule
+{
+    /// Delegate the caller's voting power, replacing any previous delegation.
+    /// The caller needs at least `min_stake_to_delegate`, valued at the current LP to $TRO ratios.
+    #[endpoint(delegate)]
+    fn delegate(&self, delegate: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            delegate != caller && !delegate.is_zero(),
+            ERR_INVALID_DELEGATE
+        );
+        require!(
+            self.get_current_stake_value(&caller) >= self.min_stake_to_delegate().get(),
+            ERR_INSUFFICIENT_STAKE_TO_DELEGATE
+        );
+
+        self.remove_delegation(&caller);
+
+        let mut delegators = self.delegators(&delegate);
+        require!(
+            delegators.len() < MAX_DELEGATORS_PER_DELEGATE,
+            ERR_TOO_MANY_DELEGATORS
+        );
+        delegators.insert(caller.clone());
+        self.user_delegate(&caller).set(&delegate);
+
+        self.emit_delegate_event(&caller, &delegate);
+    }
+
+    #[endpoint(revokeDelegation)]
+    fn revoke_delegation(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.user_delegate(&caller).is_empty(), ERR_NO_DELEGATION);
+
+        let delegate = self.user_delegate(&caller).get();
+        self.remove_delegation(&caller);
+
+        self.emit_revoke_delegation_event(&caller, &delegate);
+    }
+
+    /// Let a delegate drop one of their delegators, freeing its slot.
+    #[endpoint(removeDelegator)]
+    fn remove_delegator(&self, delegator: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.delegators(&caller).contains(&delegator),
+            ERR_NOT_A_DELEGATOR
+        );
+
+        self.remove_delegation(&delegator);
+
+        self.emit_revoke_delegation_event(&delegator, &caller);
+    }
+
+    /// Voting power the delegate would add to their own when voting on the proposal now
+    #[view(getDelegatedVotingPower)]
+    fn get_delegated_voting_power(&self, delegate: &ManagedAddress, proposal_id: u64) -> BigUint {
+        let mut delegated_voting_power = BigUint::zero();
+        for delegator in self.delegators(delegate).iter() {
+            if self.user_votes(&delegator, proposal_id).is_empty()
+                && self.delegated_votes(&delegator, proposal_id).is_empty()
+            {
+                delegated_voting_power += self.get_voting_power(&delegator, proposal_id);
+            }
+        }
+
+        delegated_voting_power
+    }
+
+    fn get_current_stake_value(&self, user: &ManagedAddress) -> BigUint {
+        let mut stake_value = self
+            .users_stake(user, &self.tro_token_identifier().get())
+            .get();
+        for lp_token in self.whitelisted_lp_token_identifiers().iter() {
+            let staked_lp_balance = self.users_stake(user, &lp_token).get();
+            stake_value +=
+                staked_lp_balance * self.current_lp_to_tro_ratio(&lp_token).get() / DIVISION_GUARD;
+        }
+
+        stake_value
+    }
+
+    fn remove_delegation(&self, delegator: &ManagedAddress) {
+        let delegate_mapper = self.user_delegate(delegator);
+        if delegate_mapper.is_empty() {
+            return;
+        }
+
+        self.delegators(&delegate_mapper.get())
+            .swap_remove(delegator);
+        delegate_mapper.clear();
+    }
+}
@@ -7,3 +7,38 @@ pub const ERR_INSUFFICIENT_VOTING_POWER: &str = "Insufficient voting power";
 pub const ERR_PROPOSAL_ACTIVE: &str = "Proposal active";
 pub const ERR_INVALID_TIME_RANGE: &str = "Invalid time range";
 pub const ERR_USER_ALREADY_VOTED: &str = "User already voted";
+pub const ERR_NOT_PROPOSAL_CREATOR: &str = "Only the proposal creator can do this";
+pub const ERR_PROPOSAL_NOT_PENDING: &str = "Proposal not pending";
+pub const ERR_PROPOSAL_NOT_APPROVED: &str = "Proposal not approved";
+pub const ERR_PROPOSAL_ALREADY_EXECUTED: &str = "Proposal already executed";
+pub const ERR_PROPOSAL_HAS_NO_ACTIONS: &str = "Proposal has no actions";
+pub const ERR_TOO_MANY_PROPOSAL_ACTIONS: &str = "Too many proposal actions";
+pub const ERR_INVALID_PROPOSAL_ACTION: &str = "Invalid proposal action";
+pub const ERR_PROPOSAL_NOT_EXECUTABLE: &str = "Proposal not executable";
+pub const ERR_PROPOSAL_CANNOT_BE_VETOED: &str = "Proposal cannot be vetoed";
+pub const ERR_NOT_GUARDIAN: &str = "Only the owner or a guardian can do this";
+pub const ERR_INVALID_EXECUTION_GRACE_PERIOD: &str = "Invalid execution grace period";
+pub const ERR_LP_TO_TRO_RATIOS_OWNER_ONLY: &str = "Only the owner can set LP to TRO ratios";
+pub const ERR_TOO_MANY_OPEN_PROPOSALS: &str = "Too many open proposals";
+pub const ERR_INVALID_PROPOSAL_DEPOSIT: &str = "Invalid proposal deposit";
+pub const ERR_NO_PROPOSAL_DEPOSIT: &str = "No proposal deposit";
+pub const ERR_PROPOSAL_VOTING_NOT_ENDED: &str = "Proposal voting not ended";
+pub const ERR_INVALID_MAX_OPEN_PROPOSALS: &str = "Invalid max open proposals";
+pub const ERR_STAKE_LOCKED: &str = "Stake locked until the voted proposals end";
+pub const ERR_NO_UNSTAKED_TOKENS: &str = "No unstaked tokens to claim";
+pub const ERR_INVALID_DELEGATE: &str = "Invalid delegate";
+pub const ERR_TOO_MANY_DELEGATORS: &str = "Too many delegators";
+pub const ERR_TOO_MANY_WHITELISTED_LP_TOKENS: &str = "Too many whitelisted LP tokens";
+pub const ERR_NO_DELEGATION: &str = "No delegation";
+pub const ERR_INSUFFICIENT_STAKE_TO_DELEGATE: &str = "Not enough stake to delegate";
+pub const ERR_NOT_A_DELEGATOR: &str = "Not a delegator";
+pub const ERR_USER_HAS_NOT_VOTED: &str = "User has not voted";
+pub const ERR_INVALID_VOTING_METHOD: &str = "Invalid voting method for this proposal";
+pub const ERR_INVALID_PROPOSAL_OPTIONS: &str = "Invalid proposal options";
+pub const ERR_INVALID_OPTION_RANKING: &str = "Invalid option ranking";
+pub const ERR_INVALID_BPS: &str = "Invalid basis points";
+pub const ERR_MIN_VOTING_POWER_TO_VALIDATE_VOTE_TOO_LOW: &str =
+    "Minimum voting power to validate vote below the floor";
+pub const ERR_UNEXPECTED_PAYMENT: &str = "No payment expected";
+pub const ERR_RANKED_CHOICE_VOTES_NOT_COUNTED: &str = "Ranked choice votes not counted";
+pub const ERR_RANKED_CHOICE_VOTES_ALREADY_COUNTED: &str = "Ranked choice votes already counted";
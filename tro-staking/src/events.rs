@@ -1,4 +1,4 @@
-use crate::voting::{VoteContext, VoteDecision};
+use crate::voting::{OptionBallot, VoteContext};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -13,6 +13,9 @@ pub struct StakeEvent<M: ManagedTypeApi> {
 #[allow(type_alias_bounds)]
 pub type UnstakeEvent<M: ManagedTypeApi> = StakeEvent<M>;
 
+#[allow(type_alias_bounds)]
+pub type ClaimUnstakedEvent<M: ManagedTypeApi> = StakeEvent<M>;
+
 #[type_abi]
 #[derive(TopEncode)]
 pub struct ProposalCreatedEvent<M: ManagedTypeApi> {
@@ -24,15 +27,6 @@ pub struct ProposalCreatedEvent<M: ManagedTypeApi> {
     end_time: u64,
 }
 
-#[type_abi]
-#[derive(TopEncode)]
-pub struct VoteEvent<M: ManagedTypeApi> {
-    voter: ManagedAddress<M>,
-    proposal_id: u64,
-    decision: VoteDecision,
-    voting_power: BigUint<M>,
-}
-
 #[multiversx_sc::module]
 pub trait EventsModule: crate::storage::StorageModule {
     fn emit_stake_event(&self, caller: &ManagedAddress, payments: &MultiEsdtPayment<Self::Api>) {
@@ -67,6 +61,45 @@ pub trait EventsModule: crate::storage::StorageModule {
         );
     }
 
+    fn emit_claim_unstaked_event(
+        &self,
+        caller: &ManagedAddress,
+        payments: &MultiEsdtPayment<Self::Api>,
+    ) {
+        let claim_unstaked_event = ClaimUnstakedEvent {
+            caller: caller.clone(),
+            payments: payments.clone(),
+        };
+
+        self.claim_unstaked_event(
+            caller,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+            &claim_unstaked_event,
+        );
+    }
+
+    fn emit_delegate_event(&self, delegator: &ManagedAddress, delegate: &ManagedAddress) {
+        self.delegate_event(
+            delegator,
+            delegate,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn emit_revoke_delegation_event(&self, delegator: &ManagedAddress, delegate: &ManagedAddress) {
+        self.revoke_delegation_event(
+            delegator,
+            delegate,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
     fn emit_proposal_created_event(
         &self,
         proposal_id: u64,
@@ -109,6 +142,71 @@ pub trait EventsModule: crate::storage::StorageModule {
         );
     }
 
+    fn emit_option_vote_event(
+        &self,
+        voter: &ManagedAddress,
+        proposal_id: u64,
+        ballot: &OptionBallot<Self::Api>,
+    ) {
+        self.option_vote_event(
+            voter,
+            proposal_id,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+            ballot,
+        );
+    }
+
+    fn emit_proposal_queued_event(&self, proposal_id: u64, executable_at: u64, expires_at: u64) {
+        self.proposal_queued_event(
+            proposal_id,
+            executable_at,
+            expires_at,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn emit_proposal_vetoed_event(&self, proposal_id: u64) {
+        self.proposal_vetoed_event(
+            proposal_id,
+            &self.blockchain().get_caller(),
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn emit_proposal_executed_event(&self, proposal_id: u64) {
+        self.proposal_executed_event(
+            proposal_id,
+            &self.blockchain().get_caller(),
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
+    fn emit_proposal_deposit_settled_event(
+        &self,
+        proposal_id: u64,
+        receiver: &ManagedAddress,
+        amount: &BigUint,
+        slashed: bool,
+    ) {
+        self.proposal_deposit_settled_event(
+            proposal_id,
+            receiver,
+            amount,
+            slashed,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_nonce(),
+            self.blockchain().get_block_timestamp(),
+        );
+    }
+
     #[event("stake")]
     fn stake_event(
         &self,
@@ -129,6 +227,16 @@ pub trait EventsModule: crate::storage::StorageModule {
         unstake_event: &UnstakeEvent<Self::Api>,
     );
 
+    #[event("claimUnstaked")]
+    fn claim_unstaked_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+        claim_unstaked_event: &ClaimUnstakedEvent<Self::Api>,
+    );
+
     #[event("proposalCreated")]
     fn proposal_created_event(
         &self,
@@ -149,4 +257,78 @@ pub trait EventsModule: crate::storage::StorageModule {
         #[indexed] timestamp: u64,
         event: &VoteContext<Self::Api>,
     );
+
+    #[event("optionVote")]
+    fn option_vote_event(
+        &self,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] proposal_id: u64,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+        event: &OptionBallot<Self::Api>,
+    );
+
+    #[event("proposalExecuted")]
+    fn proposal_executed_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] executor: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
+
+    #[event("proposalQueued")]
+    fn proposal_queued_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] executable_at: u64,
+        #[indexed] expires_at: u64,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
+
+    #[event("proposalVetoed")]
+    fn proposal_vetoed_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] vetoer: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
+
+    #[event("proposalDepositSettled")]
+    fn proposal_deposit_settled_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] receiver: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+        #[indexed] slashed: bool,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
+
+    #[event("delegate")]
+    fn delegate_event(
+        &self,
+        #[indexed] delegator: &ManagedAddress,
+        #[indexed] delegate: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
+
+    #[event("revokeDelegation")]
+    fn revoke_delegation_event(
+        &self,
+        #[indexed] delegator: &ManagedAddress,
+        #[indexed] delegate: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] block: u64,
+        #[indexed] timestamp: u64,
+    );
 }
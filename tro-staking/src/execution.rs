@@ -0,0 +1,233 @@
+use crate::errors::*;
+use crate::voting::ProposalStatus;
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub const MAX_PROPOSAL_ACTIONS: usize = 10;
+pub const ACTION_CALLBACK_GAS: u64 = 10_000_000;
+pub const DEFAULT_TIMELOCK_DELAY_IN_SECONDS: u64 = 2 * 24 * 3600;
+pub const DEFAULT_EXECUTION_GRACE_PERIOD_IN_SECONDS: u64 = 7 * 24 * 3600;
+
+/// Sync actions run within the `executeProposal` transaction, so a failing one reverts the whole execution.
+/// Async actions are independent: their outcome is recorded by a callback.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Copy)]
+pub enum ActionCallType {
+    Sync,
+    Async,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ActionStatus {
+    NotExecuted,
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+/// On-chain call performed when an approved proposal is executed.
+/// The payment is sent from the contract balance; a zero amount means no payment.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct ProposalAction<M: ManagedTypeApi> {
+    pub target: ManagedAddress<M>,
+    pub endpoint_name: ManagedBuffer<M>,
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+    pub payment: EgldOrEsdtTokenPayment<M>,
+    pub gas_limit: u64,
+    pub call_type: ActionCallType,
+}
+
+#[multiversx_sc::module]
+pub trait ExecutionModule:
+    crate::storage::StorageModule + crate::voting::VotingModule + crate::events::EventsModule
+{
+    /// Attach actions to a proposal before its voting starts.
+    /// Payments cannot use $TRO or whitelisted LP tokens, so that staked funds are never moved by a proposal.
+    #[endpoint(addProposalActions)]
+    fn add_proposal_actions(
+        &self,
+        proposal_id: u64,
+        actions: MultiValueEncoded<ProposalAction<Self::Api>>,
+    ) {
+        self.require_proposal_exists(proposal_id);
+        let proposal = self.proposals(proposal_id).get();
+        require!(
+            self.blockchain().get_caller() == proposal.creator,
+            ERR_NOT_PROPOSAL_CREATOR
+        );
+        require!(
+            self.get_proposal_status(&proposal, self.blockchain().get_block_timestamp())
+                == ProposalStatus::Pending,
+            ERR_PROPOSAL_NOT_PENDING
+        );
+
+        for action in actions {
+            self.require_action_is_valid(&action);
+            self.proposal_actions(proposal_id).push(&action);
+        }
+
+        require!(
+            self.proposal_actions(proposal_id).len() <= MAX_PROPOSAL_ACTIONS,
+            ERR_TOO_MANY_PROPOSAL_ACTIONS
+        );
+    }
+
+    /// Start the timelock of an approved proposal. Can be called by anyone.
+    /// The proposal becomes executable once the timelock delay has passed,
+    /// and expires if it is not executed within the grace period.
+    #[endpoint(queueProposal)]
+    fn queue_proposal(&self, proposal_id: u64) {
+        self.require_proposal_exists(proposal_id);
+        self.require_option_votes_counted(proposal_id);
+        require!(
+            self.get_proposal_status_now(proposal_id) == ProposalStatus::Approved,
+            ERR_PROPOSAL_NOT_APPROVED
+        );
+        require!(
+            !self.proposal_actions(proposal_id).is_empty(),
+            ERR_PROPOSAL_HAS_NO_ACTIONS
+        );
+
+        let executable_at = self.blockchain().get_block_timestamp() + self.timelock_delay().get();
+        let expires_at = executable_at + self.execution_grace_period().get();
+        self.proposal_executable_at(proposal_id).set(executable_at);
+        self.proposal_expires_at(proposal_id).set(expires_at);
+
+        self.emit_proposal_queued_event(proposal_id, executable_at, expires_at);
+    }
+
+    /// Cancel an approved proposal before it becomes executable. Owner or guardians only.
+    #[endpoint(vetoProposal)]
+    fn veto_proposal(&self, proposal_id: u64) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.blockchain().get_owner_address() || self.guardians().contains(&caller),
+            ERR_NOT_GUARDIAN
+        );
+        self.require_proposal_exists(proposal_id);
+        self.require_option_votes_counted(proposal_id);
+
+        let status = self.get_proposal_status_now(proposal_id);
+        require!(
+            status == ProposalStatus::Approved || status == ProposalStatus::Queued,
+            ERR_PROPOSAL_CANNOT_BE_VETOED
+        );
+
+        self.proposal_vetoed(proposal_id).set(true);
+
+        self.emit_proposal_vetoed_event(proposal_id);
+    }
+
+    /// Run the actions of a queued proposal once its timelock delay has passed. Can be called by anyone.
+    #[endpoint(executeProposal)]
+    fn execute_proposal(&self, proposal_id: u64) {
+        self.require_proposal_exists(proposal_id);
+        self.require_option_votes_counted(proposal_id);
+        require!(
+            !self.proposal_executed(proposal_id).get(),
+            ERR_PROPOSAL_ALREADY_EXECUTED
+        );
+        require!(
+            self.get_proposal_status_now(proposal_id) == ProposalStatus::Executable,
+            ERR_PROPOSAL_NOT_EXECUTABLE
+        );
+
+        self.proposal_executed(proposal_id).set(true);
+
+        for (index, action) in self.proposal_actions(proposal_id).iter().enumerate() {
+            let action_index = index + 1;
+            let call = self
+                .tx()
+                .to(&action.target)
+                .raw_call(action.endpoint_name)
+                .arguments_raw(ManagedArgBuffer::from(action.arguments))
+                .payment(action.payment)
+                .gas(action.gas_limit);
+
+            match action.call_type {
+                ActionCallType::Sync => {
+                    call.sync_call();
+                    self.proposal_action_status(proposal_id, action_index)
+                        .set(ActionStatus::Succeeded);
+                }
+                ActionCallType::Async => {
+                    call.callback(
+                        self.callbacks()
+                            .proposal_action_callback(proposal_id, action_index),
+                    )
+                    .gas_for_callback(ACTION_CALLBACK_GAS)
+                    .register_promise();
+                    self.proposal_action_status(proposal_id, action_index)
+                        .set(ActionStatus::Pending);
+                }
+            }
+        }
+
+        self.emit_proposal_executed_event(proposal_id);
+    }
+
+    #[promises_callback]
+    fn proposal_action_callback(
+        &self,
+        proposal_id: u64,
+        action_index: usize,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>,
+    ) {
+        let status = match result {
+            ManagedAsyncCallResult::Ok(_) => ActionStatus::Succeeded,
+            ManagedAsyncCallResult::Err(_) => ActionStatus::Failed,
+        };
+
+        self.proposal_action_status(proposal_id, action_index)
+            .set(status);
+    }
+
+    #[view(getProposalActionStatuses)]
+    fn get_proposal_action_statuses(&self, proposal_id: u64) -> MultiValueEncoded<ActionStatus> {
+        let mut statuses = MultiValueEncoded::new();
+        for action_index in 1..=self.proposal_actions(proposal_id).len() {
+            statuses.push(self.proposal_action_status(proposal_id, action_index).get());
+        }
+
+        statuses
+    }
+
+    fn get_proposal_status_now(&self, proposal_id: u64) -> ProposalStatus {
+        self.get_proposal_status(
+            &self.proposals(proposal_id).get(),
+            self.blockchain().get_block_timestamp(),
+        )
+    }
+
+    fn require_action_is_valid(&self, action: &ProposalAction<Self::Api>) {
+        require!(action.gas_limit > 0, ERR_INVALID_PROPOSAL_ACTION);
+
+        if action.payment.amount == 0 {
+            return;
+        }
+
+        if let Some(token_identifier) = action.payment.token_identifier.as_esdt_option() {
+            require!(
+                *token_identifier != self.tro_token_identifier().get()
+                    && !self
+                        .whitelisted_lp_token_identifiers()
+                        .contains(&token_identifier),
+                ERR_INVALID_PROPOSAL_ACTION
+            );
+        }
+    }
+
+    #[view(getProposalActions)]
+    #[storage_mapper("proposal_actions")]
+    fn proposal_actions(&self, proposal_id: u64) -> VecMapper<ProposalAction<Self::Api>>;
+
+    #[storage_mapper("proposal_action_status")]
+    fn proposal_action_status(
+        &self,
+        proposal_id: u64,
+        action_index: usize,
+    ) -> SingleValueMapper<ActionStatus>;
+}
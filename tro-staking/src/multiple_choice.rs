@@ -0,0 +1,233 @@
+use crate::errors::*;
+use crate::voting::{
+    LpToTroRatio, OptionBallot, ProposalStatus, RankedChoiceCount, RankedChoiceResult, VotingMethod,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub const MIN_PROPOSAL_OPTIONS: usize = 2;
+pub const MAX_PROPOSAL_OPTIONS: usize = 20; // bounds the gas used to count ranked choice votes
+pub const MAX_BALLOTS_COUNTED_PER_CALL: usize = 100;
+
+/// Outcome of a multiple choice proposal. While voting is active, the winning option is the current leader.
+/// For ranked choice proposals, the final round votes are the ones left after the instant runoff eliminations.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct OptionProposalResult<M: ManagedTypeApi> {
+    pub voting_method: VotingMethod,
+    pub proposal_status: ProposalStatus,
+    pub winning_option: Option<u32>,
+    pub first_choice_votes: ManagedVec<M, BigUint<M>>,
+    pub final_round_votes: ManagedVec<M, BigUint<M>>,
+}
+
+/// Proposals voted by choosing among custom options, such as the next featured artist.
+/// The winner is the option with the most votes (Plurality) or the instant runoff winner (RankedChoice);
+/// a proposal without a winner is Rejected. Delegated voting power only applies to YesNo proposals.
+/// Once voting has ended, ranked choice votes are counted with `countRankedChoiceVotes` before the
+/// proposal can be settled, queued, vetoed or executed.
+#[multiversx_sc::module]
+pub trait MultipleChoiceModule:
+    crate::storage::StorageModule + crate::voting::VotingModule + crate::events::EventsModule
+{
+    /// Same rules as `createProposal`, for a proposal voted on `options` instead of a `VoteDecision`.
+    #[payable("*")]
+    #[endpoint(createOptionProposal)]
+    #[allow_multiple_var_args]
+    fn create_option_proposal(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        min_voting_power_to_validate_vote: BigUint,
+        voting_method: VotingMethod,
+        options: ManagedVec<ManagedBuffer>,
+        start_time: OptionalValue<u64>,
+        end_time: OptionalValue<u64>,
+        lp_to_tro_ratios: MultiValueEncoded<LpToTroRatio<Self::Api>>,
+    ) {
+        require!(
+            voting_method != VotingMethod::YesNo,
+            ERR_INVALID_VOTING_METHOD
+        );
+        require!(
+            options.len() >= MIN_PROPOSAL_OPTIONS && options.len() <= MAX_PROPOSAL_OPTIONS,
+            ERR_INVALID_PROPOSAL_OPTIONS
+        );
+
+        let proposal_id = self.create_new_proposal(
+            title,
+            description,
+            min_voting_power_to_validate_vote,
+            start_time,
+            end_time,
+            lp_to_tro_ratios,
+        );
+
+        self.proposal_voting_method(proposal_id).set(voting_method);
+        for option in options.iter() {
+            self.proposal_options(proposal_id).push(&*option);
+        }
+    }
+
+    /// Vote with the indexes of the chosen options, by order of preference.
+    /// Plurality proposals take a single option, ranked choice proposals take one or more.
+    #[endpoint(voteOptions)]
+    fn vote_options(&self, proposal_id: u64, ranking: MultiValueEncoded<u32>) {
+        let caller = self.blockchain().get_caller();
+        self.require_proposal_exists(proposal_id);
+        self.require_proposal_active(proposal_id);
+        let voting_method = self.proposal_voting_method(proposal_id).get();
+        require!(
+            voting_method != VotingMethod::YesNo,
+            ERR_INVALID_VOTING_METHOD
+        );
+        require!(
+            self.user_option_ballot(&caller, proposal_id).is_empty(),
+            ERR_USER_ALREADY_VOTED
+        );
+
+        let ranking = ranking.to_vec();
+        self.require_ranking_is_valid(proposal_id, voting_method, &ranking);
+
+        let voting_power = self.get_voting_power(&caller, proposal_id);
+        require!(voting_power > 0, ERR_INSUFFICIENT_VOTING_POWER);
+
+        self.option_votes(proposal_id, ranking.get(0))
+            .update(|votes| *votes += &voting_power);
+
+        let ballot = OptionBallot {
+            voter: caller.clone(),
+            ranking,
+            voting_power,
+            timestamp: self.blockchain().get_block_timestamp(),
+        };
+        let ballot_index = self.option_ballots(proposal_id).push(&ballot);
+        self.user_option_ballot(&caller, proposal_id)
+            .set(ballot_index);
+
+        self.emit_option_vote_event(&caller, proposal_id, &ballot);
+        self.lock_stake_until_proposal_end(&caller, proposal_id);
+    }
+
+    /// Count the ballots of an ended ranked choice proposal, `MAX_BALLOTS_COUNTED_PER_CALL` at a time.
+    /// Can be called by anyone until the count completes and its result is stored.
+    #[endpoint(countRankedChoiceVotes)]
+    fn count_ranked_choice_votes(&self, proposal_id: u64) -> OperationCompletionStatus {
+        self.require_proposal_exists(proposal_id);
+        require!(
+            self.proposal_voting_method(proposal_id).get() == VotingMethod::RankedChoice,
+            ERR_INVALID_VOTING_METHOD
+        );
+        require!(
+            self.blockchain().get_block_timestamp() > self.proposals(proposal_id).get().end_time,
+            ERR_PROPOSAL_VOTING_NOT_ENDED
+        );
+        require!(
+            self.ranked_choice_result(proposal_id).is_empty(),
+            ERR_RANKED_CHOICE_VOTES_ALREADY_COUNTED
+        );
+
+        let count_mapper = self.ranked_choice_count(proposal_id);
+        let mut count = if count_mapper.is_empty() {
+            RankedChoiceCount::new(self.proposal_options(proposal_id).len())
+        } else {
+            count_mapper.get()
+        };
+
+        let ballots = self.option_ballots(proposal_id);
+        let mut counted_ballots = 0;
+        while counted_ballots < MAX_BALLOTS_COUNTED_PER_CALL {
+            if count.next_ballot_index <= ballots.len() {
+                count.add_ballot(&ballots.get(count.next_ballot_index));
+                counted_ballots += 1;
+                continue;
+            }
+
+            if let Some(winning_option) = count.end_round() {
+                count_mapper.clear();
+                self.ranked_choice_result(proposal_id)
+                    .set(RankedChoiceResult {
+                        winning_option,
+                        final_round_votes: count.round_votes,
+                    });
+                return OperationCompletionStatus::Completed;
+            }
+        }
+
+        count_mapper.set(count);
+        OperationCompletionStatus::InterruptedBeforeOutOfGas
+    }
+
+    fn require_ranking_is_valid(
+        &self,
+        proposal_id: u64,
+        voting_method: VotingMethod,
+        ranking: &ManagedVec<u32>,
+    ) {
+        let option_count = self.proposal_options(proposal_id).len();
+        let max_ranking_len = if voting_method == VotingMethod::Plurality {
+            1
+        } else {
+            option_count
+        };
+        require!(
+            !ranking.is_empty() && ranking.len() <= max_ranking_len,
+            ERR_INVALID_OPTION_RANKING
+        );
+
+        for (position, option_index) in ranking.iter().enumerate() {
+            require!(
+                (option_index as usize) < option_count,
+                ERR_INVALID_OPTION_RANKING
+            );
+            require!(
+                !ranking
+                    .iter()
+                    .take(position)
+                    .any(|ranked_option| ranked_option == option_index),
+                ERR_INVALID_OPTION_RANKING
+            );
+        }
+    }
+
+    #[view(getUserOptionBallot)]
+    fn get_user_option_ballot(
+        &self,
+        user: ManagedAddress,
+        proposal_id: u64,
+    ) -> OptionalValue<OptionBallot<Self::Api>> {
+        if self.user_option_ballot(&user, proposal_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        let ballot_index = self.user_option_ballot(&user, proposal_id).get();
+        OptionalValue::Some(self.option_ballots(proposal_id).get(ballot_index))
+    }
+
+    #[view(getOptionProposalResult)]
+    fn get_option_proposal_result(&self, proposal_id: u64) -> OptionProposalResult<Self::Api> {
+        self.require_proposal_exists(proposal_id);
+        let voting_method = self.proposal_voting_method(proposal_id).get();
+        require!(
+            voting_method != VotingMethod::YesNo,
+            ERR_INVALID_VOTING_METHOD
+        );
+
+        let proposal = self.proposals(proposal_id).get();
+        let mut first_choice_votes = ManagedVec::new();
+        for option_index in 0..self.proposal_options(proposal_id).len() as u32 {
+            first_choice_votes.push(self.option_votes(proposal_id, option_index).get());
+        }
+        let (winning_option, final_round_votes) = self.count_option_votes(proposal_id);
+
+        OptionProposalResult {
+            voting_method,
+            proposal_status: self
+                .get_proposal_status(&proposal, self.blockchain().get_block_timestamp()),
+            winning_option,
+            first_choice_votes,
+            final_round_votes,
+        }
+    }
+}
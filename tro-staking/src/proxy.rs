@@ -45,228 +45,1156 @@ where
 {
     pub fn init<
         Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
-        Arg1: ProxyArg<MultiValueEncoded<Env::Api, TokenIdentifier<Env::Api>>>,
     >(
         self,
         tro_token_identifier: Arg0,
-        lp_token_identifiers: Arg1,
     ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_deploy()
             .argument(&tro_token_identifier)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> TroStakingProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> TroStakingProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn stake(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("stake")
+            .original_result()
+    }
+
+    pub fn unstake<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<TokenIdentifier<Env::Api>, BigUint<Env::Api>>>>,
+    >(
+        self,
+        request: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unstake")
+            .argument(&request)
+            .original_result()
+    }
+
+    /// Claim unstaked tokens whose unbonding period has passed, oldest batches first. 
+    /// At most `max_batches` batches are claimed; all unbonded batches are claimed if not given. 
+    pub fn claim_unstaked<
+        Arg0: ProxyArg<OptionalValue<usize>>,
+    >(
+        self,
+        max_batches: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimUnstaked")
+            .argument(&max_batches)
+            .original_result()
+    }
+
+    pub fn tro_token_identifier(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTroTokenIdentifier")
+            .original_result()
+    }
+
+    pub fn whitelisted_lp_token_identifiers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWhitelistedLpTokenIdentifiers")
+            .original_result()
+    }
+
+    pub fn users_stake<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        users_address: Arg0,
+        token_identifier: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUsersStake")
+            .argument(&users_address)
+            .argument(&token_identifier)
+            .original_result()
+    }
+
+    /// Sum of the stake of all users, per token 
+    pub fn total_staked<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_identifier: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalStaked")
+            .argument(&token_identifier)
+            .original_result()
+    }
+
+    /// Set by the owner once the stake of every user is counted in the totals, which enables 
+    /// the quorum and approval thresholds of the proposals created afterwards. 
+    /// Always set on contracts deployed with the totals. 
+    pub fn total_staked_reconciled(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isTotalStakedReconciled")
+            .original_result()
+    }
+
+    /// Address voting on behalf of the user, if any 
+    pub fn user_delegate<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        users_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegate")
+            .argument(&users_address)
+            .original_result()
+    }
+
+    pub fn delegators<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        delegate: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegators")
+            .argument(&delegate)
+            .original_result()
+    }
+
+    /// Stake value, in $TRO, needed to delegate 
+    pub fn min_stake_to_delegate(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinStakeToDelegate")
+            .original_result()
+    }
+
+    /// How long unstaked tokens wait before they can be claimed 
+    pub fn unbonding_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnbondingPeriod")
+            .original_result()
+    }
+
+    /// Voting locks the voter's stake until the end of the proposal, the latest one if several 
+    pub fn users_locked_until<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        users_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserLockedUntil")
+            .argument(&users_address)
+            .original_result()
+    }
+
+    /// Delay between queueing an approved proposal and being able to execute it. 
+    pub fn timelock_delay(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTimelockDelay")
+            .original_result()
+    }
+
+    /// How long a queued proposal can be executed once its timelock delay has passed. 
+    pub fn execution_grace_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExecutionGracePeriod")
+            .original_result()
+    }
+
+    /// Addresses that can veto approved or queued proposals, besides the owner. 
+    pub fn guardians(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGuardians")
+            .original_result()
+    }
+
+    pub fn proposal_executable_at<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalExecutableAt")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn proposal_expires_at<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalExpiresAt")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn proposal_vetoed<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isProposalVetoed")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn proposal_executed<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isProposalExecuted")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Minimum voting power required to create a proposal, for anyone but the owner. 
+    pub fn proposal_creation_threshold(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalCreationThreshold")
+            .original_result()
+    }
+
+    /// $TRO amount locked when creating a proposal, for anyone but the owner. 
+    pub fn proposal_deposit_amount(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalDepositAmount")
+            .original_result()
+    }
+
+    /// Lowest `min_voting_power_to_validate_vote` anyone but the owner can set on their proposals. 
+    pub fn min_voting_power_to_validate_vote_floor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinVotingPowerToValidateVoteFloor")
+            .original_result()
+    }
+
+    pub fn max_open_proposals_per_creator(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxOpenProposalsPerCreator")
+            .original_result()
+    }
+
+    /// Share of the total voting power that must vote on a proposal, in basis points. 
+    /// Applies to the proposals created afterwards, on top of their `min_voting_power_to_validate_vote`. 
+    pub fn quorum_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQuorumBps")
+            .original_result()
+    }
+
+    /// Share of the total voting power that must approve a proposal, in basis points. 
+    /// Applies to the proposals created afterwards. 
+    pub fn approval_threshold_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getApprovalThresholdBps")
+            .original_result()
+    }
+
+    /// Receiver of the slashed proposal deposits. The owner receives them if not set. 
+    pub fn treasury_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTreasuryAddress")
+            .original_result()
+    }
+
+    pub fn get_unbonding_batches<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, UnbondingBatch<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnbondingBatches")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Tokens the user can currently claim through `claimUnstaked` 
+    pub fn get_claimable_unstaked<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimableUnstaked")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// First timestamp at which the user can unstake, 0 if the stake is not locked. 
+    pub fn get_unstake_available_at<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnstakeAvailableAt")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// At most `MAX_WHITELISTED_LP_TOKENS` LP tokens can be whitelisted, as voting power sums the stake of each of them. 
+    pub fn add_whitelisted_lp_tokens<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, TokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        lp_token_identifiers: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addWhitelistedLpTokens")
             .argument(&lp_token_identifiers)
             .original_result()
     }
-}
 
-#[rustfmt::skip]
-impl<Env, From, To, Gas> TroStakingProxyMethods<Env, From, To, Gas>
-where
-    Env: TxEnv,
-    Env::Api: VMApi,
-    From: TxFrom<Env>,
-    To: TxTo<Env>,
-    Gas: TxGas<Env>,
-{
-    pub fn upgrade(
+    /// Count the stake of users who staked before the totals were recorded. 
+    /// Users already counted are skipped. 
+    pub fn count_users_stake_in_total<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        users: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("countUsersStakeInTotal")
+            .argument(&users)
+            .original_result()
+    }
+
+    /// Enable the quorum and approval thresholds once every staker is counted in the totals 
+    pub fn set_total_staked_reconciled(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTotalStakedReconciled")
+            .original_result()
+    }
+
+    pub fn set_tro_token_identifier<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        tro_token_identifier: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTroTokenIdentifier")
+            .argument(&tro_token_identifier)
+            .original_result()
+    }
+
+    pub fn set_min_stake_to_delegate<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        min_stake: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinStakeToDelegate")
+            .argument(&min_stake)
+            .original_result()
+    }
+
+    pub fn set_timelock_delay<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        delay: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTimelockDelay")
+            .argument(&delay)
+            .original_result()
+    }
+
+    pub fn set_execution_grace_period<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        grace_period: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setExecutionGracePeriod")
+            .argument(&grace_period)
+            .original_result()
+    }
+
+    pub fn add_guardian<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        guardian: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addGuardian")
+            .argument(&guardian)
+            .original_result()
+    }
+
+    pub fn remove_guardian<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        guardian: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeGuardian")
+            .argument(&guardian)
+            .original_result()
+    }
+
+    pub fn set_proposal_creation_threshold<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        threshold: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProposalCreationThreshold")
+            .argument(&threshold)
+            .original_result()
+    }
+
+    pub fn set_proposal_deposit_amount<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProposalDepositAmount")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn set_min_voting_power_to_validate_vote_floor<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        floor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinVotingPowerToValidateVoteFloor")
+            .argument(&floor)
+            .original_result()
+    }
+
+    pub fn set_max_open_proposals_per_creator<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        max_open_proposals: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxOpenProposalsPerCreator")
+            .argument(&max_open_proposals)
+            .original_result()
+    }
+
+    pub fn set_treasury_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        treasury_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTreasuryAddress")
+            .argument(&treasury_address)
+            .original_result()
+    }
+
+    pub fn set_unbonding_period<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        unbonding_period: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setUnbondingPeriod")
+            .argument(&unbonding_period)
+            .original_result()
+    }
+
+    pub fn set_quorum_bps<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        quorum_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setQuorumBps")
+            .argument(&quorum_bps)
+            .original_result()
+    }
+
+    pub fn set_approval_threshold_bps<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        approval_threshold_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setApprovalThresholdBps")
+            .argument(&approval_threshold_bps)
+            .original_result()
+    }
+
+    /// The owner can always create proposals and set the LP to $TRO ratios, which also become the current ones. 
+    /// Anyone else needs at least `proposal_creation_threshold` voting power and must lock the $TRO 
+    /// `proposal_deposit_amount` as payment. Their proposals use the current LP ratios, and their 
+    /// `min_voting_power_to_validate_vote` cannot be below the floor set by the owner. 
+    pub fn create_proposal<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<OptionalValue<u64>>,
+        Arg4: ProxyArg<OptionalValue<u64>>,
+        Arg5: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<TokenIdentifier<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>>>,
+    >(
+        self,
+        title: Arg0,
+        description: Arg1,
+        min_voting_power_to_validate_vote: Arg2,
+        start_time: Arg3,
+        end_time: Arg4,
+        lp_to_tro_ratios: Arg5,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("createProposal")
+            .argument(&title)
+            .argument(&description)
+            .argument(&min_voting_power_to_validate_vote)
+            .argument(&start_time)
+            .argument(&end_time)
+            .argument(&lp_to_tro_ratios)
+            .original_result()
+    }
+
+    /// Once voting has ended, refund the proposal deposit to its creator if the proposal reached quorum, 
+    /// or slash it to the treasury if it Failed. Can be called by anyone. 
+    pub fn settle_proposal_deposit<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("settleProposalDeposit")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn vote<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<VoteDecision>,
+    >(
+        self,
+        proposal_id: Arg0,
+        decision: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("vote")
+            .argument(&proposal_id)
+            .argument(&decision)
+            .original_result()
+    }
+
+    /// Replace the caller's vote on an active proposal. The voting power is recomputed, 
+    /// including the power of delegators who have not voted yet. 
+    pub fn change_vote<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<VoteDecision>,
+    >(
+        self,
+        proposal_id: Arg0,
+        decision: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("changeVote")
+            .argument(&proposal_id)
+            .argument(&decision)
+            .original_result()
+    }
+
+    /// Staked balance of the user at the end of the last block before `timestamp`. 
+    pub fn get_user_stake_before<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        user: Arg0,
+        token_identifier: Arg1,
+        timestamp: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserStakeBefore")
+            .argument(&user)
+            .argument(&token_identifier)
+            .argument(&timestamp)
+            .original_result()
+    }
+
+    pub fn last_proposal_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastProposalId")
+            .original_result()
+    }
+
+    pub fn proposals<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Proposal<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn proposal_votes<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<VoteDecision>,
+    >(
+        self,
+        proposal_id: Arg0,
+        decision: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalVotes")
+            .argument(&proposal_id)
+            .argument(&decision)
+            .original_result()
+    }
+
+    pub fn user_votes<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        user: Arg0,
+        proposal_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, VoteContext<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserVote")
+            .argument(&user)
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// $TRO locked by the creator of a proposal, until it is settled 
+    pub fn proposal_deposit<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalDeposit")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn creator_open_proposals<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        creator: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCreatorOpenProposals")
+            .argument(&creator)
+            .original_result()
+    }
+
+    /// Every vote and vote change of the user on the proposal, oldest first 
+    pub fn user_vote_history<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        user: Arg0,
+        proposal_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, VoteContext<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserVoteHistory")
+            .argument(&user)
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn delegated_votes<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        delegator: Arg0,
+        proposal_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DelegatedVote<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegatedVote")
+            .argument(&delegator)
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Voting power is computed from the stake held before this timestamp 
+    pub fn proposal_snapshot_timestamp<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalSnapshotTimestamp")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Empty for YesNo proposals 
+    pub fn proposal_voting_method<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, VotingMethod> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalVotingMethod")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn proposal_options<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalOptions")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Voting power of the ballots ranking the option first 
+    pub fn option_votes<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        proposal_id: Arg0,
+        option_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOptionVotes")
+            .argument(&proposal_id)
+            .argument(&option_index)
+            .original_result()
+    }
+
+    /// LP to $TRO ratios used by the proposals created by anyone but the owner 
+    pub fn current_lp_to_tro_ratio<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        lp_token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentLpToTroRatio")
+            .argument(&lp_token)
+            .original_result()
+    }
+
+    pub fn lp_to_tro_ratio<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        lp_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLpToTroRatio")
+            .argument(&proposal_id)
+            .argument(&lp_token)
+            .original_result()
+    }
+
+    /// Attach actions to a proposal before its voting starts. 
+    /// Payments cannot use $TRO or whitelisted LP tokens, so that staked funds are never moved by a proposal. 
+    pub fn add_proposal_actions<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ProposalAction<Env::Api>>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        actions: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addProposalActions")
+            .argument(&proposal_id)
+            .argument(&actions)
+            .original_result()
+    }
+
+    /// Start the timelock of an approved proposal. Can be called by anyone. 
+    /// The proposal becomes executable once the timelock delay has passed, 
+    /// and expires if it is not executed within the grace period. 
+    pub fn queue_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("queueProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Cancel an approved proposal before it becomes executable. Owner or guardians only. 
+    pub fn veto_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("vetoProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Run the actions of a queued proposal once its timelock delay has passed. Can be called by anyone. 
+    pub fn execute_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
         self,
-    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_upgrade()
+            .raw_call("executeProposal")
+            .argument(&proposal_id)
             .original_result()
     }
-}
 
-#[rustfmt::skip]
-impl<Env, From, To, Gas> TroStakingProxyMethods<Env, From, To, Gas>
-where
-    Env: TxEnv,
-    Env::Api: VMApi,
-    From: TxFrom<Env>,
-    To: TxTo<Env>,
-    Gas: TxGas<Env>,
-{
-    pub fn stake(
+    pub fn get_proposal_action_statuses<
+        Arg0: ProxyArg<u64>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ActionStatus>> {
         self.wrapped_tx
-            .raw_call("stake")
+            .payment(NotPayable)
+            .raw_call("getProposalActionStatuses")
+            .argument(&proposal_id)
             .original_result()
     }
 
-    pub fn unstake<
-        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<TokenIdentifier<Env::Api>, BigUint<Env::Api>>>>,
+    pub fn proposal_actions<
+        Arg0: ProxyArg<u64>,
     >(
         self,
-        request: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ProposalAction<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("unstake")
-            .argument(&request)
+            .raw_call("getProposalActions")
+            .argument(&proposal_id)
             .original_result()
     }
 
-    pub fn tro_token_identifier(
+    /// Delegate the caller's voting power, replacing any previous delegation. 
+    /// The caller needs at least `min_stake_to_delegate`, valued at the current LP to $TRO ratios. 
+    pub fn delegate<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        delegate: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getTroTokenIdentifier")
+            .raw_call("delegate")
+            .argument(&delegate)
             .original_result()
     }
 
-    pub fn whitelisted_lp_token_identifiers(
+    pub fn revoke_delegation(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TokenIdentifier<Env::Api>>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getWhitelistedLpTokenIdentifiers")
+            .raw_call("revokeDelegation")
             .original_result()
     }
 
-    pub fn users_stake<
+    /// Let a delegate drop one of their delegators, freeing its slot. 
+    pub fn remove_delegator<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
     >(
         self,
-        users_address: Arg0,
-        token_identifier: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        delegator: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getUsersStake")
-            .argument(&users_address)
-            .argument(&token_identifier)
+            .raw_call("removeDelegator")
+            .argument(&delegator)
             .original_result()
     }
 
-    pub fn add_whitelisted_lp_tokens<
-        Arg0: ProxyArg<MultiValueEncoded<Env::Api, TokenIdentifier<Env::Api>>>,
+    /// Voting power the delegate would add to their own when voting on the proposal now 
+    pub fn get_delegated_voting_power<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
     >(
         self,
-        lp_token_identifiers: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        delegate: Arg0,
+        proposal_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("addWhitelistedLpTokens")
-            .argument(&lp_token_identifiers)
+            .raw_call("getDelegatedVotingPower")
+            .argument(&delegate)
+            .argument(&proposal_id)
             .original_result()
     }
 
-    pub fn create_proposal<
+    /// Same rules as `createProposal`, for a proposal voted on `options` instead of a `VoteDecision`. 
+    pub fn create_option_proposal<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg2: ProxyArg<BigUint<Env::Api>>,
-        Arg3: ProxyArg<OptionalValue<u64>>,
-        Arg4: ProxyArg<OptionalValue<u64>>,
-        Arg5: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<TokenIdentifier<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>>>,
+        Arg3: ProxyArg<VotingMethod>,
+        Arg4: ProxyArg<ManagedVec<Env::Api, ManagedBuffer<Env::Api>>>,
+        Arg5: ProxyArg<OptionalValue<u64>>,
+        Arg6: ProxyArg<OptionalValue<u64>>,
+        Arg7: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<TokenIdentifier<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>>>,
     >(
         self,
         title: Arg0,
         description: Arg1,
         min_voting_power_to_validate_vote: Arg2,
-        start_time: Arg3,
-        end_time: Arg4,
-        lp_to_tro_ratios: Arg5,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        voting_method: Arg3,
+        options: Arg4,
+        start_time: Arg5,
+        end_time: Arg6,
+        lp_to_tro_ratios: Arg7,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("createProposal")
+            .raw_call("createOptionProposal")
             .argument(&title)
             .argument(&description)
             .argument(&min_voting_power_to_validate_vote)
+            .argument(&voting_method)
+            .argument(&options)
             .argument(&start_time)
             .argument(&end_time)
             .argument(&lp_to_tro_ratios)
             .original_result()
     }
 
-    pub fn vote<
+    /// Vote with the indexes of the chosen options, by order of preference. 
+    /// Plurality proposals take a single option, ranked choice proposals take one or more. 
+    pub fn vote_options<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<VoteDecision>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, u32>>,
     >(
         self,
         proposal_id: Arg0,
-        decision: Arg1,
+        ranking: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("vote")
-            .argument(&proposal_id)
-            .argument(&decision)
-            .original_result()
-    }
-
-    pub fn last_proposal_id(
-        self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
-        self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("getLastProposalId")
-            .original_result()
-    }
-
-    pub fn proposals<
-        Arg0: ProxyArg<u64>,
-    >(
-        self,
-        proposal_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Proposal<Env::Api>> {
-        self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("getProposal")
+            .raw_call("voteOptions")
             .argument(&proposal_id)
+            .argument(&ranking)
             .original_result()
     }
 
-    pub fn proposal_votes<
+    /// Count the ballots of an ended ranked choice proposal, `MAX_BALLOTS_COUNTED_PER_CALL` at a time. 
+    /// Can be called by anyone until the count completes and its result is stored. 
+    pub fn count_ranked_choice_votes<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<VoteDecision>,
     >(
         self,
         proposal_id: Arg0,
-        decision: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OperationCompletionStatus> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getProposalVotes")
+            .raw_call("countRankedChoiceVotes")
             .argument(&proposal_id)
-            .argument(&decision)
             .original_result()
     }
 
-    pub fn user_votes<
+    pub fn get_user_option_ballot<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<u64>,
     >(
         self,
         user: Arg0,
         proposal_id: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, VoteContext<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<OptionBallot<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getUserVote")
+            .raw_call("getUserOptionBallot")
             .argument(&user)
             .argument(&proposal_id)
             .original_result()
     }
 
-    pub fn lp_to_tro_ratio<
+    pub fn get_option_proposal_result<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
     >(
         self,
         proposal_id: Arg0,
-        lp_token: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionProposalResult<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getLpToTroRatio")
+            .raw_call("getOptionProposalResult")
             .argument(&proposal_id)
-            .argument(&lp_token)
             .original_result()
     }
 
@@ -394,6 +1322,56 @@ where
             .argument(&proposal_id)
             .original_result()
     }
+
+    pub fn get_proposal_thresholds_view<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ProposalThresholds<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalThresholds")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_participation<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProposalParticipation<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalParticipation")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_all_proposals<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, FullProposalContext<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllProposals")
+            .argument(&user)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct UnbondingBatch<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub unstake_timestamp: u64,
+    pub claimable_at: u64,
+    pub payments: ManagedVec<Api, EsdtTokenPayment<Api>>,
 }
 
 #[type_abi]
@@ -406,7 +1384,7 @@ pub enum VoteDecision {
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
 pub struct Proposal<Api>
 where
     Api: ManagedTypeApi,
@@ -422,12 +1400,12 @@ where
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
 pub struct VoteContext<Api>
 where
     Api: ManagedTypeApi,
 {
-    pub decision: VoteDecision,
+    pub decision: u8,
     pub voting_power: BigUint<Api>,
     pub timestamp: u64,
     pub block: u64,
@@ -435,7 +1413,96 @@ where
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct DelegatedVote<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub delegate: ManagedAddress<Api>,
+    pub voting_power: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VotingMethod {
+    YesNo,
+    Plurality,
+    RankedChoice,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct ProposalAction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub target: ManagedAddress<Api>,
+    pub endpoint_name: ManagedBuffer<Api>,
+    pub arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+    pub payment: EgldOrEsdtTokenPayment<Api>,
+    pub gas_limit: u64,
+    pub call_type: ActionCallType,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Copy)]
+pub enum ActionCallType {
+    Sync,
+    Async,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ActionStatus {
+    NotExecuted,
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct OptionBallot<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub voter: ManagedAddress<Api>,
+    pub ranking: ManagedVec<Api, u32>,
+    pub voting_power: BigUint<Api>,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct OptionProposalResult<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub voting_method: VotingMethod,
+    pub proposal_status: ProposalStatus,
+    pub winning_option: Option<u32>,
+    pub first_choice_votes: ManagedVec<Api, BigUint<Api>>,
+    pub final_round_votes: ManagedVec<Api, BigUint<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    Invalid,
+    Pending,
+    Active,
+    Approved,
+    Rejected,
+    Failed,
+    Queued,
+    Executable,
+    Expired,
+    Vetoed,
+    Executed,
+}
+
+#[type_abi]
+#[derive(TopEncode)]
 pub struct StakeEvent<Api>
 where
     Api: ManagedTypeApi,
@@ -445,7 +1512,7 @@ where
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode)]
+#[derive(TopEncode)]
 pub struct ProposalCreatedEvent<Api>
 where
     Api: ManagedTypeApi,
@@ -483,18 +1550,7 @@ where
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
-pub enum ProposalStatus {
-    Invalid,
-    Pending,
-    Active,
-    Approved,
-    Rejected,
-    Failed,
-}
-
-#[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
 pub struct ProposalVoteCount<Api>
 where
     Api: ManagedTypeApi,
@@ -504,3 +1560,40 @@ where
     pub reject: BigUint<Api>,
     pub invalid: BigUint<Api>,
 }
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ProposalThresholds<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub total_voting_power: BigUint<Api>,
+    pub quorum_bps: u64,
+    pub approval_threshold_bps: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ProposalParticipation<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub total_voting_power: BigUint<Api>,
+    pub total_votes: BigUint<Api>,
+    pub participation_bps: u64,
+    pub quorum: BigUint<Api>,
+    pub approval_threshold: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct FullProposalContext<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub proposal: Proposal<Api>,
+    pub users_voting_power: BigUint<Api>,
+    pub users_vote: Option<VoteContext<Api>>,
+    pub proposal_status: u8,
+    pub proposal_vote_count: ProposalVoteCount<Api>,
+}
@@ -1,8 +1,21 @@
+use multiversx_sc::derive_imports::*;
 #[allow(unused_imports)]
 use multiversx_sc::imports::*;
 
 use crate::errors::*;
 
+pub const DEFAULT_UNBONDING_PERIOD_IN_SECONDS: u64 = 7 * 24 * 3600;
+pub const MAX_UNBONDING_BATCHES: usize = 50; // bounds the gas used by claimUnstaked
+
+/// Tokens unstaked at the same timestamp, claimable once the unbonding period has passed
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct UnbondingBatch<M: ManagedTypeApi> {
+    pub unstake_timestamp: u64,
+    pub claimable_at: u64,
+    pub payments: ManagedVec<M, EsdtTokenPayment<M>>,
+}
+
 #[multiversx_sc::module]
 pub trait StakeModule: crate::storage::StorageModule {
     fn process_stake(&self, user: &ManagedAddress, payments: &ManagedVec<EsdtTokenPayment>) {
@@ -13,8 +26,8 @@ pub trait StakeModule: crate::storage::StorageModule {
     }
 
     fn add_payment_to_user_stake(&self, user: &ManagedAddress, payment: &EsdtTokenPayment) {
-        self.users_stake(user, &payment.token_identifier)
-            .update(|amount| *amount += &payment.amount);
+        let new_amount = self.users_stake(user, &payment.token_identifier).get() + &payment.amount;
+        self.set_user_stake(user, &payment.token_identifier, new_amount);
     }
 
     fn process_unstake(
@@ -36,14 +49,175 @@ pub trait StakeModule: crate::storage::StorageModule {
         payments
     }
 
+    /// Unstaked tokens no longer count towards voting power while they unbond.
+    /// Tokens unstaked at the same timestamp as the last batch are merged into it.
+    fn push_unbonding_batch(&self, user: &ManagedAddress, payments: &ManagedVec<EsdtTokenPayment>) {
+        let block_timestamp = self.blockchain().get_block_timestamp();
+        let mut unbonding_queue = self.unbonding_queue(user);
+        if unbonding_queue.back() == Some(block_timestamp) {
+            self.unbonding_batch(user, block_timestamp)
+                .update(|batch| batch.append_vec(payments.clone()));
+            return;
+        }
+
+        unbonding_queue.push_back(block_timestamp);
+        self.unbonding_batch(user, block_timestamp).set(payments);
+    }
+
+    /// Removes at most `max_batches` unbonded batches, oldest first, and returns their tokens.
+    fn process_claim_unstaked(
+        &self,
+        user: &ManagedAddress,
+        max_batches: usize,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        let block_timestamp = self.blockchain().get_block_timestamp();
+        let unbonding_period = self.unbonding_period().get();
+        let mut unbonding_queue = self.unbonding_queue(user);
+        let mut payments = ManagedVec::new();
+        let mut claimed_batches = 0;
+
+        while claimed_batches < max_batches {
+            match unbonding_queue.front() {
+                Some(unstake_timestamp)
+                    if block_timestamp - unstake_timestamp >= unbonding_period =>
+                {
+                    unbonding_queue.pop_front();
+                    payments.append_vec(self.unbonding_batch(user, unstake_timestamp).take());
+                    claimed_batches += 1;
+                }
+                _ => break,
+            }
+        }
+
+        require!(!payments.is_empty(), ERR_NO_UNSTAKED_TOKENS);
+
+        payments
+    }
+
+    #[view(getUnbondingBatches)]
+    fn get_unbonding_batches(
+        &self,
+        user: &ManagedAddress,
+    ) -> MultiValueEncoded<UnbondingBatch<Self::Api>> {
+        let unbonding_period = self.unbonding_period().get();
+        let mut batches = MultiValueEncoded::new();
+        for unstake_timestamp in self.unbonding_queue(user).iter() {
+            batches.push(UnbondingBatch {
+                unstake_timestamp,
+                claimable_at: unstake_timestamp + unbonding_period,
+                payments: self.unbonding_batch(user, unstake_timestamp).get(),
+            });
+        }
+
+        batches
+    }
+
+    /// Tokens the user can currently claim through `claimUnstaked`
+    #[view(getClaimableUnstaked)]
+    fn get_claimable_unstaked(&self, user: &ManagedAddress) -> ManagedVec<EsdtTokenPayment> {
+        let block_timestamp = self.blockchain().get_block_timestamp();
+        let unbonding_period = self.unbonding_period().get();
+        let mut payments = ManagedVec::new();
+        for unstake_timestamp in self.unbonding_queue(user).iter() {
+            if block_timestamp - unstake_timestamp < unbonding_period {
+                break;
+            }
+
+            payments.append_vec(self.unbonding_batch(user, unstake_timestamp).get());
+        }
+
+        payments
+    }
+
     fn subtract_payment_from_user_stake(
         &self,
         user: &ManagedAddress,
         token_identifier: &TokenIdentifier,
         amount: &BigUint,
     ) {
-        self.users_stake(user, token_identifier)
-            .update(|old_amount| *old_amount -= amount);
+        let new_amount = self.users_stake(user, token_identifier).get() - amount;
+        self.set_user_stake(user, token_identifier, new_amount);
+    }
+
+    fn set_user_stake(
+        &self,
+        user: &ManagedAddress,
+        token_identifier: &TokenIdentifier,
+        amount: BigUint,
+    ) {
+        let mut checkpoints = self.users_stake_checkpoints(user, token_identifier);
+        if checkpoints.is_empty() {
+            // Stake from before checkpoints were recorded has been held since then
+            let previous_amount = self.users_stake(user, token_identifier).get();
+            if previous_amount > 0 {
+                checkpoints.push(&(0, previous_amount));
+            }
+        }
+        self.push_checkpoint(&mut checkpoints, &amount);
+
+        self.count_user_stake_in_total(user);
+        let previous_amount = self.users_stake(user, token_identifier).get();
+        self.update_total_staked(token_identifier, &previous_amount, &amount);
+        self.users_stake(user, token_identifier).set(amount);
+    }
+
+    /// Add the stake the user held before the totals were recorded to the totals, once
+    fn count_user_stake_in_total(&self, user: &ManagedAddress) {
+        let counted_mapper = self.stake_counted_in_total(user);
+        if counted_mapper.get() {
+            return;
+        }
+        counted_mapper.set(true);
+
+        let mut token_identifiers = ManagedVec::<Self::Api, TokenIdentifier>::new();
+        token_identifiers.push(self.tro_token_identifier().get());
+        for lp_token in self.whitelisted_lp_token_identifiers().iter() {
+            token_identifiers.push(lp_token);
+        }
+
+        for token_identifier in token_identifiers.iter() {
+            let staked_amount = self.users_stake(user, &token_identifier).get();
+            if staked_amount > 0 {
+                self.update_total_staked(&token_identifier, &BigUint::zero(), &staked_amount);
+            }
+        }
+    }
+
+    fn update_total_staked(
+        &self,
+        token_identifier: &TokenIdentifier,
+        previous_amount: &BigUint,
+        new_amount: &BigUint,
+    ) {
+        let total = self.total_staked(token_identifier).get() + new_amount - previous_amount;
+        self.push_checkpoint(&mut self.total_staked_checkpoints(token_identifier), &total);
+        self.total_staked(token_identifier).set(total);
+    }
+
+    /// Record the balance at the current timestamp, replacing the one of the same block
+    fn push_checkpoint(&self, checkpoints: &mut VecMapper<(u64, BigUint)>, balance: &BigUint) {
+        let block_timestamp = self.blockchain().get_block_timestamp();
+        let last_index = checkpoints.len();
+        if last_index > 0 && checkpoints.get(last_index).0 == block_timestamp {
+            checkpoints.set(last_index, &(block_timestamp, balance.clone()));
+        } else {
+            checkpoints.push(&(block_timestamp, balance.clone()));
+        }
+    }
+
+    fn require_user_stake_unlocked(&self, user: &ManagedAddress) {
+        require!(self.get_unstake_available_at(user) == 0, ERR_STAKE_LOCKED);
+    }
+
+    /// First timestamp at which the user can unstake, 0 if the stake is not locked.
+    #[view(getUnstakeAvailableAt)]
+    fn get_unstake_available_at(&self, user: &ManagedAddress) -> u64 {
+        let locked_until = self.users_locked_until(user).get();
+        if locked_until == 0 || locked_until < self.blockchain().get_block_timestamp() {
+            return 0;
+        }
+
+        locked_until + 1
     }
 
     fn require_token_is_allowed(&self, token_identifier: &TokenIdentifier) {
@@ -18,4 +18,140 @@ pub trait StorageModule {
         users_address: &ManagedAddress,
         token_identifier: &TokenIdentifier,
     ) -> SingleValueMapper<BigUint>;
+
+    /// Sum of the stake of all users, per token
+    #[view(getTotalStaked)]
+    #[storage_mapper("total_staked")]
+    fn total_staked(&self, token_identifier: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Total stake after each change, as (timestamp, total), oldest first.
+    /// Only the last change of a block is kept.
+    #[storage_mapper("total_staked_checkpoints")]
+    fn total_staked_checkpoints(
+        &self,
+        token_identifier: &TokenIdentifier,
+    ) -> VecMapper<(u64, BigUint)>;
+
+    /// Whether the user's stake is part of the totals. Stake from before the totals were recorded
+    /// is added on the user's next stake change, or by the owner with `countUsersStakeInTotal`.
+    #[storage_mapper("stake_counted_in_total")]
+    fn stake_counted_in_total(&self, users_address: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    /// Set by the owner once the stake of every user is counted in the totals, which enables
+    /// the quorum and approval thresholds of the proposals created afterwards.
+    /// Always set on contracts deployed with the totals.
+    #[view(isTotalStakedReconciled)]
+    #[storage_mapper("total_staked_reconciled")]
+    fn total_staked_reconciled(&self) -> SingleValueMapper<bool>;
+
+    /// Staked balance of a user after each change, as (timestamp, balance), oldest first.
+    /// Only the last change of a block is kept.
+    #[storage_mapper("users_stake_checkpoints")]
+    fn users_stake_checkpoints(
+        &self,
+        users_address: &ManagedAddress,
+        token_identifier: &TokenIdentifier,
+    ) -> VecMapper<(u64, BigUint)>;
+
+    /// Address voting on behalf of the user, if any
+    #[view(getDelegate)]
+    #[storage_mapper("user_delegate")]
+    fn user_delegate(&self, users_address: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getDelegators)]
+    #[storage_mapper("delegators")]
+    fn delegators(&self, delegate: &ManagedAddress) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Stake value, in $TRO, needed to delegate
+    #[view(getMinStakeToDelegate)]
+    #[storage_mapper("min_stake_to_delegate")]
+    fn min_stake_to_delegate(&self) -> SingleValueMapper<BigUint>;
+
+    /// How long unstaked tokens wait before they can be claimed
+    #[view(getUnbondingPeriod)]
+    #[storage_mapper("unbonding_period")]
+    fn unbonding_period(&self) -> SingleValueMapper<u64>;
+
+    /// Timestamps of the user's unbonding batches, oldest first
+    #[storage_mapper("unbonding_queue")]
+    fn unbonding_queue(&self, users_address: &ManagedAddress) -> QueueMapper<u64>;
+
+    #[storage_mapper("unbonding_batch")]
+    fn unbonding_batch(
+        &self,
+        users_address: &ManagedAddress,
+        unstake_timestamp: u64,
+    ) -> SingleValueMapper<ManagedVec<EsdtTokenPayment>>;
+
+    /// Voting locks the voter's stake until the end of the proposal, the latest one if several
+    #[view(getUserLockedUntil)]
+    #[storage_mapper("users_locked_until")]
+    fn users_locked_until(&self, users_address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    /// Delay between queueing an approved proposal and being able to execute it.
+    #[view(getTimelockDelay)]
+    #[storage_mapper("timelock_delay")]
+    fn timelock_delay(&self) -> SingleValueMapper<u64>;
+
+    /// How long a queued proposal can be executed once its timelock delay has passed.
+    #[view(getExecutionGracePeriod)]
+    #[storage_mapper("execution_grace_period")]
+    fn execution_grace_period(&self) -> SingleValueMapper<u64>;
+
+    /// Addresses that can veto approved or queued proposals, besides the owner.
+    #[view(getGuardians)]
+    #[storage_mapper("guardians")]
+    fn guardians(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getProposalExecutableAt)]
+    #[storage_mapper("proposal_executable_at")]
+    fn proposal_executable_at(&self, proposal_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getProposalExpiresAt)]
+    #[storage_mapper("proposal_expires_at")]
+    fn proposal_expires_at(&self, proposal_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(isProposalVetoed)]
+    #[storage_mapper("proposal_vetoed")]
+    fn proposal_vetoed(&self, proposal_id: u64) -> SingleValueMapper<bool>;
+
+    #[view(isProposalExecuted)]
+    #[storage_mapper("proposal_executed")]
+    fn proposal_executed(&self, proposal_id: u64) -> SingleValueMapper<bool>;
+
+    /// Minimum voting power required to create a proposal, for anyone but the owner.
+    #[view(getProposalCreationThreshold)]
+    #[storage_mapper("proposal_creation_threshold")]
+    fn proposal_creation_threshold(&self) -> SingleValueMapper<BigUint>;
+
+    /// $TRO amount locked when creating a proposal, for anyone but the owner.
+    #[view(getProposalDepositAmount)]
+    #[storage_mapper("proposal_deposit_amount")]
+    fn proposal_deposit_amount(&self) -> SingleValueMapper<BigUint>;
+
+    /// Lowest `min_voting_power_to_validate_vote` anyone but the owner can set on their proposals.
+    #[view(getMinVotingPowerToValidateVoteFloor)]
+    #[storage_mapper("min_voting_power_to_validate_vote_floor")]
+    fn min_voting_power_to_validate_vote_floor(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getMaxOpenProposalsPerCreator)]
+    #[storage_mapper("max_open_proposals_per_creator")]
+    fn max_open_proposals_per_creator(&self) -> SingleValueMapper<usize>;
+
+    /// Share of the total voting power that must vote on a proposal, in basis points.
+    /// Applies to the proposals created afterwards, on top of their `min_voting_power_to_validate_vote`.
+    #[view(getQuorumBps)]
+    #[storage_mapper("quorum_bps")]
+    fn quorum_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Share of the total voting power that must approve a proposal, in basis points.
+    /// Applies to the proposals created afterwards.
+    #[view(getApprovalThresholdBps)]
+    #[storage_mapper("approval_threshold_bps")]
+    fn approval_threshold_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Receiver of the slashed proposal deposits. The owner receives them if not set.
+    #[view(getTreasuryAddress)]
+    #[storage_mapper("treasury_address")]
+    fn treasury_address(&self) -> SingleValueMapper<ManagedAddress>;
 }
@@ -7,7 +7,10 @@ pub mod errors;
 pub mod proxy;
 
 pub mod admin;
+pub mod delegation;
 mod events;
+pub mod execution;
+pub mod multiple_choice;
 pub mod stake;
 mod storage;
 pub mod views;
@@ -23,6 +26,9 @@ pub trait TroStaking:
     + stake::StakeModule
     + admin::AdminModule
     + voting::VotingModule
+    + execution::ExecutionModule
+    + delegation::DelegationModule
+    + multiple_choice::MultipleChoiceModule
     + events::EventsModule
     + views::ViewsModule
 {
@@ -30,6 +36,10 @@ pub trait TroStaking:
     fn init(&self, tro_token_identifier: TokenIdentifier) {
         self.tro_token_identifier().set(tro_token_identifier);
         self.last_proposal_id().set(0);
+        self.total_staked_reconciled().set(true);
+        self.init_timelock();
+        self.init_proposal_creation();
+        self.init_unbonding();
     }
 
     #[payable("*")]
@@ -46,16 +56,50 @@ pub trait TroStaking:
     #[endpoint(unstake)]
     fn unstake(&self, request: MultiValueEncoded<MultiValue2<TokenIdentifier, BigUint>>) {
         let caller = self.blockchain().get_caller();
+        self.require_user_stake_unlocked(&caller);
         let payments = self.process_unstake(&caller, request);
 
-        // Security: prevent unstake if there is an ongoing proposal
-        self.require_no_proposal_ongoing();
+        self.push_unbonding_batch(&caller, &payments);
+
+        self.emit_unstake_event(&caller, &payments);
+    }
+
+    /// Claim unstaked tokens whose unbonding period has passed, oldest batches first.
+    /// At most `max_batches` batches are claimed; all unbonded batches are claimed if not given.
+    #[endpoint(claimUnstaked)]
+    fn claim_unstaked(&self, max_batches: OptionalValue<usize>) {
+        let caller = self.blockchain().get_caller();
+        let max_batches = max_batches
+            .into_option()
+            .unwrap_or(stake::MAX_UNBONDING_BATCHES);
+        let payments = self.process_claim_unstaked(&caller, max_batches);
 
         self.send().direct_multi(&caller, &payments);
 
-        self.emit_unstake_event(&caller, &payments);
+        self.emit_claim_unstaked_event(&caller, &payments);
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.init_timelock();
+        self.init_proposal_creation();
+        self.init_unbonding();
+    }
+
+    fn init_timelock(&self) {
+        self.timelock_delay()
+            .set_if_empty(execution::DEFAULT_TIMELOCK_DELAY_IN_SECONDS);
+        self.execution_grace_period()
+            .set_if_empty(execution::DEFAULT_EXECUTION_GRACE_PERIOD_IN_SECONDS);
+    }
+
+    fn init_proposal_creation(&self) {
+        self.max_open_proposals_per_creator()
+            .set_if_empty(voting::DEFAULT_MAX_OPEN_PROPOSALS_PER_CREATOR);
+    }
+
+    fn init_unbonding(&self) {
+        self.unbonding_period()
+            .set_if_empty(stake::DEFAULT_UNBONDING_PERIOD_IN_SECONDS);
+    }
 }
@@ -3,7 +3,8 @@ use multiversx_sc::derive_imports::*;
 use multiversx_sc::imports::*;
 
 use crate::voting::{
-    FullProposalContext, Proposal, ProposalStatus, ProposalVoteCount, VoteContext,
+    FullProposalContext, Proposal, ProposalStatus, ProposalThresholds, ProposalVoteCount,
+    VoteContext, MAX_BPS,
 };
 
 #[type_abi]
@@ -24,6 +25,18 @@ pub struct ProposalContext<M: ManagedTypeApi> {
     pub proposal_vote_count: ProposalVoteCount<M>,
 }
 
+/// Votes cast on a proposal compared to the total voting power at its snapshot timestamp.
+/// The quorum and approval threshold are in voting power.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ProposalParticipation<M: ManagedTypeApi> {
+    pub total_voting_power: BigUint<M>,
+    pub total_votes: BigUint<M>,
+    pub participation_bps: u64,
+    pub quorum: BigUint<M>,
+    pub approval_threshold: BigUint<M>,
+}
+
 #[multiversx_sc::module]
 pub trait ViewsModule:
     crate::storage::StorageModule + crate::voting::VotingModule + crate::events::EventsModule
@@ -162,6 +175,43 @@ pub trait ViewsModule:
         self.get_proposal_vote_count(proposal_id)
     }
 
+    #[view(getProposalThresholds)]
+    fn get_proposal_thresholds_view(
+        &self,
+        proposal_id: u64,
+    ) -> OptionalValue<ProposalThresholds<Self::Api>> {
+        self.get_proposal_thresholds(proposal_id).into()
+    }
+
+    #[view(getProposalParticipation)]
+    fn get_proposal_participation(&self, proposal_id: u64) -> ProposalParticipation<Self::Api> {
+        self.require_proposal_exists(proposal_id);
+        // Proposals created without thresholds have no total voting power
+        let thresholds = self
+            .get_proposal_thresholds(proposal_id)
+            .unwrap_or(ProposalThresholds {
+                total_voting_power: BigUint::zero(),
+                quorum_bps: 0,
+                approval_threshold_bps: 0,
+            });
+        let total_votes = self.get_proposal_total_votes(proposal_id);
+        let participation_bps = if thresholds.total_voting_power == 0 {
+            0
+        } else {
+            (&total_votes * MAX_BPS / &thresholds.total_voting_power)
+                .to_u64()
+                .unwrap_or(u64::MAX)
+        };
+
+        ProposalParticipation {
+            quorum: thresholds.quorum(),
+            approval_threshold: thresholds.approval_threshold(),
+            total_voting_power: thresholds.total_voting_power,
+            total_votes,
+            participation_bps,
+        }
+    }
+
     #[view(getAllProposals)]
     fn get_all_proposals(
         &self,
@@ -3,6 +3,8 @@ use crate::errors::*;
 pub const DEFAULT_PROPOSAL_DURATION_IN_SECONDS: u64 = 60; // 24 * 3600; // Allow proposals to be active for 1 day by default
 pub const DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS: u64 = 60;//3600; // Start proposal 1 hour after creation by default
 pub const DIVISION_GUARD: u64 = 1000000000000000000; // 1e18
+pub const DEFAULT_MAX_OPEN_PROPOSALS_PER_CREATOR: usize = 1;
+pub const MAX_BPS: u64 = 10_000;
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -24,6 +26,17 @@ pub enum VoteDecision {
     Reject = 3,
 }
 
+impl VoteDecision {
+    pub fn from_u8(decision: u8) -> Self {
+        match decision {
+            1 => VoteDecision::Approve,
+            2 => VoteDecision::Abstain,
+            3 => VoteDecision::Reject,
+            _ => VoteDecision::Invalid,
+        }
+    }
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Debug)]
 pub enum ProposalStatus {
@@ -33,6 +46,21 @@ pub enum ProposalStatus {
     Approved = 3,
     Rejected = 4,
     Failed = 5,
+    Queued = 6,
+    Executable = 7,
+    Expired = 8,
+    Vetoed = 9,
+    Executed = 10,
+}
+
+/// How the votes of a proposal are counted.
+/// YesNo proposals are voted with a `VoteDecision`, the others by choosing among the proposal options.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VotingMethod {
+    YesNo,
+    Plurality,
+    RankedChoice,
 }
 
 #[type_abi]
@@ -58,6 +86,148 @@ pub struct VoteContext<M: ManagedTypeApi> {
     pub epoch: u64,
 }
 
+/// Total voting power at the proposal's snapshot timestamp, with the quorum and approval threshold
+/// in basis points of it, as set when the proposal was created
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ProposalThresholds<M: ManagedTypeApi> {
+    pub total_voting_power: BigUint<M>,
+    pub quorum_bps: u64,
+    pub approval_threshold_bps: u64,
+}
+
+impl<M: ManagedTypeApi> ProposalThresholds<M> {
+    pub fn quorum(&self) -> BigUint<M> {
+        &self.total_voting_power * self.quorum_bps / MAX_BPS
+    }
+
+    pub fn approval_threshold(&self) -> BigUint<M> {
+        &self.total_voting_power * self.approval_threshold_bps / MAX_BPS
+    }
+}
+
+/// Vote on a multiple choice proposal: option indexes by order of preference.
+/// Plurality ballots hold a single option.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct OptionBallot<M: ManagedTypeApi> {
+    pub voter: ManagedAddress<M>,
+    pub ranking: ManagedVec<M, u32>,
+    pub voting_power: BigUint<M>,
+    pub timestamp: u64,
+}
+
+/// Progress of the instant runoff count of a ranked choice proposal, kept between
+/// `countRankedChoiceVotes` calls
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct RankedChoiceCount<M: ManagedTypeApi> {
+    pub eliminated: ManagedVec<M, bool>,
+    pub round_votes: ManagedVec<M, BigUint<M>>,
+    pub counted_votes: BigUint<M>,
+    pub next_ballot_index: usize,
+}
+
+impl<M: ManagedTypeApi> RankedChoiceCount<M> {
+    pub fn new(option_count: usize) -> Self {
+        let mut eliminated = ManagedVec::new();
+        for _ in 0..option_count {
+            eliminated.push(false);
+        }
+
+        let mut count = RankedChoiceCount {
+            eliminated,
+            round_votes: ManagedVec::new(),
+            counted_votes: BigUint::zero(),
+            next_ballot_index: 1,
+        };
+        count.start_round();
+        count
+    }
+
+    /// Count the ballot for its preferred option still in the race
+    pub fn add_ballot(&mut self, ballot: &OptionBallot<M>) {
+        let preferred_option = ballot
+            .ranking
+            .iter()
+            .find(|option_index| !self.eliminated.get(*option_index as usize));
+        if let Some(option_index) = preferred_option {
+            let votes =
+                self.round_votes.get(option_index as usize).clone_value() + &ballot.voting_power;
+            let _ = self.round_votes.set(option_index as usize, votes);
+            self.counted_votes += &ballot.voting_power;
+        }
+
+        self.next_ballot_index += 1;
+    }
+
+    /// Once every ballot of the round is counted, returns the winning option (None on a tie)
+    /// if an option has a majority or no option can be eliminated anymore.
+    /// Otherwise eliminates the options with the fewest votes and starts the next round.
+    pub fn end_round(&mut self) -> Option<Option<u32>> {
+        let leading_option = get_leading_option(&self.round_votes, &self.eliminated);
+        if let Some(option_index) = leading_option {
+            let leading_votes = self.round_votes.get(option_index as usize).clone_value();
+            if leading_votes * 2u32 > self.counted_votes {
+                return Some(leading_option);
+            }
+        }
+
+        let option_count = self.eliminated.len();
+        let fewest_votes = (0..option_count)
+            .filter(|option_index| !self.eliminated.get(*option_index))
+            .map(|option_index| self.round_votes.get(option_index).clone_value())
+            .min();
+        let Some(fewest_votes) = fewest_votes else {
+            return Some(None);
+        };
+
+        let mut remaining_options = 0;
+        for option_index in 0..option_count {
+            if self.eliminated.get(option_index) {
+                continue;
+            }
+            if *self.round_votes.get(option_index) == fewest_votes {
+                let _ = self.eliminated.set(option_index, true);
+            } else {
+                remaining_options += 1;
+            }
+        }
+
+        // Every option left is tied
+        if remaining_options == 0 {
+            return Some(None);
+        }
+
+        self.start_round();
+        None
+    }
+
+    fn start_round(&mut self) {
+        self.round_votes = ManagedVec::new();
+        for _ in 0..self.eliminated.len() {
+            self.round_votes.push(BigUint::zero());
+        }
+        self.counted_votes = BigUint::zero();
+        self.next_ballot_index = 1;
+    }
+}
+
+/// Winning option of a counted ranked choice proposal, if any, and the votes of each option
+/// in the final counting round
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct RankedChoiceResult<M: ManagedTypeApi> {
+    pub winning_option: Option<u32>,
+    pub final_round_votes: ManagedVec<M, BigUint<M>>,
+}
+
+/// Voting power of a delegator that was cast by their delegate
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct DelegatedVote<M: ManagedTypeApi> {
+    pub delegate: ManagedAddress<M>,
+    pub voting_power: BigUint<M>,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
 pub struct ProposalVoteCount<M: ManagedTypeApi> {
@@ -79,7 +249,11 @@ pub struct FullProposalContext<M: ManagedTypeApi> {
 
 #[multiversx_sc::module]
 pub trait VotingModule: crate::storage::StorageModule + crate::events::EventsModule {
-    #[only_owner]
+    /// The owner can always create proposals and set the LP to $TRO ratios, which also become the current ones.
+    /// Anyone else needs at least `proposal_creation_threshold` voting power and must lock the $TRO
+    /// `proposal_deposit_amount` as payment. Their proposals use the current LP ratios, and their
+    /// `min_voting_power_to_validate_vote` cannot be below the floor set by the owner.
+    #[payable("*")]
     #[endpoint(createProposal)]
     #[allow_multiple_var_args]
     fn create_proposal(
@@ -91,6 +265,25 @@ pub trait VotingModule: crate::storage::StorageModule + crate::events::EventsMod
         end_time: OptionalValue<u64>,
         lp_to_tro_ratios: MultiValueEncoded<LpToTroRatio<Self::Api>>,
     ) {
+        self.create_new_proposal(
+            title,
+            description,
+            min_voting_power_to_validate_vote,
+            start_time,
+            end_time,
+            lp_to_tro_ratios,
+        );
+    }
+
+    fn create_new_proposal(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        min_voting_power_to_validate_vote: BigUint,
+        start_time: OptionalValue<u64>,
+        end_time: OptionalValue<u64>,
+        lp_to_tro_ratios: MultiValueEncoded<LpToTroRatio<Self::Api>>,
+    ) -> u64 {
         let proposal_id = self.get_new_proposal_id();
         let block_timestamp = self.blockchain().get_block_timestamp();
 
@@ -114,7 +307,31 @@ pub trait VotingModule: crate::storage::StorageModule + crate::events::EventsMod
             min_voting_power_to_validate_vote: min_voting_power_to_validate_vote.clone(),
         };
 
-        self.snapshot_lp_to_tro_ratio(proposal_id, lp_to_tro_ratios);
+        self.proposal_snapshot_timestamp(proposal_id)
+            .set(start_time);
+
+        let caller = self.blockchain().get_caller();
+        if caller == self.blockchain().get_owner_address() {
+            self.require_no_payment();
+            self.snapshot_lp_to_tro_ratio(proposal_id, lp_to_tro_ratios);
+        } else {
+            require!(lp_to_tro_ratios.is_empty(), ERR_LP_TO_TRO_RATIOS_OWNER_ONLY);
+            require!(
+                min_voting_power_to_validate_vote
+                    >= self.min_voting_power_to_validate_vote_floor().get(),
+                ERR_MIN_VOTING_POWER_TO_VALIDATE_VOTE_TOO_LOW
+            );
+            self.inherit_lp_to_tro_ratio(proposal_id);
+            self.require_can_create_proposal(&caller, proposal_id);
+            self.lock_proposal_deposit(proposal_id);
+            self.creator_open_proposals(&caller).insert(proposal_id);
+        }
+
+        // The thresholds are relative to the total stake, which is only reliable once reconciled
+        if self.total_staked_reconciled().get() {
+            self.proposal_threshold_bps(proposal_id)
+                .set((self.quorum_bps().get(), self.approval_threshold_bps().get()));
+        }
 
         self.proposals(proposal_id).set(proposal);
 
@@ -125,6 +342,41 @@ pub trait VotingModule: crate::storage::StorageModule + crate::events::EventsMod
             start_time,
             end_time,
         );
+
+        proposal_id
+    }
+
+    /// Once voting has ended, refund the proposal deposit to its creator if the proposal reached quorum,
+    /// or slash it to the treasury if it Failed. Can be called by anyone.
+    #[endpoint(settleProposalDeposit)]
+    fn settle_proposal_deposit(&self, proposal_id: u64) {
+        self.require_proposal_exists(proposal_id);
+        require!(
+            !self.proposal_deposit(proposal_id).is_empty(),
+            ERR_NO_PROPOSAL_DEPOSIT
+        );
+
+        let proposal = self.proposals(proposal_id).get();
+        require!(
+            self.blockchain().get_block_timestamp() > proposal.end_time,
+            ERR_PROPOSAL_VOTING_NOT_ENDED
+        );
+        self.require_option_votes_counted(proposal_id);
+
+        let deposit = self.proposal_deposit(proposal_id).take();
+        let slashed = self.get_proposal_vote_result(&proposal) == ProposalStatus::Failed;
+        let receiver = if slashed {
+            self.get_treasury_address()
+        } else {
+            proposal.creator.clone()
+        };
+
+        self.tx()
+            .to(&receiver)
+            .single_esdt(&self.tro_token_identifier().get(), 0, &deposit)
+            .transfer();
+
+        self.emit_proposal_deposit_settled_event(proposal_id, &receiver, &deposit, slashed);
     }
 
     #[endpoint(vote)]
@@ -132,8 +384,28 @@ pub trait VotingModule: crate::storage::StorageModule + crate::events::EventsMod
         let caller = self.blockchain().get_caller();
         self.require_proposal_exists(proposal_id);
         self.require_proposal_active(proposal_id);
+        self.require_voting_method(proposal_id, VotingMethod::YesNo);
         self.require_user_has_not_voted(&caller, proposal_id);
 
+        self.withdraw_delegated_vote(&caller, proposal_id);
+        self.process_vote(&caller, proposal_id, decision);
+        self.lock_stake_until_proposal_end(&caller, proposal_id);
+    }
+
+    /// Replace the caller's vote on an active proposal. The voting power is recomputed,
+    /// including the power of delegators who have not voted yet.
+    #[endpoint(changeVote)]
+    fn change_vote(&self, proposal_id: u64, decision: VoteDecision) {
+        let caller = self.blockchain().get_caller();
+        self.require_proposal_exists(proposal_id);
+        self.require_proposal_active(proposal_id);
+        self.require_voting_method(proposal_id, VotingMethod::YesNo);
+        require!(
+            !self.user_votes(&caller, proposal_id).is_empty(),
+            ERR_USER_HAS_NOT_VOTED
+        );
+
+        self.withdraw_vote(&caller, proposal_id);
         self.process_vote(&caller, proposal_id, decision);
     }
 
@@ -143,7 +415,9 @@ pub trait VotingModule: crate::storage::StorageModule + crate::events::EventsMod
         proposal_id: u64,
         decision: VoteDecision,
     ) {
-        let voting_power = self.get_voting_power(user, proposal_id);
+        self.cast_delegated_voting_power(user, proposal_id);
+        let voting_power = self.get_voting_power(user, proposal_id)
+            + self.delegated_voting_power_cast(user, proposal_id).get();
 
         require!(voting_power > 0, ERR_INSUFFICIENT_VOTING_POWER);
 
@@ -158,7 +432,67 @@ pub trait VotingModule: crate::storage::StorageModule + crate::events::EventsMod
             epoch: self.blockchain().get_block_epoch(),
         };
 
-        self.user_votes(user, proposal_id).set(vote_context);
+        self.user_vote_history(user, proposal_id)
+            .push(&vote_context);
+        self.user_votes(user, proposal_id).set(&vote_context);
+
+        self.emit_vote_event(user, proposal_id, vote_context);
+    }
+
+    /// Removes the user's current vote from the tally, so that it can be cast again
+    fn withdraw_vote(&self, user: &ManagedAddress, proposal_id: u64) {
+        let previous_vote = self.user_votes(user, proposal_id).get();
+        self.proposal_votes(proposal_id, &VoteDecision::from_u8(previous_vote.decision))
+            .update(|votes| *votes -= &previous_vote.voting_power);
+    }
+
+    /// Casts the voting power of the delegators who have not voted on the proposal yet.
+    /// Each delegator is counted at most once per proposal, even if they change delegate afterwards.
+    fn cast_delegated_voting_power(&self, delegate: &ManagedAddress, proposal_id: u64) {
+        for delegator in self.delegators(delegate).iter() {
+            if !self.user_votes(&delegator, proposal_id).is_empty()
+                || !self.delegated_votes(&delegator, proposal_id).is_empty()
+            {
+                continue;
+            }
+
+            let voting_power = self.get_voting_power(&delegator, proposal_id);
+            if voting_power == 0 {
+                continue;
+            }
+
+            self.delegated_voting_power_cast(delegate, proposal_id)
+                .update(|cast| *cast += &voting_power);
+            self.delegated_votes(&delegator, proposal_id)
+                .set(DelegatedVote {
+                    delegate: delegate.clone(),
+                    voting_power,
+                });
+        }
+    }
+
+    /// A delegator voting directly overrides the vote their delegate cast on their behalf
+    fn withdraw_delegated_vote(&self, delegator: &ManagedAddress, proposal_id: u64) {
+        if self.delegated_votes(delegator, proposal_id).is_empty() {
+            return;
+        }
+
+        let delegated_vote = self.delegated_votes(delegator, proposal_id).take();
+        let mut delegate_vote = self.user_votes(&delegated_vote.delegate, proposal_id).get();
+        self.proposal_votes(proposal_id, &VoteDecision::from_u8(delegate_vote.decision))
+            .update(|votes| *votes -= &delegated_vote.voting_power);
+        self.delegated_voting_power_cast(&delegated_vote.delegate, proposal_id)
+            .update(|cast| *cast -= &delegated_vote.voting_power);
+        delegate_vote.voting_power -= &delegated_vote.voting_power;
+        self.user_votes(&delegated_vote.delegate, proposal_id)
+            .set(delegate_vote);
+    }
+
+    /// Voters cannot unstake before the end of the proposals they voted on
+    fn lock_stake_until_proposal_end(&self, user: &ManagedAddress, proposal_id: u64) {
+        let end_time = self.proposals(proposal_id).get().end_time;
+        self.users_locked_until(user)
+            .update(|locked_until| *locked_until = (*locked_until).max(end_time));
     }
 
     fn get_proposal_vote_context(
@@ -181,10 +515,72 @@ pub trait VotingModule: crate::storage::StorageModule + crate::events::EventsMod
         for lp_to_tro_ratio in lp_to_tro_ratios {
             let (lp_token_id, tro_pool_supply, lp_pool_supply) = lp_to_tro_ratio.into_tuple();
             let ratio = tro_pool_supply * DIVISION_GUARD / lp_pool_supply;
+            self.current_lp_to_tro_ratio(&lp_token_id).set(&ratio);
             self.lp_to_tro_ratio(proposal_id, lp_token_id).set(ratio);
         }
     }
 
+    fn inherit_lp_to_tro_ratio(&self, proposal_id: u64) {
+        for lp_token in self.whitelisted_lp_token_identifiers().iter() {
+            let ratio = self.current_lp_to_tro_ratio(&lp_token).get();
+            self.lp_to_tro_ratio(proposal_id, lp_token).set(ratio);
+        }
+    }
+
+    fn require_can_create_proposal(&self, creator: &ManagedAddress, proposal_id: u64) {
+        let voting_power = self.get_voting_power(creator, proposal_id);
+        require!(
+            voting_power > 0 && voting_power >= self.proposal_creation_threshold().get(),
+            ERR_INSUFFICIENT_VOTING_POWER
+        );
+
+        // Proposals whose voting has ended no longer count towards the limit
+        let block_timestamp = self.blockchain().get_block_timestamp();
+        let mut open_proposals = self.creator_open_proposals(creator);
+        for open_proposal_id in open_proposals.iter().collect::<ManagedVec<u64>>().iter() {
+            if block_timestamp > self.proposals(open_proposal_id).get().end_time {
+                open_proposals.swap_remove(&open_proposal_id);
+            }
+        }
+
+        require!(
+            open_proposals.len() < self.max_open_proposals_per_creator().get(),
+            ERR_TOO_MANY_OPEN_PROPOSALS
+        );
+    }
+
+    fn lock_proposal_deposit(&self, proposal_id: u64) {
+        let deposit_amount = self.proposal_deposit_amount().get();
+        if deposit_amount == 0 {
+            self.require_no_payment();
+            return;
+        }
+
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.tro_token_identifier().get()
+                && payment.amount == deposit_amount,
+            ERR_INVALID_PROPOSAL_DEPOSIT
+        );
+
+        self.proposal_deposit(proposal_id).set(deposit_amount);
+    }
+
+    fn require_no_payment(&self) {
+        require!(
+            self.call_value().all_transfers().is_empty(),
+            ERR_UNEXPECTED_PAYMENT
+        );
+    }
+
+    fn get_treasury_address(&self) -> ManagedAddress {
+        if self.treasury_address().is_empty() {
+            return self.blockchain().get_owner_address();
+        }
+
+        self.treasury_address().get()
+    }
+
     fn get_proposal_status(
         &self,
         proposal: &Proposal<Self::Api>,
@@ -193,33 +589,196 @@ pub trait VotingModule: crate::storage::StorageModule + crate::events::EventsMod
         if block_timestamp < proposal.start_time {
             ProposalStatus::Pending
         } else if block_timestamp > proposal.end_time {
-            self.get_proposal_vote_result(proposal)
+            match self.get_proposal_vote_result(proposal) {
+                ProposalStatus::Approved => {
+                    self.get_approved_proposal_status(proposal.id, block_timestamp)
+                }
+                vote_result => vote_result,
+            }
         } else {
             ProposalStatus::Active
         }
     }
 
+    /// Approved proposals with actions go through the timelock once queued:
+    /// Queued until the delay has passed, then Executable until the grace period ends, then Expired.
+    fn get_approved_proposal_status(
+        &self,
+        proposal_id: u64,
+        block_timestamp: u64,
+    ) -> ProposalStatus {
+        if self.proposal_vetoed(proposal_id).get() {
+            ProposalStatus::Vetoed
+        } else if self.proposal_executed(proposal_id).get() {
+            ProposalStatus::Executed
+        } else if self.proposal_executable_at(proposal_id).is_empty() {
+            ProposalStatus::Approved
+        } else if block_timestamp < self.proposal_executable_at(proposal_id).get() {
+            ProposalStatus::Queued
+        } else if block_timestamp < self.proposal_expires_at(proposal_id).get() {
+            ProposalStatus::Executable
+        } else {
+            ProposalStatus::Expired
+        }
+    }
+
     fn get_proposal_vote_result(&self, proposal: &Proposal<Self::Api>) -> ProposalStatus {
+        if self.proposal_voting_method(proposal.id).get() != VotingMethod::YesNo {
+            return self.get_option_proposal_vote_result(proposal);
+        }
+
         let approve_votes = self
             .proposal_votes(proposal.id, &VoteDecision::Approve)
             .get();
         let reject_votes = self
             .proposal_votes(proposal.id, &VoteDecision::Reject)
             .get();
-        let abstain_votes = self
-            .proposal_votes(proposal.id, &VoteDecision::Abstain)
-            .get();
 
-        let total_votes = &approve_votes + &reject_votes + &abstain_votes;
+        if !self.has_reached_quorum(proposal) {
+            ProposalStatus::Failed
+        } else if approve_votes > reject_votes
+            && self.has_reached_approval_threshold(proposal.id, &approve_votes)
+        {
+            ProposalStatus::Approved
+        } else {
+            ProposalStatus::Rejected
+        }
+    }
 
-        if total_votes >= proposal.min_voting_power_to_validate_vote {
-            if approve_votes > reject_votes {
+    /// Multiple choice proposals are Approved when an option wins, and Rejected on a tie
+    fn get_option_proposal_vote_result(&self, proposal: &Proposal<Self::Api>) -> ProposalStatus {
+        if !self.has_reached_quorum(proposal) {
+            return ProposalStatus::Failed;
+        }
+
+        let (winning_option, final_round_votes) = self.count_option_votes(proposal.id);
+        match winning_option {
+            Some(option_index)
+                if self.has_reached_approval_threshold(
+                    proposal.id,
+                    &final_round_votes.get(option_index as usize),
+                ) =>
+            {
                 ProposalStatus::Approved
-            } else {
-                ProposalStatus::Rejected
+            }
+            _ => ProposalStatus::Rejected,
+        }
+    }
+
+    fn has_reached_quorum(&self, proposal: &Proposal<Self::Api>) -> bool {
+        let total_votes = self.get_proposal_total_votes(proposal.id);
+        if total_votes < proposal.min_voting_power_to_validate_vote {
+            return false;
+        }
+
+        // Proposals created without thresholds only use the absolute minimum
+        match self.get_proposal_thresholds(proposal.id) {
+            Some(thresholds) => total_votes >= thresholds.quorum(),
+            None => true,
+        }
+    }
+
+    fn has_reached_approval_threshold(&self, proposal_id: u64, approve_votes: &BigUint) -> bool {
+        match self.get_proposal_thresholds(proposal_id) {
+            Some(thresholds) => *approve_votes >= thresholds.approval_threshold(),
+            None => true,
+        }
+    }
+
+    /// None for the proposals created before the thresholds were recorded,
+    /// or while the total stake was not reconciled
+    fn get_proposal_thresholds(&self, proposal_id: u64) -> Option<ProposalThresholds<Self::Api>> {
+        let threshold_bps = self.proposal_threshold_bps(proposal_id);
+        if threshold_bps.is_empty() {
+            return None;
+        }
+
+        let (quorum_bps, approval_threshold_bps) = threshold_bps.get();
+        Some(ProposalThresholds {
+            total_voting_power: self.get_total_voting_power(proposal_id),
+            quorum_bps,
+            approval_threshold_bps,
+        })
+    }
+
+    /// Voting power cast on the proposal, abstentions included
+    fn get_proposal_total_votes(&self, proposal_id: u64) -> BigUint {
+        let mut total_votes = BigUint::zero();
+        if self.proposal_voting_method(proposal_id).get() == VotingMethod::YesNo {
+            for decision in [
+                VoteDecision::Approve,
+                VoteDecision::Abstain,
+                VoteDecision::Reject,
+            ] {
+                total_votes += self.proposal_votes(proposal_id, &decision).get();
             }
         } else {
-            ProposalStatus::Failed
+            for option_index in 0..self.proposal_options(proposal_id).len() as u32 {
+                total_votes += self.option_votes(proposal_id, option_index).get();
+            }
+        }
+
+        total_votes
+    }
+
+    /// Voting power of all the stake held before the proposal's snapshot timestamp,
+    /// using the LP to $TRO ratios of the proposal, like the voting power of each voter
+    fn get_total_voting_power(&self, proposal_id: u64) -> BigUint {
+        let snapshot_timestamp = self.proposal_snapshot_timestamp(proposal_id).get();
+        let mut total_voting_power =
+            self.get_total_staked_before(&self.tro_token_identifier().get(), snapshot_timestamp);
+        for lp_token in self.whitelisted_lp_token_identifiers().iter() {
+            let total_staked_lp = self.get_total_staked_before(&lp_token, snapshot_timestamp);
+            let lp_to_tro_ratio = self.lp_to_tro_ratio(proposal_id, lp_token).get();
+            total_voting_power += total_staked_lp * lp_to_tro_ratio / DIVISION_GUARD;
+        }
+
+        total_voting_power
+    }
+
+    /// Winning option of a multiple choice proposal, if any, and the votes of each option
+    /// in the final counting round
+    fn count_option_votes(&self, proposal_id: u64) -> (Option<u32>, ManagedVec<BigUint>) {
+        let option_count = self.proposal_options(proposal_id).len();
+        if self.proposal_voting_method(proposal_id).get() != VotingMethod::RankedChoice {
+            let mut eliminated = ManagedVec::<Self::Api, bool>::new();
+            let mut votes = ManagedVec::new();
+            for option_index in 0..option_count as u32 {
+                eliminated.push(false);
+                votes.push(self.option_votes(proposal_id, option_index).get());
+            }
+            return (get_leading_option(&votes, &eliminated), votes);
+        }
+
+        let result_mapper = self.ranked_choice_result(proposal_id);
+        if !result_mapper.is_empty() {
+            let result = result_mapper.get();
+            return (result.winning_option, result.final_round_votes);
+        }
+
+        // Instant runoff: the options with the fewest votes are eliminated until one has a majority.
+        // Endpoints only act on the result stored by `countRankedChoiceVotes`, see
+        // `require_option_votes_counted`, so reading every ballot each round stays off-chain.
+        let mut count = RankedChoiceCount::new(option_count);
+        loop {
+            for ballot in self.option_ballots(proposal_id).iter() {
+                count.add_ballot(&ballot);
+            }
+
+            if let Some(winning_option) = count.end_round() {
+                return (winning_option, count.round_votes);
+            }
+        }
+    }
+
+    /// Ranked choice proposals need their votes counted by `countRankedChoiceVotes`
+    /// before their outcome is acted on
+    fn require_option_votes_counted(&self, proposal_id: u64) {
+        if self.proposal_voting_method(proposal_id).get() == VotingMethod::RankedChoice {
+            require!(
+                !self.ranked_choice_result(proposal_id).is_empty(),
+                ERR_RANKED_CHOICE_VOTES_NOT_COUNTED
+            );
         }
     }
 
@@ -230,14 +789,15 @@ pub trait VotingModule: crate::storage::StorageModule + crate::events::EventsMod
         new_proposal_id
     }
 
+    /// Voting power from the stake held before the proposal's snapshot timestamp (its start time),
+    /// so that tokens moved to another wallet once voting started cannot be counted twice.
     fn get_voting_power(&self, user: &ManagedAddress, proposal_id: u64) -> BigUint<Self::Api> {
-        let tro_staked = self
-            .users_stake(user, &self.tro_token_identifier().get())
-            .get();
+        let tro_staked =
+            self.get_user_stake_for_proposal(user, &self.tro_token_identifier().get(), proposal_id);
         let mut lp_voting_power = BigUint::zero();
 
         for lp_token in self.whitelisted_lp_token_identifiers().iter() {
-            let staked_lp_balance = self.users_stake(user, &lp_token).get();
+            let staked_lp_balance = self.get_user_stake_for_proposal(user, &lp_token, proposal_id);
             let lp_to_tro_ratio = self.lp_to_tro_ratio(proposal_id, lp_token).get();
             let tro_equivalent = staked_lp_balance * lp_to_tro_ratio / DIVISION_GUARD;
 
@@ -247,6 +807,74 @@ pub trait VotingModule: crate::storage::StorageModule + crate::events::EventsMod
         lp_voting_power + tro_staked
     }
 
+    fn get_user_stake_for_proposal(
+        &self,
+        user: &ManagedAddress,
+        token_identifier: &TokenIdentifier,
+        proposal_id: u64,
+    ) -> BigUint {
+        let snapshot_timestamp = self.proposal_snapshot_timestamp(proposal_id);
+        if snapshot_timestamp.is_empty() {
+            // Proposals created before checkpoints were recorded use the live stake
+            return self.users_stake(user, token_identifier).get();
+        }
+
+        self.get_user_stake_before(user, token_identifier, snapshot_timestamp.get())
+    }
+
+    /// Staked balance of the user at the end of the last block before `timestamp`.
+    #[view(getUserStakeBefore)]
+    fn get_user_stake_before(
+        &self,
+        user: &ManagedAddress,
+        token_identifier: &TokenIdentifier,
+        timestamp: u64,
+    ) -> BigUint {
+        let checkpoints = self.users_stake_checkpoints(user, token_identifier);
+        if checkpoints.is_empty() {
+            // The stake has not changed since before checkpoints were recorded
+            return self.users_stake(user, token_identifier).get();
+        }
+
+        self.get_checkpoint_balance_before(&checkpoints, timestamp)
+    }
+
+    /// Total stake at the end of the last block before `timestamp`
+    fn get_total_staked_before(
+        &self,
+        token_identifier: &TokenIdentifier,
+        timestamp: u64,
+    ) -> BigUint {
+        let checkpoints = self.total_staked_checkpoints(token_identifier);
+        if checkpoints.is_empty() {
+            return self.total_staked(token_identifier).get();
+        }
+
+        self.get_checkpoint_balance_before(&checkpoints, timestamp)
+    }
+
+    fn get_checkpoint_balance_before(
+        &self,
+        checkpoints: &VecMapper<(u64, BigUint)>,
+        timestamp: u64,
+    ) -> BigUint {
+        let mut balance = BigUint::zero();
+        let mut low = 1;
+        let mut high = checkpoints.len();
+        while low <= high {
+            let middle = (low + high) / 2;
+            let (checkpoint_timestamp, checkpoint_balance) = checkpoints.get(middle);
+            if checkpoint_timestamp < timestamp {
+                balance = checkpoint_balance;
+                low = middle + 1;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        balance
+    }
+
     fn require_time_range_is_valid(&self, start_time: u64, end_time: u64) {
         let current_timestamp = self.blockchain().get_block_timestamp();
 
@@ -279,20 +907,10 @@ pub trait VotingModule: crate::storage::StorageModule + crate::events::EventsMod
         );
     }
 
-    fn require_no_proposal_ongoing(&self) {
-        let last_proposal = self.last_proposal_id().get();
-        if last_proposal == 0 {
-            return;
-        }
-
-        let last_proposal_status = self.get_proposal_status(
-            &self.proposals(last_proposal).get(),
-            self.blockchain().get_block_timestamp(),
-        );
-
+    fn require_voting_method(&self, proposal_id: u64, voting_method: VotingMethod) {
         require!(
-            last_proposal_status != ProposalStatus::Active,
-            ERR_PROPOSAL_ACTIVE
+            self.proposal_voting_method(proposal_id).get() == voting_method,
+            ERR_INVALID_VOTING_METHOD
         );
     }
 
@@ -345,6 +963,91 @@ pub trait VotingModule: crate::storage::StorageModule + crate::events::EventsMod
         proposal_id: u64,
     ) -> SingleValueMapper<VoteContext<Self::Api>>;
 
+    /// $TRO locked by the creator of a proposal, until it is settled
+    #[view(getProposalDeposit)]
+    #[storage_mapper("proposal_deposit")]
+    fn proposal_deposit(&self, proposal_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[view(getCreatorOpenProposals)]
+    #[storage_mapper("creator_open_proposals")]
+    fn creator_open_proposals(&self, creator: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    /// Every vote and vote change of the user on the proposal, oldest first
+    #[view(getUserVoteHistory)]
+    #[storage_mapper("user_vote_history")]
+    fn user_vote_history(
+        &self,
+        user: &ManagedAddress,
+        proposal_id: u64,
+    ) -> VecMapper<VoteContext<Self::Api>>;
+
+    /// Voting power of the delegators currently cast by the delegate on the proposal
+    #[storage_mapper("delegated_voting_power_cast")]
+    fn delegated_voting_power_cast(
+        &self,
+        delegate: &ManagedAddress,
+        proposal_id: u64,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(getDelegatedVote)]
+    #[storage_mapper("delegated_votes")]
+    fn delegated_votes(
+        &self,
+        delegator: &ManagedAddress,
+        proposal_id: u64,
+    ) -> SingleValueMapper<DelegatedVote<Self::Api>>;
+
+    /// Voting power is computed from the stake held before this timestamp
+    #[view(getProposalSnapshotTimestamp)]
+    #[storage_mapper("proposal_snapshot_timestamp")]
+    fn proposal_snapshot_timestamp(&self, proposal_id: u64) -> SingleValueMapper<u64>;
+
+    /// Quorum and approval threshold of the proposal, in basis points
+    #[storage_mapper("proposal_threshold_bps")]
+    fn proposal_threshold_bps(&self, proposal_id: u64) -> SingleValueMapper<(u64, u64)>;
+
+    /// Empty for YesNo proposals
+    #[view(getProposalVotingMethod)]
+    #[storage_mapper("proposal_voting_method")]
+    fn proposal_voting_method(&self, proposal_id: u64) -> SingleValueMapper<VotingMethod>;
+
+    #[view(getProposalOptions)]
+    #[storage_mapper("proposal_options")]
+    fn proposal_options(&self, proposal_id: u64) -> VecMapper<ManagedBuffer>;
+
+    /// Voting power of the ballots ranking the option first
+    #[view(getOptionVotes)]
+    #[storage_mapper("option_votes")]
+    fn option_votes(&self, proposal_id: u64, option_index: u32) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("option_ballots")]
+    fn option_ballots(&self, proposal_id: u64) -> VecMapper<OptionBallot<Self::Api>>;
+
+    #[storage_mapper("ranked_choice_count")]
+    fn ranked_choice_count(
+        &self,
+        proposal_id: u64,
+    ) -> SingleValueMapper<RankedChoiceCount<Self::Api>>;
+
+    #[storage_mapper("ranked_choice_result")]
+    fn ranked_choice_result(
+        &self,
+        proposal_id: u64,
+    ) -> SingleValueMapper<RankedChoiceResult<Self::Api>>;
+
+    /// Index of the user's ballot in `option_ballots`
+    #[storage_mapper("user_option_ballot")]
+    fn user_option_ballot(
+        &self,
+        user: &ManagedAddress,
+        proposal_id: u64,
+    ) -> SingleValueMapper<usize>;
+
+    /// LP to $TRO ratios used by the proposals created by anyone but the owner
+    #[view(getCurrentLpToTroRatio)]
+    #[storage_mapper("current_lp_to_tro_ratio")]
+    fn current_lp_to_tro_ratio(&self, lp_token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
     #[view(getLpToTroRatio)]
     #[storage_mapper("lp_to_tro_ratio")]
     fn lp_to_tro_ratio(
@@ -353,3 +1056,26 @@ pub trait VotingModule: crate::storage::StorageModule + crate::events::EventsMod
         lp_token: TokenIdentifier,
     ) -> SingleValueMapper<BigUint>;
 }
+
+/// Option with the most votes among those not eliminated, None if there is a tie or no votes
+fn get_leading_option<M: ManagedTypeApi>(
+    votes: &ManagedVec<M, BigUint<M>>,
+    eliminated: &ManagedVec<M, bool>,
+) -> Option<u32> {
+    let mut leading_option = None;
+    let mut leading_votes = BigUint::zero();
+    for (option_index, option_votes) in votes.iter().enumerate() {
+        if eliminated.get(option_index) {
+            continue;
+        }
+
+        if *option_votes > leading_votes {
+            leading_option = Some(option_index as u32);
+            leading_votes = option_votes.clone_value();
+        } else if *option_votes == leading_votes {
+            leading_option = None;
+        }
+    }
+
+    leading_option
+}
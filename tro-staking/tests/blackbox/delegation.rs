@@ -0,0 +1,453 @@
+use multiversx_sc_scenario::imports::*;
+use tro_staking::{
+    admin::MAX_WHITELISTED_LP_TOKENS,
+    delegation::MAX_DELEGATORS_PER_DELEGATE,
+    errors::*,
+    proxy::VoteDecision,
+    voting::{DEFAULT_PROPOSAL_DURATION_IN_SECONDS, DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS},
+};
+
+use crate::config::*;
+
+use super::test_setup::setup_world_with_contract;
+
+const SECOND_DELEGATE_ADDRESS: TestAddress = TestAddress::new("second-delegate");
+const OWNER_STAKE: u64 = 500;
+const USER_LP_STAKE: u64 = 1000;
+// LP_TOKEN_ID_1 is worth 2 $TRO in the proposal
+const USER_VOTING_POWER: u64 = USER_LP_STAKE * 2;
+const PROPOSAL_START_TIME: u64 = DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + 1;
+const LP_TOKEN_ID_4: TestTokenIdentifier = TestTokenIdentifier::new("LPTOKEN4");
+const LP_TOKEN_ID_5: TestTokenIdentifier = TestTokenIdentifier::new("LPTOKEN5");
+const DELEGATOR_STAKE: u64 = 10;
+
+#[test]
+fn delegate_should_vote_with_delegated_voting_power() {
+    let mut world = setup_world_with_contract();
+    setup_delegation(&mut world);
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .user_delegate(USER_ADDRESS)
+        .returns(ExpectValue(OWNER_ADDRESS))
+        .run();
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_delegated_voting_power(OWNER_ADDRESS, 1u64)
+        .returns(ExpectValue(BigUint::from(USER_VOTING_POWER)))
+        .run();
+
+    vote(&mut world, OWNER_ADDRESS, VoteDecision::Approve);
+
+    check_votes(
+        &mut world,
+        VoteDecision::Approve,
+        OWNER_STAKE + USER_VOTING_POWER,
+    );
+}
+
+#[test]
+fn delegator_voting_directly_should_override_delegate() {
+    let mut world = setup_delegation_and_vote();
+
+    vote(&mut world, USER_ADDRESS, VoteDecision::Reject);
+
+    check_votes(&mut world, VoteDecision::Approve, OWNER_STAKE);
+    check_votes(&mut world, VoteDecision::Reject, USER_VOTING_POWER);
+}
+
+#[test]
+fn delegate_should_not_vote_for_delegator_who_already_voted() {
+    let mut world = setup_world_with_contract();
+    setup_delegation(&mut world);
+
+    vote(&mut world, USER_ADDRESS, VoteDecision::Reject);
+    vote(&mut world, OWNER_ADDRESS, VoteDecision::Approve);
+
+    check_votes(&mut world, VoteDecision::Approve, OWNER_STAKE);
+    check_votes(&mut world, VoteDecision::Reject, USER_VOTING_POWER);
+}
+
+#[test]
+fn delegate_changing_vote_should_keep_delegated_voting_power() {
+    let mut world = setup_delegation_and_vote();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .change_vote(1u64, VoteDecision::Reject)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    check_votes(&mut world, VoteDecision::Approve, 0);
+    check_votes(
+        &mut world,
+        VoteDecision::Reject,
+        OWNER_STAKE + USER_VOTING_POWER,
+    );
+
+    // the delegator overriding the delegate after the change removes the power from the new decision
+    vote(&mut world, USER_ADDRESS, VoteDecision::Approve);
+    check_votes(&mut world, VoteDecision::Approve, USER_VOTING_POWER);
+    check_votes(&mut world, VoteDecision::Reject, OWNER_STAKE);
+}
+
+#[test]
+fn changing_delegate_should_not_count_voting_power_twice() {
+    let mut world = setup_delegation_and_vote();
+
+    world.account(SECOND_DELEGATE_ADDRESS).nonce(1);
+    delegate(&mut world, SECOND_DELEGATE_ADDRESS);
+
+    // the second delegate has no power of its own and the delegated power was already cast
+    world
+        .tx()
+        .from(SECOND_DELEGATE_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .vote(1u64, VoteDecision::Reject)
+        .returns(ExpectMessage(ERR_INSUFFICIENT_VOTING_POWER))
+        .run();
+
+    check_votes(
+        &mut world,
+        VoteDecision::Approve,
+        OWNER_STAKE + USER_VOTING_POWER,
+    );
+}
+
+#[test]
+fn revoked_delegation_should_not_be_counted() {
+    let mut world = setup_world_with_contract();
+    setup_delegation(&mut world);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .revoke_delegation()
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    vote(&mut world, OWNER_ADDRESS, VoteDecision::Approve);
+    check_votes(&mut world, VoteDecision::Approve, OWNER_STAKE);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .revoke_delegation()
+        .returns(ExpectMessage(ERR_NO_DELEGATION))
+        .run();
+}
+
+#[test]
+fn delegate_should_remove_delegator() {
+    let mut world = setup_world_with_contract();
+    setup_delegation(&mut world);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .remove_delegator(USER_ADDRESS)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    vote(&mut world, OWNER_ADDRESS, VoteDecision::Approve);
+    check_votes(&mut world, VoteDecision::Approve, OWNER_STAKE);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .remove_delegator(USER_ADDRESS)
+        .returns(ExpectMessage(ERR_NOT_A_DELEGATOR))
+        .run();
+}
+
+#[test]
+fn delegating_below_min_stake_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .set_min_stake_to_delegate(BigUint::from(OWNER_STAKE))
+        .returns(ExpectStatus(0u64))
+        .run();
+    stake(&mut world, USER_ADDRESS, TRO_TOKEN_ID, OWNER_STAKE - 1);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .delegate(OWNER_ADDRESS)
+        .returns(ExpectMessage(ERR_INSUFFICIENT_STAKE_TO_DELEGATE))
+        .run();
+
+    stake(&mut world, USER_ADDRESS, TRO_TOKEN_ID, 1);
+    delegate(&mut world, OWNER_ADDRESS);
+}
+
+#[test]
+fn delegating_to_self_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .delegate(USER_ADDRESS)
+        .returns(ExpectMessage(ERR_INVALID_DELEGATE))
+        .run();
+}
+
+#[test]
+fn whitelisting_too_many_lp_tokens_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .add_whitelisted_lp_tokens(MultiValueEncoded::from_iter([
+            LP_TOKEN_ID_4.to_token_identifier(),
+            LP_TOKEN_ID_5.to_token_identifier(),
+            UNSUPPORTED_LP_TOKEN_ID.to_token_identifier(),
+        ]))
+        .returns(ExpectMessage(ERR_TOO_MANY_WHITELISTED_LP_TOKENS))
+        .run();
+}
+
+/// Worst case for `vote`: the most delegators, each staking $TRO and every whitelisted LP token.
+#[test]
+fn delegate_should_vote_with_max_delegators_and_lp_tokens() {
+    let mut world = setup_world_with_contract();
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .add_whitelisted_lp_tokens(MultiValueEncoded::from_iter([
+            LP_TOKEN_ID_4.to_token_identifier(),
+            LP_TOKEN_ID_5.to_token_identifier(),
+        ]))
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    let lp_token_ids = [
+        LP_TOKEN_ID_1,
+        LP_TOKEN_ID_2,
+        LP_TOKEN_ID_3,
+        LP_TOKEN_ID_4,
+        LP_TOKEN_ID_5,
+    ];
+    assert_eq!(lp_token_ids.len(), MAX_WHITELISTED_LP_TOKENS);
+
+    stake(&mut world, OWNER_ADDRESS, TRO_TOKEN_ID, OWNER_STAKE);
+    for index in 0..=MAX_DELEGATORS_PER_DELEGATE {
+        let mut address_bytes = [0u8; 32];
+        address_bytes[..8].copy_from_slice(&(index as u64 + 1).to_be_bytes());
+        let delegator = Address::from(address_bytes);
+
+        {
+            let mut account = world
+                .account(&delegator)
+                .nonce(1)
+                .esdt_balance(TRO_TOKEN_ID, DELEGATOR_STAKE);
+            for lp_token_id in lp_token_ids.iter() {
+                account = account.esdt_balance(*lp_token_id, DELEGATOR_STAKE);
+            }
+        }
+
+        let mut payments = MultiEsdtPayment::new();
+        payments.push(EsdtTokenPayment::new(
+            TRO_TOKEN_ID.to_token_identifier(),
+            0,
+            BigUint::from(DELEGATOR_STAKE),
+        ));
+        for lp_token_id in lp_token_ids.iter() {
+            payments.push(EsdtTokenPayment::new(
+                lp_token_id.to_token_identifier(),
+                0,
+                BigUint::from(DELEGATOR_STAKE),
+            ));
+        }
+        world
+            .tx()
+            .from(&delegator)
+            .to(SC_ADDRESS)
+            .typed(tro_staking::proxy::TroStakingProxy)
+            .stake()
+            .payment(payments)
+            .returns(ExpectStatus(0u64))
+            .run();
+
+        if index < MAX_DELEGATORS_PER_DELEGATE {
+            world
+                .tx()
+                .from(&delegator)
+                .to(SC_ADDRESS)
+                .typed(tro_staking::proxy::TroStakingProxy)
+                .delegate(OWNER_ADDRESS)
+                .returns(ExpectStatus(0u64))
+                .run();
+        } else {
+            world
+                .tx()
+                .from(&delegator)
+                .to(SC_ADDRESS)
+                .typed(tro_staking::proxy::TroStakingProxy)
+                .delegate(OWNER_ADDRESS)
+                .returns(ExpectMessage(ERR_TOO_MANY_DELEGATORS))
+                .run();
+        }
+    }
+
+    let mut lp_to_tro_ratios = MultiValueEncoded::new();
+    for lp_token_id in lp_token_ids.iter() {
+        lp_to_tro_ratios.push(MultiValue3((
+            lp_token_id.to_token_identifier(),
+            BigUint::from(2000u64),
+            BigUint::from(1000u64),
+        )));
+    }
+
+    world.set_state_step(SetStateStep::new().block_timestamp(1));
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .create_proposal(
+            ManagedBuffer::from("Test Proposal"),
+            ManagedBuffer::from("This is a test proposal"),
+            BigUint::from(OWNER_STAKE),
+            OptionalValue::Some(PROPOSAL_START_TIME),
+            OptionalValue::Some(PROPOSAL_START_TIME + DEFAULT_PROPOSAL_DURATION_IN_SECONDS),
+            lp_to_tro_ratios,
+        )
+        .returns(ExpectStatus(0u64))
+        .run();
+    world.set_state_step(SetStateStep::new().block_timestamp(PROPOSAL_START_TIME));
+
+    vote(&mut world, OWNER_ADDRESS, VoteDecision::Approve);
+
+    // each LP token is worth 2 $TRO in the proposal
+    let delegator_voting_power = DELEGATOR_STAKE + DELEGATOR_STAKE * 2 * lp_token_ids.len() as u64;
+    check_votes(
+        &mut world,
+        VoteDecision::Approve,
+        OWNER_STAKE + delegator_voting_power * MAX_DELEGATORS_PER_DELEGATE as u64,
+    );
+}
+
+fn setup_delegation_and_vote() -> ScenarioWorld {
+    let mut world = setup_world_with_contract();
+    setup_delegation(&mut world);
+    vote(&mut world, OWNER_ADDRESS, VoteDecision::Approve);
+
+    world
+}
+
+/// The user stakes LP tokens and delegates to the owner, then the proposal starts.
+fn setup_delegation(world: &mut ScenarioWorld) {
+    stake(world, OWNER_ADDRESS, TRO_TOKEN_ID, OWNER_STAKE);
+    stake(world, USER_ADDRESS, LP_TOKEN_ID_1, USER_LP_STAKE);
+    delegate(world, OWNER_ADDRESS);
+
+    let mut lp_to_tro_ratios = MultiValueEncoded::new();
+    lp_to_tro_ratios.push(MultiValue3((
+        LP_TOKEN_ID_1.to_token_identifier(),
+        BigUint::from(2000u64),
+        BigUint::from(1000u64),
+    )));
+
+    world.set_state_step(SetStateStep::new().block_timestamp(1));
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .create_proposal(
+            ManagedBuffer::from("Test Proposal"),
+            ManagedBuffer::from("This is a test proposal"),
+            BigUint::from(OWNER_STAKE),
+            OptionalValue::Some(PROPOSAL_START_TIME),
+            OptionalValue::Some(PROPOSAL_START_TIME + DEFAULT_PROPOSAL_DURATION_IN_SECONDS),
+            lp_to_tro_ratios,
+        )
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world.set_state_step(SetStateStep::new().block_timestamp(PROPOSAL_START_TIME));
+}
+
+fn stake(
+    world: &mut ScenarioWorld,
+    address: TestAddress,
+    token_id: TestTokenIdentifier,
+    amount: u64,
+) {
+    world
+        .tx()
+        .from(address)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .stake()
+        .payment(EsdtTokenPayment::new(
+            token_id.to_token_identifier(),
+            0,
+            BigUint::from(amount),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn delegate(world: &mut ScenarioWorld, delegate: TestAddress) {
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .delegate(delegate)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn vote(world: &mut ScenarioWorld, address: TestAddress, decision: VoteDecision) {
+    world
+        .tx()
+        .from(address)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .vote(1u64, decision)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn check_votes(world: &mut ScenarioWorld, decision: VoteDecision, expected_votes: u64) {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .proposal_votes(1u64, decision)
+        .returns(ExpectValue(BigUint::from(expected_votes)))
+        .run();
+}
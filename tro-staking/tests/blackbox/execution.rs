@@ -0,0 +1,456 @@
+use multiversx_sc_scenario::imports::*;
+use tro_staking::{
+    errors::*,
+    execution::{DEFAULT_EXECUTION_GRACE_PERIOD_IN_SECONDS, DEFAULT_TIMELOCK_DELAY_IN_SECONDS},
+    proxy::{ActionCallType, ActionStatus, ProposalAction, ProposalStatus, VoteDecision},
+    voting::{DEFAULT_PROPOSAL_DURATION_IN_SECONDS, DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS},
+};
+
+use crate::config::*;
+
+use super::test_setup::setup_world_with_contract;
+
+const TARGET_SC_ADDRESS: TestSCAddress = TestSCAddress::new("target");
+const ACTION_GAS_LIMIT: u64 = 10_000_000;
+const MIN_VOTING_POWER_TO_VALIDATE_VOTE: u64 = 1000;
+const VOTING_END_TIMESTAMP: u64 =
+    DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + DEFAULT_PROPOSAL_DURATION_IN_SECONDS + 1;
+const EXECUTABLE_AT: u64 = VOTING_END_TIMESTAMP + DEFAULT_TIMELOCK_DELAY_IN_SECONDS;
+
+#[test]
+fn executing_approved_proposal_should_run_sync_actions() {
+    let mut world = setup_world_with_contract();
+    deploy_target_contract(&mut world);
+
+    create_proposal(&mut world);
+    add_proposal_actions(&mut world, whitelist_lp_token_action(ActionCallType::Sync));
+    approve_proposal(&mut world);
+    queue_proposal(&mut world);
+
+    execute_proposal(&mut world);
+
+    world
+        .query()
+        .to(TARGET_SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .whitelisted_lp_token_identifiers()
+        .returns(ExpectValue(MultiValueVec::from(vec![
+            UNSUPPORTED_LP_TOKEN_ID.to_token_identifier(),
+        ])))
+        .run();
+    check_action_statuses(&mut world, &[ActionStatus::Succeeded]);
+}
+
+#[test]
+fn executing_approved_proposal_should_record_async_action_results() {
+    let mut world = setup_world_with_contract();
+    deploy_target_contract(&mut world);
+
+    create_proposal(&mut world);
+    let mut failing_action = whitelist_lp_token_action(ActionCallType::Async);
+    failing_action.endpoint_name = ManagedBuffer::from("missingEndpoint");
+    add_proposal_actions(&mut world, whitelist_lp_token_action(ActionCallType::Async));
+    add_proposal_actions(&mut world, failing_action);
+    approve_proposal(&mut world);
+    queue_proposal(&mut world);
+
+    execute_proposal(&mut world);
+
+    check_action_statuses(&mut world, &[ActionStatus::Succeeded, ActionStatus::Failed]);
+}
+
+#[test]
+fn executing_proposal_twice_should_fail() {
+    let mut world = setup_world_with_contract();
+    deploy_target_contract(&mut world);
+
+    create_proposal(&mut world);
+    add_proposal_actions(&mut world, whitelist_lp_token_action(ActionCallType::Sync));
+    approve_proposal(&mut world);
+    queue_proposal(&mut world);
+
+    execute_proposal(&mut world);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .execute_proposal(1u64)
+        .returns(ExpectMessage(ERR_PROPOSAL_ALREADY_EXECUTED))
+        .run();
+}
+
+#[test]
+fn executing_proposal_that_is_not_approved_should_fail() {
+    let mut world = setup_world_with_contract();
+    deploy_target_contract(&mut world);
+
+    create_proposal(&mut world);
+    add_proposal_actions(&mut world, whitelist_lp_token_action(ActionCallType::Sync));
+
+    world.set_state_step(SetStateStep::new().block_timestamp(VOTING_END_TIMESTAMP));
+
+    // nobody voted, so the proposal failed
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .queue_proposal(1u64)
+        .returns(ExpectMessage(ERR_PROPOSAL_NOT_APPROVED))
+        .run();
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .execute_proposal(1u64)
+        .returns(ExpectMessage(ERR_PROPOSAL_NOT_EXECUTABLE))
+        .run();
+}
+
+#[test]
+fn queued_proposal_should_become_executable_then_expire() {
+    let mut world = setup_world_with_contract();
+    deploy_target_contract(&mut world);
+
+    create_proposal(&mut world);
+    add_proposal_actions(&mut world, whitelist_lp_token_action(ActionCallType::Sync));
+    approve_proposal(&mut world);
+    check_proposal_status(&mut world, ProposalStatus::Approved);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .queue_proposal(1u64)
+        .returns(ExpectStatus(0u64))
+        .run();
+    check_proposal_status(&mut world, ProposalStatus::Queued);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .execute_proposal(1u64)
+        .returns(ExpectMessage(ERR_PROPOSAL_NOT_EXECUTABLE))
+        .run();
+
+    world.set_state_step(SetStateStep::new().block_timestamp(EXECUTABLE_AT));
+    check_proposal_status(&mut world, ProposalStatus::Executable);
+
+    world.set_state_step(
+        SetStateStep::new()
+            .block_timestamp(EXECUTABLE_AT + DEFAULT_EXECUTION_GRACE_PERIOD_IN_SECONDS),
+    );
+    check_proposal_status(&mut world, ProposalStatus::Expired);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .execute_proposal(1u64)
+        .returns(ExpectMessage(ERR_PROPOSAL_NOT_EXECUTABLE))
+        .run();
+}
+
+#[test]
+fn guardian_should_veto_queued_proposal() {
+    let mut world = setup_world_with_contract();
+    deploy_target_contract(&mut world);
+
+    create_proposal(&mut world);
+    add_proposal_actions(&mut world, whitelist_lp_token_action(ActionCallType::Sync));
+    approve_proposal(&mut world);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .add_guardian(USER_ADDRESS)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .queue_proposal(1u64)
+        .returns(ExpectStatus(0u64))
+        .run();
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .veto_proposal(1u64)
+        .returns(ExpectStatus(0u64))
+        .run();
+    check_proposal_status(&mut world, ProposalStatus::Vetoed);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(EXECUTABLE_AT));
+    check_proposal_status(&mut world, ProposalStatus::Vetoed);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .execute_proposal(1u64)
+        .returns(ExpectMessage(ERR_PROPOSAL_NOT_EXECUTABLE))
+        .run();
+}
+
+#[test]
+fn vetoing_proposal_without_being_guardian_should_fail() {
+    let mut world = setup_world_with_contract();
+    deploy_target_contract(&mut world);
+
+    create_proposal(&mut world);
+    add_proposal_actions(&mut world, whitelist_lp_token_action(ActionCallType::Sync));
+    approve_proposal(&mut world);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .veto_proposal(1u64)
+        .returns(ExpectMessage(ERR_NOT_GUARDIAN))
+        .run();
+}
+
+#[test]
+fn vetoing_executed_proposal_should_fail() {
+    let mut world = setup_world_with_contract();
+    deploy_target_contract(&mut world);
+
+    create_proposal(&mut world);
+    add_proposal_actions(&mut world, whitelist_lp_token_action(ActionCallType::Sync));
+    approve_proposal(&mut world);
+    queue_proposal(&mut world);
+    execute_proposal(&mut world);
+    check_proposal_status(&mut world, ProposalStatus::Executed);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .veto_proposal(1u64)
+        .returns(ExpectMessage(ERR_PROPOSAL_CANNOT_BE_VETOED))
+        .run();
+}
+
+#[test]
+fn adding_actions_after_voting_started_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    create_proposal(&mut world);
+
+    world.set_state_step(
+        SetStateStep::new().block_timestamp(DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + 1),
+    );
+
+    let mut actions = MultiValueEncoded::new();
+    actions.push(whitelist_lp_token_action(ActionCallType::Sync));
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .add_proposal_actions(1u64, actions)
+        .returns(ExpectMessage(ERR_PROPOSAL_NOT_PENDING))
+        .run();
+}
+
+#[test]
+fn adding_action_paying_staked_tokens_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    create_proposal(&mut world);
+
+    let mut action = whitelist_lp_token_action(ActionCallType::Sync);
+    action.payment = EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(TRO_TOKEN_ID.to_token_identifier()),
+        0,
+        BigUint::from(1000u64),
+    );
+    let mut actions = MultiValueEncoded::new();
+    actions.push(action);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .add_proposal_actions(1u64, actions)
+        .returns(ExpectMessage(ERR_INVALID_PROPOSAL_ACTION))
+        .run();
+}
+
+/// The target contract is owned by the staking contract, so that proposals can administrate it.
+fn deploy_target_contract(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .init(TRO_TOKEN_ID)
+        .code(CODE_PATH)
+        .new_address(TARGET_SC_ADDRESS)
+        .returns(ReturnsNewAddress)
+        .run();
+}
+
+fn whitelist_lp_token_action(call_type: ActionCallType) -> ProposalAction<StaticApi> {
+    ProposalAction {
+        target: TARGET_SC_ADDRESS.to_managed_address(),
+        endpoint_name: ManagedBuffer::from("addWhitelistedLpTokens"),
+        arguments: ManagedVec::from_single_item(
+            UNSUPPORTED_LP_TOKEN_ID
+                .to_token_identifier()
+                .into_managed_buffer(),
+        ),
+        payment: EgldOrEsdtTokenPayment::no_payment(),
+        gas_limit: ACTION_GAS_LIMIT,
+        call_type,
+    }
+}
+
+fn create_proposal(world: &mut ScenarioWorld) {
+    world.set_state_step(SetStateStep::new().block_timestamp(1));
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .create_proposal(
+            ManagedBuffer::from("Whitelist LP token"),
+            ManagedBuffer::from("Whitelist a new LP token on the target contract"),
+            BigUint::from(MIN_VOTING_POWER_TO_VALIDATE_VOTE),
+            OptionalValue::Some(DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS),
+            OptionalValue::Some(
+                DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + DEFAULT_PROPOSAL_DURATION_IN_SECONDS,
+            ),
+            MultiValueEncoded::new(),
+        )
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn add_proposal_actions(world: &mut ScenarioWorld, action: ProposalAction<StaticApi>) {
+    let mut actions = MultiValueEncoded::new();
+    actions.push(action);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .add_proposal_actions(1u64, actions)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn approve_proposal(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .stake()
+        .payment(EsdtTokenPayment::new(
+            TRO_TOKEN_ID.to_token_identifier(),
+            0,
+            BigUint::from(MIN_VOTING_POWER_TO_VALIDATE_VOTE),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world.set_state_step(
+        SetStateStep::new().block_timestamp(DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + 1),
+    );
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .vote(1u64, VoteDecision::Approve)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world.set_state_step(SetStateStep::new().block_timestamp(VOTING_END_TIMESTAMP));
+}
+
+/// Queues the approved proposal and waits for its timelock delay to pass.
+fn queue_proposal(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .queue_proposal(1u64)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .proposal_executable_at(1u64)
+        .returns(ExpectValue(EXECUTABLE_AT))
+        .run();
+
+    world.set_state_step(SetStateStep::new().block_timestamp(EXECUTABLE_AT));
+}
+
+fn execute_proposal(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .execute_proposal(1u64)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .proposal_executed(1u64)
+        .returns(ExpectValue(true))
+        .run();
+}
+
+fn check_proposal_status(world: &mut ScenarioWorld, expected_status: ProposalStatus) {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_proposal_status_view(1u64)
+        .returns(ExpectValue(expected_status))
+        .run();
+}
+
+fn check_action_statuses(world: &mut ScenarioWorld, expected_statuses: &[ActionStatus]) {
+    let statuses = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_proposal_action_statuses(1u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    assert_eq!(statuses, expected_statuses);
+}
@@ -1,10 +1,17 @@
 use test_setup::setup_world_with_contract;
 
+pub mod delegation;
+pub mod execution;
+pub mod multiple_choice;
 pub mod permissions;
+pub mod proposal_creation;
+pub mod quorum;
 pub mod stake;
 pub mod test_setup;
+pub mod unbonding;
 pub mod unstake;
 pub mod voting;
+pub mod voting_power_snapshot;
 
 #[test]
 fn deploy_should_succeed() {
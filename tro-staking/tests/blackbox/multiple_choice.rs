@@ -0,0 +1,317 @@
+use multiversx_sc_scenario::imports::*;
+use tro_staking::{
+    errors::*,
+    proxy::{OptionProposalResult, ProposalStatus, VoteDecision, VotingMethod},
+    voting::{DEFAULT_PROPOSAL_DURATION_IN_SECONDS, DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS},
+};
+
+use crate::config::*;
+
+use super::test_setup::{add_account, setup_world_with_contract};
+
+const THIRD_VOTER_ADDRESS: TestAddress = TestAddress::new("third-voter");
+const OPTIONS: [&str; 3] = ["Artist A", "Artist B", "Artist C"];
+const MIN_VOTING_POWER_TO_VALIDATE_VOTE: u64 = 500;
+const PROPOSAL_START_TIME: u64 = DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + 1;
+const VOTING_END_TIMESTAMP: u64 = PROPOSAL_START_TIME + DEFAULT_PROPOSAL_DURATION_IN_SECONDS + 1;
+
+#[test]
+fn plurality_proposal_should_be_won_by_option_with_most_votes() {
+    let mut world = setup_voters(&[400, 300, 200]);
+    create_option_proposal(&mut world, VotingMethod::Plurality);
+
+    vote_options(&mut world, OWNER_ADDRESS, &[0]);
+    vote_options(&mut world, USER_ADDRESS, &[1]);
+    vote_options(&mut world, THIRD_VOTER_ADDRESS, &[1]);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(VOTING_END_TIMESTAMP));
+    let result = get_option_proposal_result(&mut world);
+
+    assert_eq!(result.proposal_status, ProposalStatus::Approved);
+    assert_eq!(result.winning_option, Some(1));
+    assert_eq!(result.first_choice_votes, option_votes(&[400, 500, 0]));
+    assert_eq!(result.final_round_votes, option_votes(&[400, 500, 0]));
+}
+
+#[test]
+fn ranked_choice_should_transfer_votes_of_eliminated_options() {
+    let mut world = setup_voters(&[400, 300, 200]);
+    create_option_proposal(&mut world, VotingMethod::RankedChoice);
+
+    vote_options(&mut world, OWNER_ADDRESS, &[0]);
+    vote_options(&mut world, USER_ADDRESS, &[1, 0]);
+    vote_options(&mut world, THIRD_VOTER_ADDRESS, &[2, 1]);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(VOTING_END_TIMESTAMP));
+    count_ranked_choice_votes(&mut world);
+    let result = get_option_proposal_result(&mut world);
+
+    // Artist A leads the first choices, but Artist C is eliminated and its votes go to Artist B
+    assert_eq!(result.proposal_status, ProposalStatus::Approved);
+    assert_eq!(result.winning_option, Some(1));
+    assert_eq!(result.first_choice_votes, option_votes(&[400, 300, 200]));
+    assert_eq!(result.final_round_votes, option_votes(&[400, 500, 0]));
+}
+
+#[test]
+fn option_proposal_without_winner_should_be_rejected() {
+    let mut world = setup_voters(&[400, 400, 0]);
+    create_option_proposal(&mut world, VotingMethod::RankedChoice);
+
+    vote_options(&mut world, OWNER_ADDRESS, &[0, 1]);
+    vote_options(&mut world, USER_ADDRESS, &[1, 0]);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(VOTING_END_TIMESTAMP));
+    let result = get_option_proposal_result(&mut world);
+
+    assert_eq!(result.proposal_status, ProposalStatus::Rejected);
+    assert_eq!(result.winning_option, None);
+}
+
+#[test]
+fn ranked_choice_votes_should_be_counted_before_queueing() {
+    let mut world = setup_voters(&[400, 300, 200]);
+    create_option_proposal(&mut world, VotingMethod::RankedChoice);
+
+    vote_options(&mut world, OWNER_ADDRESS, &[0]);
+    vote_options(&mut world, USER_ADDRESS, &[1, 0]);
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .count_ranked_choice_votes(1u64)
+        .returns(ExpectMessage(ERR_PROPOSAL_VOTING_NOT_ENDED))
+        .run();
+
+    world.set_state_step(SetStateStep::new().block_timestamp(VOTING_END_TIMESTAMP));
+    try_queue_proposal(&mut world, ERR_RANKED_CHOICE_VOTES_NOT_COUNTED);
+
+    count_ranked_choice_votes(&mut world);
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .count_ranked_choice_votes(1u64)
+        .returns(ExpectMessage(ERR_RANKED_CHOICE_VOTES_ALREADY_COUNTED))
+        .run();
+    // the stored result is used from now on
+    try_queue_proposal(&mut world, ERR_PROPOSAL_HAS_NO_ACTIONS);
+}
+
+#[test]
+fn option_proposal_below_quorum_should_fail() {
+    let mut world = setup_voters(&[400, 300, 200]);
+    create_option_proposal(&mut world, VotingMethod::Plurality);
+
+    vote_options(&mut world, THIRD_VOTER_ADDRESS, &[2]);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(VOTING_END_TIMESTAMP));
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_proposal_status_view(1u64)
+        .returns(ExpectValue(ProposalStatus::Failed))
+        .run();
+}
+
+#[test]
+fn invalid_option_votes_should_fail() {
+    let mut world = setup_voters(&[400, 300, 200]);
+    create_option_proposal(&mut world, VotingMethod::Plurality);
+
+    try_vote_options(
+        &mut world,
+        OWNER_ADDRESS,
+        &[0, 1],
+        Some(ERR_INVALID_OPTION_RANKING),
+    );
+    try_vote_options(
+        &mut world,
+        OWNER_ADDRESS,
+        &[3],
+        Some(ERR_INVALID_OPTION_RANKING),
+    );
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .vote(1u64, VoteDecision::Approve)
+        .returns(ExpectMessage(ERR_INVALID_VOTING_METHOD))
+        .run();
+
+    vote_options(&mut world, OWNER_ADDRESS, &[0]);
+    try_vote_options(
+        &mut world,
+        OWNER_ADDRESS,
+        &[1],
+        Some(ERR_USER_ALREADY_VOTED),
+    );
+}
+
+#[test]
+fn ranked_choice_ballot_with_duplicate_option_should_fail() {
+    let mut world = setup_voters(&[400, 300, 200]);
+    create_option_proposal(&mut world, VotingMethod::RankedChoice);
+
+    try_vote_options(
+        &mut world,
+        OWNER_ADDRESS,
+        &[0, 2, 0],
+        Some(ERR_INVALID_OPTION_RANKING),
+    );
+}
+
+#[test]
+fn create_option_proposal_with_invalid_options_should_fail() {
+    let mut world = setup_voters(&[400, 300, 200]);
+
+    try_create_option_proposal(
+        &mut world,
+        VotingMethod::Plurality,
+        &OPTIONS[..1],
+        Some(ERR_INVALID_PROPOSAL_OPTIONS),
+    );
+    try_create_option_proposal(
+        &mut world,
+        VotingMethod::YesNo,
+        &OPTIONS,
+        Some(ERR_INVALID_VOTING_METHOD),
+    );
+}
+
+/// The owner, the user and a third voter stake the given $TRO amounts
+fn setup_voters(stakes: &[u64; 3]) -> ScenarioWorld {
+    let mut world = setup_world_with_contract();
+    add_account(&mut world, THIRD_VOTER_ADDRESS);
+
+    let voters = [OWNER_ADDRESS, USER_ADDRESS, THIRD_VOTER_ADDRESS];
+    for (voter, stake) in voters.iter().zip(stakes) {
+        if *stake == 0 {
+            continue;
+        }
+
+        world
+            .tx()
+            .from(*voter)
+            .to(SC_ADDRESS)
+            .typed(tro_staking::proxy::TroStakingProxy)
+            .stake()
+            .payment(EsdtTokenPayment::new(
+                TRO_TOKEN_ID.to_token_identifier(),
+                0,
+                BigUint::from(*stake),
+            ))
+            .returns(ExpectStatus(0u64))
+            .run();
+    }
+
+    world.set_state_step(SetStateStep::new().block_timestamp(1));
+    world
+}
+
+/// Creates the proposal and waits for its voting to start
+fn create_option_proposal(world: &mut ScenarioWorld, voting_method: VotingMethod) {
+    try_create_option_proposal(world, voting_method, &OPTIONS, None);
+    world.set_state_step(SetStateStep::new().block_timestamp(PROPOSAL_START_TIME));
+}
+
+fn try_create_option_proposal(
+    world: &mut ScenarioWorld,
+    voting_method: VotingMethod,
+    options: &[&str],
+    expected_error: Option<&str>,
+) {
+    let options = options
+        .iter()
+        .map(|option| ManagedBuffer::from(*option))
+        .collect::<ManagedVec<StaticApi, ManagedBuffer<StaticApi>>>();
+
+    let tx = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .create_option_proposal(
+            ManagedBuffer::from("Next featured artist"),
+            ManagedBuffer::from("Which artist should be featured next?"),
+            BigUint::from(MIN_VOTING_POWER_TO_VALIDATE_VOTE),
+            voting_method,
+            options,
+            OptionalValue::Some(PROPOSAL_START_TIME),
+            OptionalValue::Some(PROPOSAL_START_TIME + DEFAULT_PROPOSAL_DURATION_IN_SECONDS),
+            MultiValueEncoded::new(),
+        );
+
+    match expected_error {
+        Some(message) => tx.returns(ExpectMessage(message)).run(),
+        None => tx.returns(ExpectStatus(0u64)).run(),
+    }
+}
+
+fn vote_options(world: &mut ScenarioWorld, voter: TestAddress, ranking: &[u32]) {
+    try_vote_options(world, voter, ranking, None);
+}
+
+fn try_vote_options(
+    world: &mut ScenarioWorld,
+    voter: TestAddress,
+    ranking: &[u32],
+    expected_error: Option<&str>,
+) {
+    let mut ranking_args = MultiValueEncoded::new();
+    for option_index in ranking {
+        ranking_args.push(*option_index);
+    }
+
+    let tx = world
+        .tx()
+        .from(voter)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .vote_options(1u64, ranking_args);
+
+    match expected_error {
+        Some(message) => tx.returns(ExpectMessage(message)).run(),
+        None => tx.returns(ExpectStatus(0u64)).run(),
+    }
+}
+
+fn count_ranked_choice_votes(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .count_ranked_choice_votes(1u64)
+        .returns(ExpectValue(OperationCompletionStatus::Completed))
+        .run();
+}
+
+fn try_queue_proposal(world: &mut ScenarioWorld, expected_error: &str) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .queue_proposal(1u64)
+        .returns(ExpectMessage(expected_error))
+        .run();
+}
+
+fn get_option_proposal_result(world: &mut ScenarioWorld) -> OptionProposalResult<StaticApi> {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_option_proposal_result(1u64)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn option_votes(votes: &[u64]) -> ManagedVec<StaticApi, BigUint<StaticApi>> {
+    votes.iter().map(|votes| BigUint::from(*votes)).collect()
+}
@@ -1,4 +1,5 @@
 use multiversx_sc_scenario::imports::*;
+use tro_staking::errors::ERR_INSUFFICIENT_VOTING_POWER;
 
 use crate::config::*;
 
@@ -39,7 +40,7 @@ fn user_calling_add_whitelisted_lp_tokens_should_fail() {
 }
 
 #[test]
-fn create_proposal_when_not_owner_should_fail() {
+fn create_proposal_when_not_owner_without_voting_power_should_fail() {
     let mut world = setup_world_with_contract();
 
     let title = ManagedBuffer::new_from_bytes(b"Test Proposal");
@@ -61,6 +62,6 @@ fn create_proposal_when_not_owner_should_fail() {
             end_time,
             MultiValueEncoded::new(),
         )
-        .returns(ExpectStatus(4u64))
+        .returns(ExpectMessage(ERR_INSUFFICIENT_VOTING_POWER))
         .run();
 }
@@ -0,0 +1,381 @@
+use multiversx_sc_scenario::imports::*;
+use tro_staking::{
+    errors::*,
+    proxy::VoteDecision,
+    voting::{
+        LpToTroRatio, DEFAULT_PROPOSAL_DURATION_IN_SECONDS,
+        DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS, DIVISION_GUARD,
+    },
+};
+
+use crate::config::*;
+
+use super::test_setup::{check_staked_amount, setup_world_with_contract};
+
+const PROPOSAL_CREATION_THRESHOLD: u64 = 500;
+const PROPOSAL_DEPOSIT_AMOUNT: u64 = 100;
+const MIN_VOTING_POWER_TO_VALIDATE_VOTE: u64 = 1000;
+const VOTING_END_TIMESTAMP: u64 =
+    DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + DEFAULT_PROPOSAL_DURATION_IN_SECONDS + 2;
+
+#[test]
+fn user_above_threshold_should_create_proposal_with_deposit() {
+    let mut world = setup_world_with_contract();
+    configure_proposal_creation(&mut world);
+    stake_tro(&mut world, PROPOSAL_CREATION_THRESHOLD);
+
+    create_user_proposal(&mut world, PROPOSAL_DEPOSIT_AMOUNT);
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .proposal_deposit(1u64)
+        .returns(ExpectValue(BigUint::from(PROPOSAL_DEPOSIT_AMOUNT)))
+        .run();
+    world.check_account(USER_ADDRESS).esdt_balance(
+        TRO_TOKEN_ID,
+        INITIAL_TOKEN_BALANCE - PROPOSAL_CREATION_THRESHOLD - PROPOSAL_DEPOSIT_AMOUNT,
+    );
+    // the deposit does not count as stake
+    check_staked_amount(
+        &mut world,
+        USER_ADDRESS,
+        TRO_TOKEN_ID,
+        PROPOSAL_CREATION_THRESHOLD,
+    );
+}
+
+#[test]
+fn user_below_threshold_should_not_create_proposal() {
+    let mut world = setup_world_with_contract();
+    configure_proposal_creation(&mut world);
+    stake_tro(&mut world, PROPOSAL_CREATION_THRESHOLD - 1);
+
+    try_create_user_proposal(
+        &mut world,
+        PROPOSAL_DEPOSIT_AMOUNT,
+        Some(ERR_INSUFFICIENT_VOTING_POWER),
+    );
+}
+
+#[test]
+fn user_proposal_with_wrong_deposit_should_fail() {
+    let mut world = setup_world_with_contract();
+    configure_proposal_creation(&mut world);
+    stake_tro(&mut world, PROPOSAL_CREATION_THRESHOLD);
+
+    try_create_user_proposal(
+        &mut world,
+        PROPOSAL_DEPOSIT_AMOUNT - 1,
+        Some(ERR_INVALID_PROPOSAL_DEPOSIT),
+    );
+}
+
+#[test]
+fn user_proposal_with_lp_to_tro_ratios_should_fail() {
+    let mut world = setup_world_with_contract();
+    configure_proposal_creation(&mut world);
+    stake_tro(&mut world, PROPOSAL_CREATION_THRESHOLD);
+
+    let mut lp_to_tro_ratios = MultiValueEncoded::new();
+    lp_to_tro_ratios.push(MultiValue3((
+        LP_TOKEN_ID_1.to_token_identifier(),
+        BigUint::from(1000u64),
+        BigUint::from(1u64),
+    )));
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .create_proposal(
+            ManagedBuffer::from("Community proposal"),
+            ManagedBuffer::from("Raised by a staker"),
+            BigUint::from(MIN_VOTING_POWER_TO_VALIDATE_VOTE),
+            OptionalValue::Some(DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + 1),
+            OptionalValue::Some(
+                DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS
+                    + DEFAULT_PROPOSAL_DURATION_IN_SECONDS
+                    + 1,
+            ),
+            lp_to_tro_ratios,
+        )
+        .payment(deposit_payment(PROPOSAL_DEPOSIT_AMOUNT))
+        .returns(ExpectMessage(ERR_LP_TO_TRO_RATIOS_OWNER_ONLY))
+        .run();
+}
+
+#[test]
+fn user_proposal_below_validation_floor_should_fail() {
+    let mut world = setup_world_with_contract();
+    configure_proposal_creation(&mut world);
+    stake_tro(&mut world, PROPOSAL_CREATION_THRESHOLD);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .set_min_voting_power_to_validate_vote_floor(BigUint::from(
+            MIN_VOTING_POWER_TO_VALIDATE_VOTE + 1,
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    try_create_user_proposal(
+        &mut world,
+        PROPOSAL_DEPOSIT_AMOUNT,
+        Some(ERR_MIN_VOTING_POWER_TO_VALIDATE_VOTE_TOO_LOW),
+    );
+}
+
+#[test]
+fn user_proposal_should_use_current_lp_to_tro_ratio() {
+    let mut world = setup_world_with_contract();
+    configure_proposal_creation(&mut world);
+    stake_tro(&mut world, PROPOSAL_CREATION_THRESHOLD);
+
+    let expected_ratio = BigUint::from(2u64) * BigUint::from(DIVISION_GUARD);
+    let mut lp_to_tro_ratios = MultiValueEncoded::new();
+    lp_to_tro_ratios.push(MultiValue3((
+        LP_TOKEN_ID_1.to_token_identifier(),
+        BigUint::from(2000u64),
+        BigUint::from(1000u64),
+    )));
+    create_owner_proposal(&mut world, lp_to_tro_ratios);
+    // an owner proposal without ratios keeps the current ones
+    create_owner_proposal(&mut world, MultiValueEncoded::new());
+
+    create_user_proposal(&mut world, PROPOSAL_DEPOSIT_AMOUNT);
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .current_lp_to_tro_ratio(LP_TOKEN_ID_1.to_token_identifier())
+        .returns(ExpectValue(expected_ratio.clone()))
+        .run();
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .lp_to_tro_ratio(3u64, LP_TOKEN_ID_1.to_token_identifier())
+        .returns(ExpectValue(expected_ratio))
+        .run();
+}
+
+#[test]
+fn payment_without_required_deposit_should_fail() {
+    let mut world = setup_world_with_contract();
+    configure_proposal_creation(&mut world);
+    stake_tro(&mut world, PROPOSAL_CREATION_THRESHOLD);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .set_proposal_deposit_amount(BigUint::zero())
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    try_create_user_proposal(
+        &mut world,
+        PROPOSAL_DEPOSIT_AMOUNT,
+        Some(ERR_UNEXPECTED_PAYMENT),
+    );
+}
+
+#[test]
+fn open_proposals_per_creator_should_be_limited() {
+    let mut world = setup_world_with_contract();
+    configure_proposal_creation(&mut world);
+    stake_tro(&mut world, PROPOSAL_CREATION_THRESHOLD);
+
+    create_user_proposal(&mut world, PROPOSAL_DEPOSIT_AMOUNT);
+    try_create_user_proposal(
+        &mut world,
+        PROPOSAL_DEPOSIT_AMOUNT,
+        Some(ERR_TOO_MANY_OPEN_PROPOSALS),
+    );
+
+    // the first proposal is no longer open once its voting has ended
+    world.set_state_step(SetStateStep::new().block_timestamp(VOTING_END_TIMESTAMP));
+    create_user_proposal(&mut world, PROPOSAL_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn deposit_of_failed_proposal_should_be_slashed_to_treasury() {
+    let mut world = setup_world_with_contract();
+    configure_proposal_creation(&mut world);
+    stake_tro(&mut world, PROPOSAL_CREATION_THRESHOLD);
+
+    create_user_proposal(&mut world, PROPOSAL_DEPOSIT_AMOUNT);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .settle_proposal_deposit(1u64)
+        .returns(ExpectMessage(ERR_PROPOSAL_VOTING_NOT_ENDED))
+        .run();
+
+    // nobody voted, so the proposal failed
+    world.set_state_step(SetStateStep::new().block_timestamp(VOTING_END_TIMESTAMP));
+    settle_proposal_deposit(&mut world);
+
+    // the treasury defaults to the owner
+    world.check_account(OWNER_ADDRESS).esdt_balance(
+        TRO_TOKEN_ID,
+        INITIAL_TOKEN_BALANCE + PROPOSAL_DEPOSIT_AMOUNT,
+    );
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .settle_proposal_deposit(1u64)
+        .returns(ExpectMessage(ERR_NO_PROPOSAL_DEPOSIT))
+        .run();
+}
+
+#[test]
+fn deposit_of_proposal_reaching_quorum_should_be_refunded() {
+    let mut world = setup_world_with_contract();
+    configure_proposal_creation(&mut world);
+    stake_tro(&mut world, MIN_VOTING_POWER_TO_VALIDATE_VOTE);
+
+    create_user_proposal(&mut world, PROPOSAL_DEPOSIT_AMOUNT);
+
+    world.set_state_step(
+        SetStateStep::new().block_timestamp(DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + 2),
+    );
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .vote(1u64, VoteDecision::Reject)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world.set_state_step(SetStateStep::new().block_timestamp(VOTING_END_TIMESTAMP));
+    settle_proposal_deposit(&mut world);
+
+    world.check_account(USER_ADDRESS).esdt_balance(
+        TRO_TOKEN_ID,
+        INITIAL_TOKEN_BALANCE - MIN_VOTING_POWER_TO_VALIDATE_VOTE,
+    );
+}
+
+fn configure_proposal_creation(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .set_proposal_creation_threshold(BigUint::from(PROPOSAL_CREATION_THRESHOLD))
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .set_proposal_deposit_amount(BigUint::from(PROPOSAL_DEPOSIT_AMOUNT))
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world.set_state_step(SetStateStep::new().block_timestamp(1));
+}
+
+fn create_owner_proposal(
+    world: &mut ScenarioWorld,
+    lp_to_tro_ratios: MultiValueEncoded<StaticApi, LpToTroRatio<StaticApi>>,
+) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .create_proposal(
+            ManagedBuffer::from("Owner proposal"),
+            ManagedBuffer::from("Raised by the owner"),
+            BigUint::from(MIN_VOTING_POWER_TO_VALIDATE_VOTE),
+            OptionalValue::Some(DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + 1),
+            OptionalValue::Some(
+                DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS
+                    + DEFAULT_PROPOSAL_DURATION_IN_SECONDS
+                    + 1,
+            ),
+            lp_to_tro_ratios,
+        )
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn stake_tro(world: &mut ScenarioWorld, amount: u64) {
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .stake()
+        .payment(EsdtTokenPayment::new(
+            TRO_TOKEN_ID.to_token_identifier(),
+            0,
+            BigUint::from(amount),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn deposit_payment(amount: u64) -> EsdtTokenPayment<StaticApi> {
+    EsdtTokenPayment::new(TRO_TOKEN_ID.to_token_identifier(), 0, BigUint::from(amount))
+}
+
+fn create_user_proposal(world: &mut ScenarioWorld, deposit_amount: u64) {
+    try_create_user_proposal(world, deposit_amount, None);
+}
+
+fn try_create_user_proposal(
+    world: &mut ScenarioWorld,
+    deposit_amount: u64,
+    expected_error: Option<&str>,
+) {
+    let tx = world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .create_proposal(
+            ManagedBuffer::from("Community proposal"),
+            ManagedBuffer::from("Raised by a staker"),
+            BigUint::from(MIN_VOTING_POWER_TO_VALIDATE_VOTE),
+            OptionalValue::<u64>::None,
+            OptionalValue::<u64>::None,
+            MultiValueEncoded::new(),
+        )
+        .payment(deposit_payment(deposit_amount));
+
+    match expected_error {
+        Some(message) => tx.returns(ExpectMessage(message)).run(),
+        None => tx.returns(ExpectStatus(0u64)).run(),
+    }
+}
+
+fn settle_proposal_deposit(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .settle_proposal_deposit(1u64)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
@@ -0,0 +1,294 @@
+use multiversx_sc_scenario::imports::*;
+use tro_staking::{
+    errors::*,
+    proxy::{ProposalStatus, VoteDecision},
+    voting::{DEFAULT_PROPOSAL_DURATION_IN_SECONDS, DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS},
+};
+
+use crate::config::*;
+
+use super::test_setup::setup_world_with_contract;
+
+const OWNER_STAKE: u64 = 400;
+const USER_TRO_STAKE: u64 = 200;
+const USER_LP_STAKE: u64 = 200;
+// LP_TOKEN_ID_1 is worth 2 $TRO in the proposal
+const TOTAL_VOTING_POWER: u64 = OWNER_STAKE + USER_TRO_STAKE + USER_LP_STAKE * 2;
+const PROPOSAL_START_TIME: u64 = DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + 1;
+const VOTING_END_TIMESTAMP: u64 = PROPOSAL_START_TIME + DEFAULT_PROPOSAL_DURATION_IN_SECONDS + 1;
+
+#[test]
+fn total_staked_should_follow_stake_and_unstake() {
+    let mut world = setup_world_with_contract();
+    stake_voters(&mut world);
+
+    check_total_staked(&mut world, TRO_TOKEN_ID, OWNER_STAKE + USER_TRO_STAKE);
+    check_total_staked(&mut world, LP_TOKEN_ID_1, USER_LP_STAKE);
+
+    let mut unstake_args = MultiValueEncoded::new();
+    unstake_args.push(MultiValue2((
+        TRO_TOKEN_ID.to_token_identifier(),
+        BigUint::from(100u64),
+    )));
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .unstake(unstake_args)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    // unbonding tokens are no longer staked
+    check_total_staked(&mut world, TRO_TOKEN_ID, OWNER_STAKE + USER_TRO_STAKE - 100);
+}
+
+#[test]
+fn proposal_below_quorum_bps_should_fail() {
+    let mut world = setup_world_with_contract();
+    set_thresholds(&mut world, 5_000, 0);
+    stake_voters(&mut world);
+    create_proposal(&mut world);
+
+    vote(&mut world, OWNER_ADDRESS, VoteDecision::Approve);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(VOTING_END_TIMESTAMP));
+    check_proposal_status(&mut world, ProposalStatus::Failed);
+
+    let participation = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_proposal_participation(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(participation.total_voting_power, TOTAL_VOTING_POWER);
+    assert_eq!(participation.total_votes, OWNER_STAKE);
+    assert_eq!(participation.participation_bps, 4_000);
+    assert_eq!(participation.quorum, TOTAL_VOTING_POWER / 2);
+}
+
+#[test]
+fn proposal_below_approval_threshold_should_be_rejected() {
+    let mut world = setup_world_with_contract();
+    set_thresholds(&mut world, 5_000, 5_000);
+    stake_voters(&mut world);
+    create_proposal(&mut world);
+
+    vote(&mut world, OWNER_ADDRESS, VoteDecision::Approve);
+    vote(&mut world, USER_ADDRESS, VoteDecision::Abstain);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(VOTING_END_TIMESTAMP));
+    check_proposal_status(&mut world, ProposalStatus::Rejected);
+}
+
+#[test]
+fn proposal_reaching_quorum_and_approval_threshold_should_be_approved() {
+    let mut world = setup_world_with_contract();
+    set_thresholds(&mut world, 5_000, 4_000);
+    stake_voters(&mut world);
+    create_proposal(&mut world);
+
+    // staking once voting started does not change its thresholds
+    world.set_state_step(SetStateStep::new().block_timestamp(PROPOSAL_START_TIME));
+    stake(&mut world, OWNER_ADDRESS, TRO_TOKEN_ID, OWNER_STAKE);
+
+    vote(&mut world, OWNER_ADDRESS, VoteDecision::Approve);
+    vote(&mut world, USER_ADDRESS, VoteDecision::Abstain);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(VOTING_END_TIMESTAMP));
+    check_proposal_status(&mut world, ProposalStatus::Approved);
+
+    let thresholds = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_proposal_thresholds_view(1u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert_eq!(thresholds.total_voting_power, TOTAL_VOTING_POWER);
+    assert_eq!(thresholds.quorum_bps, 5_000);
+    assert_eq!(thresholds.approval_threshold_bps, 4_000);
+}
+
+#[test]
+fn stake_before_voting_starts_should_count_in_total_voting_power() {
+    let mut world = setup_world_with_contract();
+    set_thresholds(&mut world, 5_000, 0);
+    stake_voters(&mut world);
+    create_proposal(&mut world);
+
+    stake(&mut world, OWNER_ADDRESS, TRO_TOKEN_ID, OWNER_STAKE);
+    vote(&mut world, OWNER_ADDRESS, VoteDecision::Approve);
+
+    let participation = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_proposal_participation(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(
+        participation.total_voting_power,
+        TOTAL_VOTING_POWER + OWNER_STAKE
+    );
+    assert_eq!(participation.total_votes, OWNER_STAKE * 2);
+}
+
+#[test]
+fn counting_stake_already_in_total_should_not_change_it() {
+    let mut world = setup_world_with_contract();
+    stake_voters(&mut world);
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .total_staked_reconciled()
+        .returns(ExpectValue(true))
+        .run();
+
+    let mut users = MultiValueEncoded::new();
+    users.push(OWNER_ADDRESS.to_managed_address());
+    users.push(USER_ADDRESS.to_managed_address());
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .count_users_stake_in_total(users)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    check_total_staked(&mut world, TRO_TOKEN_ID, OWNER_STAKE + USER_TRO_STAKE);
+    check_total_staked(&mut world, LP_TOKEN_ID_1, USER_LP_STAKE);
+}
+
+#[test]
+fn thresholds_above_max_bps_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .set_quorum_bps(10_001u64)
+        .returns(ExpectMessage(ERR_INVALID_BPS))
+        .run();
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .set_approval_threshold_bps(10_001u64)
+        .returns(ExpectMessage(ERR_INVALID_BPS))
+        .run();
+}
+
+fn set_thresholds(world: &mut ScenarioWorld, quorum_bps: u64, approval_threshold_bps: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .set_quorum_bps(quorum_bps)
+        .returns(ExpectStatus(0u64))
+        .run();
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .set_approval_threshold_bps(approval_threshold_bps)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn stake_voters(world: &mut ScenarioWorld) {
+    stake(world, OWNER_ADDRESS, TRO_TOKEN_ID, OWNER_STAKE);
+    stake(world, USER_ADDRESS, TRO_TOKEN_ID, USER_TRO_STAKE);
+    stake(world, USER_ADDRESS, LP_TOKEN_ID_1, USER_LP_STAKE);
+}
+
+fn stake(
+    world: &mut ScenarioWorld,
+    address: TestAddress,
+    token_id: TestTokenIdentifier,
+    amount: u64,
+) {
+    world
+        .tx()
+        .from(address)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .stake()
+        .payment(EsdtTokenPayment::new(
+            token_id.to_token_identifier(),
+            0,
+            BigUint::from(amount),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+/// Creates the proposal without an absolute minimum voting power, and waits for its voting to start
+fn create_proposal(world: &mut ScenarioWorld) {
+    let mut lp_to_tro_ratios = MultiValueEncoded::new();
+    lp_to_tro_ratios.push(MultiValue3((
+        LP_TOKEN_ID_1.to_token_identifier(),
+        BigUint::from(2000u64),
+        BigUint::from(1000u64),
+    )));
+
+    world.set_state_step(SetStateStep::new().block_timestamp(1));
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .create_proposal(
+            ManagedBuffer::from("Test Proposal"),
+            ManagedBuffer::from("This is a test proposal"),
+            BigUint::zero(),
+            OptionalValue::Some(PROPOSAL_START_TIME),
+            OptionalValue::Some(PROPOSAL_START_TIME + DEFAULT_PROPOSAL_DURATION_IN_SECONDS),
+            lp_to_tro_ratios,
+        )
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn vote(world: &mut ScenarioWorld, address: TestAddress, decision: VoteDecision) {
+    world.set_state_step(SetStateStep::new().block_timestamp(PROPOSAL_START_TIME));
+    world
+        .tx()
+        .from(address)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .vote(1u64, decision)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn check_total_staked(world: &mut ScenarioWorld, token_id: TestTokenIdentifier, expected: u64) {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .total_staked(token_id.to_token_identifier())
+        .returns(ExpectValue(BigUint::from(expected)))
+        .run();
+}
+
+fn check_proposal_status(world: &mut ScenarioWorld, expected_status: ProposalStatus) {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_proposal_status_view(1u64)
+        .returns(ExpectValue(expected_status))
+        .run();
+}
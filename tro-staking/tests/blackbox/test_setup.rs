@@ -8,7 +8,7 @@ fn world() -> ScenarioWorld {
     blockchain
 }
 
-fn add_account(world: &mut ScenarioWorld, address: TestAddress) {
+pub fn add_account(world: &mut ScenarioWorld, address: TestAddress) {
     world
         .account(address)
         .nonce(1)
@@ -41,7 +41,7 @@ fn deploy_contract(world: &mut ScenarioWorld) {
         .tx()
         .from(OWNER_ADDRESS)
         .typed(tro_staking::proxy::TroStakingProxy)
-        .init(TRO_TOKEN_ID, MultiValueEncoded::new())
+        .init(TRO_TOKEN_ID)
         .code(CODE_PATH)
         .new_address(SC_ADDRESS)
         .returns(ReturnsNewAddress)
@@ -0,0 +1,175 @@
+use multiversx_sc_scenario::imports::*;
+use tro_staking::{
+    errors::ERR_NO_UNSTAKED_TOKENS,
+    stake::DEFAULT_UNBONDING_PERIOD_IN_SECONDS,
+    voting::{DEFAULT_PROPOSAL_DURATION_IN_SECONDS, DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS},
+};
+
+use crate::config::*;
+
+use super::test_setup::setup_world_with_contract;
+
+const STAKE_AMOUNT: u64 = 1000;
+
+#[test]
+fn unstaked_tokens_should_be_claimable_after_unbonding_period() {
+    let mut world = setup_world_with_contract();
+
+    stake(&mut world, STAKE_AMOUNT);
+    unstake(&mut world, STAKE_AMOUNT);
+
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(TRO_TOKEN_ID, INITIAL_TOKEN_BALANCE - STAKE_AMOUNT);
+    let batches = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_unbonding_batches(USER_ADDRESS)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].claimable_at, DEFAULT_UNBONDING_PERIOD_IN_SECONDS);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .claim_unstaked(OptionalValue::<usize>::None)
+        .returns(ExpectMessage(ERR_NO_UNSTAKED_TOKENS))
+        .run();
+
+    world.set_state_step(SetStateStep::new().block_timestamp(DEFAULT_UNBONDING_PERIOD_IN_SECONDS));
+    claim_unstaked(&mut world, OptionalValue::None);
+
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(TRO_TOKEN_ID, INITIAL_TOKEN_BALANCE);
+}
+
+#[test]
+fn claim_unstaked_should_respect_max_batches() {
+    let mut world = setup_world_with_contract();
+
+    stake(&mut world, STAKE_AMOUNT);
+    unstake(&mut world, STAKE_AMOUNT / 2);
+    world.set_state_step(SetStateStep::new().block_timestamp(1));
+    unstake(&mut world, STAKE_AMOUNT / 2);
+
+    world.set_state_step(
+        SetStateStep::new().block_timestamp(DEFAULT_UNBONDING_PERIOD_IN_SECONDS + 1),
+    );
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_claimable_unstaked(USER_ADDRESS)
+        .returns(ExpectValue(ManagedVec::<
+            StaticApi,
+            EsdtTokenPayment<StaticApi>,
+        >::from(vec![
+            EsdtTokenPayment::new(
+                TRO_TOKEN_ID.to_token_identifier(),
+                0,
+                BigUint::from(STAKE_AMOUNT / 2),
+            ),
+            EsdtTokenPayment::new(
+                TRO_TOKEN_ID.to_token_identifier(),
+                0,
+                BigUint::from(STAKE_AMOUNT / 2),
+            ),
+        ])))
+        .run();
+
+    claim_unstaked(&mut world, OptionalValue::Some(1));
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(TRO_TOKEN_ID, INITIAL_TOKEN_BALANCE - STAKE_AMOUNT / 2);
+
+    claim_unstaked(&mut world, OptionalValue::None);
+    world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(TRO_TOKEN_ID, INITIAL_TOKEN_BALANCE);
+}
+
+#[test]
+fn unbonding_tokens_should_not_count_towards_voting_power() {
+    let mut world = setup_world_with_contract();
+
+    stake(&mut world, STAKE_AMOUNT);
+    unstake(&mut world, STAKE_AMOUNT / 2);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(1));
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .create_proposal(
+            ManagedBuffer::from("Test Proposal"),
+            ManagedBuffer::from("This is a test proposal"),
+            BigUint::from(STAKE_AMOUNT),
+            OptionalValue::Some(DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS),
+            OptionalValue::Some(
+                DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + DEFAULT_PROPOSAL_DURATION_IN_SECONDS,
+            ),
+            MultiValueEncoded::new(),
+        )
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_voting_power_view(USER_ADDRESS, OptionalValue::Some(1u64))
+        .returns(ExpectValue(BigUint::from(STAKE_AMOUNT / 2)))
+        .run();
+}
+
+fn stake(world: &mut ScenarioWorld, amount: u64) {
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .stake()
+        .payment(EsdtTokenPayment::new(
+            TRO_TOKEN_ID.to_token_identifier(),
+            0,
+            BigUint::from(amount),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn unstake(world: &mut ScenarioWorld, amount: u64) {
+    let mut unstake_args = MultiValueEncoded::new();
+    unstake_args.push(MultiValue2((
+        TRO_TOKEN_ID.to_token_identifier(),
+        BigUint::from(amount),
+    )));
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .unstake(unstake_args)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn claim_unstaked(world: &mut ScenarioWorld, max_batches: OptionalValue<usize>) {
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .claim_unstaked(max_batches)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
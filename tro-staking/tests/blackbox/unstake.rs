@@ -1,6 +1,7 @@
 use multiversx_sc_scenario::imports::*;
 use tro_staking::{
-    errors::{ERR_INSUFFICIENT_STAKE, ERR_PROPOSAL_ACTIVE},
+    errors::{ERR_INSUFFICIENT_STAKE, ERR_STAKE_LOCKED},
+    proxy::VoteDecision,
     voting::DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS,
 };
 
@@ -247,7 +248,7 @@ fn unstaking_same_token_multiple_times_over_total_staked_should_fail() {
 }
 
 #[test]
-fn unstake_should_fail_if_proposal_is_active() {
+fn unstake_should_succeed_while_proposal_is_active() {
     let mut world = setup_world_with_contract();
 
     stake_token(&mut world, TRO_TOKEN_ID, 1000);
@@ -284,8 +285,105 @@ fn unstake_should_fail_if_proposal_is_active() {
         .to(SC_ADDRESS)
         .typed(tro_staking::proxy::TroStakingProxy)
         .unstake(unstake_args)
-        .returns(ExpectMessage(ERR_PROPOSAL_ACTIVE))
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    // the voting power is taken from the stake held before the proposal started
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_voting_power_view(USER_ADDRESS, OptionalValue::Some(1u64))
+        .returns(ExpectValue(BigUint::from(1000u64)))
+        .run();
+}
+
+#[test]
+fn unstake_should_fail_until_voted_proposals_end() {
+    let mut world = setup_world_with_contract();
+
+    stake_token(&mut world, TRO_TOKEN_ID, 1000);
+
+    // the older proposal ends last
+    let first_end_time = DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS * 4;
+    let second_end_time = DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS * 3;
+    create_proposal_ending_at(&mut world, first_end_time);
+    create_proposal_ending_at(&mut world, second_end_time);
+
+    world.set_state_step(
+        SetStateStep::new().block_timestamp(DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + 1),
+    );
+    for proposal_id in [1u64, 2u64] {
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(tro_staking::proxy::TroStakingProxy)
+            .vote(proposal_id, VoteDecision::Approve)
+            .returns(ExpectStatus(0u64))
+            .run();
+    }
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_unstake_available_at(USER_ADDRESS)
+        .returns(ExpectValue(first_end_time + 1))
+        .run();
+
+    world.set_state_step(SetStateStep::new().block_timestamp(first_end_time));
+    unstake_tro(&mut world, Some(ERR_STAKE_LOCKED));
+
+    // users who did not vote are not locked
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_unstake_available_at(OWNER_ADDRESS)
+        .returns(ExpectValue(0u64))
         .run();
+
+    world.set_state_step(SetStateStep::new().block_timestamp(first_end_time + 1));
+    unstake_tro(&mut world, None);
+}
+
+fn create_proposal_ending_at(world: &mut ScenarioWorld, end_time: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .create_proposal(
+            ManagedBuffer::new_from_bytes(b"title"),
+            ManagedBuffer::new_from_bytes(b"description"),
+            BigUint::from(1u64),
+            OptionalValue::Some(DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS),
+            OptionalValue::Some(end_time),
+            MultiValueEncoded::new(),
+        )
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn unstake_tro(world: &mut ScenarioWorld, expected_error: Option<&str>) {
+    let mut unstake_args = MultiValueEncoded::new();
+    unstake_args.push(MultiValue2((
+        TRO_TOKEN_ID.to_token_identifier(),
+        BigUint::from(1000u64),
+    )));
+
+    let tx = world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .unstake(unstake_args);
+
+    match expected_error {
+        Some(message) => tx.returns(ExpectMessage(message)).run(),
+        None => tx.returns(ExpectStatus(0u64)).run(),
+    }
 }
 
 fn stake_token(world: &mut ScenarioWorld, token_id: TestTokenIdentifier, amount: u64) {
@@ -324,6 +324,121 @@ fn voting_twice_on_same_proposal_should_fail() {
         .run();
 }
 
+#[test]
+fn changing_vote_should_move_voting_power_to_new_decision() {
+    let mut world = setup_world_with_contract();
+
+    create_proposal(&mut world);
+    add_stake(&mut world, TRO_TOKEN_ID, 1000);
+
+    world.set_state_step(
+        SetStateStep::new().block_timestamp(DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + 1),
+    );
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .vote(1u64, VoteDecision::Approve)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .change_vote(1u64, VoteDecision::Reject)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .proposal_votes(1u64, VoteDecision::Approve)
+        .returns(ExpectValue(BigUint::zero()))
+        .run();
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .proposal_votes(1u64, VoteDecision::Reject)
+        .returns(ExpectValue(BigUint::from(1000u64)))
+        .run();
+
+    let history = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .user_vote_history(USER_ADDRESS, 1u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|vote| vote.decision)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        history,
+        vec![VoteDecision::Approve as u8, VoteDecision::Reject as u8]
+    );
+}
+
+#[test]
+fn changing_vote_without_voting_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    create_proposal(&mut world);
+    add_stake(&mut world, TRO_TOKEN_ID, 1000);
+
+    world.set_state_step(
+        SetStateStep::new().block_timestamp(DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + 1),
+    );
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .change_vote(1u64, VoteDecision::Reject)
+        .returns(ExpectMessage(ERR_USER_HAS_NOT_VOTED))
+        .run();
+}
+
+#[test]
+fn changing_vote_after_proposal_ended_should_fail() {
+    let mut world = setup_world_with_contract();
+
+    create_proposal(&mut world);
+    add_stake(&mut world, TRO_TOKEN_ID, 1000);
+
+    world.set_state_step(
+        SetStateStep::new().block_timestamp(DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + 1),
+    );
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .vote(1u64, VoteDecision::Approve)
+        .returns(ExpectStatus(0u64))
+        .run();
+
+    world.set_state_step(SetStateStep::new().block_timestamp(
+        DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + DEFAULT_PROPOSAL_DURATION_IN_SECONDS + 1,
+    ));
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .change_vote(1u64, VoteDecision::Reject)
+        .returns(ExpectMessage(ERR_PROPOSAL_NOT_ACTIVE))
+        .run();
+}
+
 #[test]
 fn voting_when_proposal_not_active_should_fail() {
     let mut world = setup_world_with_contract();
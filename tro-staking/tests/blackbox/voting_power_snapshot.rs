@@ -0,0 +1,147 @@
+use multiversx_sc_scenario::imports::*;
+use tro_staking::{
+    errors::*,
+    proxy::VoteDecision,
+    voting::{DEFAULT_PROPOSAL_DURATION_IN_SECONDS, DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS},
+};
+
+use crate::config::*;
+
+use super::test_setup::setup_world_with_contract;
+
+const STAKE_AMOUNT: u64 = 1000;
+const PROPOSAL_START_TIME: u64 = DEFAULT_PROPOSAL_START_TIME_DELAY_IN_SECONDS + 1;
+
+#[test]
+fn stake_added_after_proposal_start_should_not_count() {
+    let mut world = setup_world_with_contract();
+
+    stake(&mut world, USER_ADDRESS, STAKE_AMOUNT);
+    create_proposal(&mut world);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(PROPOSAL_START_TIME));
+    stake(&mut world, USER_ADDRESS, STAKE_AMOUNT);
+
+    check_voting_power(&mut world, USER_ADDRESS, STAKE_AMOUNT);
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_user_stake_before(USER_ADDRESS, TRO_TOKEN_ID, PROPOSAL_START_TIME + 1)
+        .returns(ExpectValue(BigUint::from(STAKE_AMOUNT * 2)))
+        .run();
+}
+
+#[test]
+fn stake_moved_to_another_wallet_should_not_be_counted_twice() {
+    let mut world = setup_world_with_contract();
+
+    stake(&mut world, USER_ADDRESS, STAKE_AMOUNT);
+    create_proposal(&mut world);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(PROPOSAL_START_TIME));
+
+    let mut unstake_args = MultiValueEncoded::new();
+    unstake_args.push(MultiValue2((
+        TRO_TOKEN_ID.to_token_identifier(),
+        BigUint::from(STAKE_AMOUNT),
+    )));
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .unstake(unstake_args)
+        .returns(ExpectStatus(0u64))
+        .run();
+    stake(&mut world, OWNER_ADDRESS, STAKE_AMOUNT);
+
+    check_voting_power(&mut world, OWNER_ADDRESS, 0);
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .vote(1u64, VoteDecision::Approve)
+        .returns(ExpectMessage(ERR_INSUFFICIENT_VOTING_POWER))
+        .run();
+
+    // the unstaked tokens still count once, for their previous owner
+    vote(&mut world, USER_ADDRESS);
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .proposal_votes(1u64, VoteDecision::Approve)
+        .returns(ExpectValue(BigUint::from(STAKE_AMOUNT)))
+        .run();
+}
+
+#[test]
+fn stake_changes_in_the_same_block_should_keep_last_balance() {
+    let mut world = setup_world_with_contract();
+
+    stake(&mut world, USER_ADDRESS, STAKE_AMOUNT);
+    stake(&mut world, USER_ADDRESS, STAKE_AMOUNT);
+    create_proposal(&mut world);
+
+    world.set_state_step(SetStateStep::new().block_timestamp(PROPOSAL_START_TIME));
+    check_voting_power(&mut world, USER_ADDRESS, STAKE_AMOUNT * 2);
+}
+
+fn create_proposal(world: &mut ScenarioWorld) {
+    world.set_state_step(SetStateStep::new().block_timestamp(1));
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .create_proposal(
+            ManagedBuffer::from("Test Proposal"),
+            ManagedBuffer::from("This is a test proposal"),
+            BigUint::from(STAKE_AMOUNT),
+            OptionalValue::Some(PROPOSAL_START_TIME),
+            OptionalValue::Some(PROPOSAL_START_TIME + DEFAULT_PROPOSAL_DURATION_IN_SECONDS),
+            MultiValueEncoded::new(),
+        )
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn stake(world: &mut ScenarioWorld, address: TestAddress, amount: u64) {
+    world
+        .tx()
+        .from(address)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .stake()
+        .payment(EsdtTokenPayment::new(
+            TRO_TOKEN_ID.to_token_identifier(),
+            0,
+            BigUint::from(amount),
+        ))
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn vote(world: &mut ScenarioWorld, address: TestAddress) {
+    world
+        .tx()
+        .from(address)
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .vote(1u64, VoteDecision::Approve)
+        .returns(ExpectStatus(0u64))
+        .run();
+}
+
+fn check_voting_power(world: &mut ScenarioWorld, address: TestAddress, expected_voting_power: u64) {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(tro_staking::proxy::TroStakingProxy)
+        .get_voting_power_view(address, OptionalValue::Some(1u64))
+        .returns(ExpectValue(BigUint::from(expected_voting_power)))
+        .run();
+}